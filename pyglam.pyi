@@ -2,125 +2,5059 @@
 # ruff: noqa: E501, F401, F403, F405
 
 import builtins
+import enum
+import numpy
+import numpy.typing
 import typing
 
 __all__ = [
+    "Affine3A",
+    "DAffine3",
+    "DMat3",
+    "DMat4",
     "DQuat",
+    "DQuatArray",
+    "DVec2",
     "DVec3",
+    "DVec3Array",
+    "DVec4",
+    "EulerRot",
+    "Mat3",
+    "Mat4",
+    "NormalizationError",
+    "PyglamError",
+    "Quat",
+    "QuatArray",
+    "Rng",
+    "SingularMatrixError",
+    "Vec2",
+    "Vec3",
+    "Vec3Array",
+    "Vec4",
+    "quat_array",
+    "seed",
+    "vec3_array",
 ]
 
+# Stub-only aliases, import them under `typing.TYPE_CHECKING`
+class SupportsArray(typing.Protocol):
+    r"""
+    Object that converts itself with numpy's `__array__` protocol
+    """
+    def __array__(self) -> typing.Any: ...
+
+Vec2Like: typing.TypeAlias = typing.Union["DVec2", "Vec2", typing.Sequence[builtins.float], SupportsArray]
+r"""
+Anything accepted where a vec2 is expected: a pyglam vec2, a 2 component sequence
+such as a tuple or list, a numpy 1-D array of length 2 or an object exposing `__array__`
+"""
+
+Vec3Like: typing.TypeAlias = typing.Union["DVec3", "Vec3", typing.Sequence[builtins.float], SupportsArray]
+r"""
+Anything accepted where a vec3 is expected: a pyglam vec3, a 3 component sequence
+such as a tuple or list, a numpy 1-D array of length 3 or an object exposing `__array__`
+"""
+
+QuatLike: typing.TypeAlias = typing.Union["DQuat", "Quat", typing.Sequence[builtins.float], SupportsArray]
+r"""
+Anything accepted where a quaternion is expected: a pyglam quat, a 4 component xyzw sequence
+such as a tuple or list, a numpy 1-D array of length 4 or an object exposing `__array__`
+"""
+
+@typing.final
+class Affine3A:
+    r"""
+    3D affine transform: a 3x3 linear transform followed by a translation
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def matrix3(self) -> Mat3: ...
+    @matrix3.setter
+    def matrix3(self, value: Mat3) -> None: ...
+    @property
+    def translation(self) -> Vec3: ...
+    @translation.setter
+    def translation(self, value: Vec3) -> None: ...
+    def __new__(cls, rotation: Quat, translation: Vec3) -> Affine3A:
+        r"""
+        Create an affine transform from a rotation followed by a translation
+
+        # Arguments
+
+        - `rotation` (`quat`) - Rotation, must be normalized
+        - `translation` (`vec3`) - Translation
+
+        # Returns
+
+        - `Self` - Rigid transform
+        """
+    @staticmethod
+    def from_rotation_translation(rotation: Quat, translation: Vec3) -> Affine3A:
+        r"""
+        Create an affine transform from a rotation followed by a translation
+        """
+    @staticmethod
+    def from_scale_rotation_translation(
+        scale: Vec3, rotation: Quat, translation: Vec3
+    ) -> Affine3A:
+        r"""
+        Create an affine transform that applies scale first, then rotation, then translation
+
+        # Arguments
+
+        - `scale` (`vec3`) - Scale along each axis
+        - `rotation` (`quat`) - Rotation, must be normalized
+        - `translation` (`vec3`) - Translation
+
+        # Returns
+
+        - `Self` - Affine transform
+        """
+    @staticmethod
+    def from_mat4(mat: Mat4) -> Affine3A:
+        r"""
+        Create an affine transform from a 4x4 matrix, discarding the last row
+
+        The matrix is expected to be a 3D affine transformation matrix
+        """
+    def to_mat4(self) -> Mat4:
+        r"""
+        Convert this transform into the equivalent 4x4 matrix
+        """
+    def to_scale_rotation_translation(self) -> tuple[Vec3, Quat, Vec3]:
+        r"""
+        Decompose this transform into its scale, rotation and translation
+
+        # Returns
+
+        - `(vec3, quat, vec3)` - scale, rotation and translation, raises SingularMatrixError if the determinant is 0
+        """
+    def transform_point3(self, point: Vec3) -> Vec3:
+        r"""
+        Transform a 3D point, applying rotation, scale and translation
+        """
+    def transform_vector3(self, vector: Vec3) -> Vec3:
+        r"""
+        Transform a 3D direction, ignoring translation
+        """
+    def inverse(self) -> Affine3A:
+        r"""
+        Returns the inverse of this transform
+
+        # Returns
+
+        - `PyResult<Self>` - Inverse transform, raises SingularMatrixError if this transform is not invertible
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[Affine3A, typing.Union[Vec3, DVec3]]:
+        r"""
+        Compose this transform with another transform or transform a point
+
+        `a * b` yields a transform that applies `b` first and then `a`.
+        A vector operand is treated as a point, see `transform_point3`.
+        Mixing f32 and f64 operands returns f64
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - Affine transform or vec3 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a combined transform or a transformed point
+        """
+    def __rmul__(self, lhs: typing.Any) -> Affine3A:
+        r"""
+        Compose another transform with this transform, `lhs * self`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - Affine transform applied after this one
+
+        # Returns
+
+        - `PyResult<Self>` - Combined transform
+        """
+    def __matmul__(self, rhs: typing.Any) -> typing.Union[Affine3A, typing.Union[Vec3, DVec3]]:
+        r"""
+        Compose this transform with another transform or transform a point, same as `*`
+        """
+    def __rmatmul__(self, lhs: typing.Any) -> Affine3A:
+        r"""
+        Compose another transform with this transform, same as reflected `*`
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the column major components
+        """
+    def __copy__(self) -> Affine3A:
+        r"""
+        Copy this transform
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Affine3A: ...
+
+@typing.final
+class DAffine3:
+    r"""
+    3D affine transform: a 3x3 linear transform followed by a translation
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def matrix3(self) -> DMat3: ...
+    @matrix3.setter
+    def matrix3(self, value: DMat3) -> None: ...
+    @property
+    def translation(self) -> DVec3: ...
+    @translation.setter
+    def translation(self, value: DVec3) -> None: ...
+    def __new__(cls, rotation: DQuat, translation: DVec3) -> DAffine3:
+        r"""
+        Create an affine transform from a rotation followed by a translation
+
+        # Arguments
+
+        - `rotation` (`quat`) - Rotation, must be normalized
+        - `translation` (`vec3`) - Translation
+
+        # Returns
+
+        - `Self` - Rigid transform
+        """
+    @staticmethod
+    def from_rotation_translation(rotation: DQuat, translation: DVec3) -> DAffine3:
+        r"""
+        Create an affine transform from a rotation followed by a translation
+        """
+    @staticmethod
+    def from_scale_rotation_translation(
+        scale: DVec3, rotation: DQuat, translation: DVec3
+    ) -> DAffine3:
+        r"""
+        Create an affine transform that applies scale first, then rotation, then translation
+
+        # Arguments
+
+        - `scale` (`vec3`) - Scale along each axis
+        - `rotation` (`quat`) - Rotation, must be normalized
+        - `translation` (`vec3`) - Translation
+
+        # Returns
+
+        - `Self` - Affine transform
+        """
+    @staticmethod
+    def from_mat4(mat: DMat4) -> DAffine3:
+        r"""
+        Create an affine transform from a 4x4 matrix, discarding the last row
+
+        The matrix is expected to be a 3D affine transformation matrix
+        """
+    def to_mat4(self) -> DMat4:
+        r"""
+        Convert this transform into the equivalent 4x4 matrix
+        """
+    def to_scale_rotation_translation(self) -> tuple[DVec3, DQuat, DVec3]:
+        r"""
+        Decompose this transform into its scale, rotation and translation
+
+        # Returns
+
+        - `(vec3, quat, vec3)` - scale, rotation and translation, raises SingularMatrixError if the determinant is 0
+        """
+    def transform_point3(self, point: DVec3) -> DVec3:
+        r"""
+        Transform a 3D point, applying rotation, scale and translation
+        """
+    def transform_vector3(self, vector: DVec3) -> DVec3:
+        r"""
+        Transform a 3D direction, ignoring translation
+        """
+    def inverse(self) -> DAffine3:
+        r"""
+        Returns the inverse of this transform
+
+        # Returns
+
+        - `PyResult<Self>` - Inverse transform, raises SingularMatrixError if this transform is not invertible
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[DAffine3, DVec3]:
+        r"""
+        Compose this transform with another transform or transform a point
+
+        `a * b` yields a transform that applies `b` first and then `a`.
+        A vector operand is treated as a point, see `transform_point3`.
+        Mixing f32 and f64 operands returns f64
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - Affine transform or vec3 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a combined transform or a transformed point
+        """
+    def __rmul__(self, lhs: typing.Any) -> DAffine3:
+        r"""
+        Compose another transform with this transform, `lhs * self`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - Affine transform applied after this one
+
+        # Returns
+
+        - `PyResult<Self>` - Combined transform
+        """
+    def __matmul__(self, rhs: typing.Any) -> typing.Union[DAffine3, DVec3]:
+        r"""
+        Compose this transform with another transform or transform a point, same as `*`
+        """
+    def __rmatmul__(self, lhs: typing.Any) -> DAffine3:
+        r"""
+        Compose another transform with this transform, same as reflected `*`
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the column major components
+        """
+    def __copy__(self) -> DAffine3:
+        r"""
+        Copy this transform
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DAffine3: ...
+
+@typing.final
+class DMat3:
+    r"""
+    3x3 column major matrix
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x_axis(self) -> DVec3: ...
+    @x_axis.setter
+    def x_axis(self, value: DVec3) -> None: ...
+    @property
+    def y_axis(self) -> DVec3: ...
+    @y_axis.setter
+    def y_axis(self, value: DVec3) -> None: ...
+    @property
+    def z_axis(self) -> DVec3: ...
+    @z_axis.setter
+    def z_axis(self, value: DVec3) -> None: ...
+    def __new__(cls, x_axis: Vec3Like, y_axis: Vec3Like, z_axis: Vec3Like) -> DMat3:
+        r"""
+        Create a new matrix from three column vectors
+
+        # Arguments
+
+        - `x_axis` (`Vec3Like`) - first column
+        - `y_axis` (`Vec3Like`) - second column
+        - `z_axis` (`Vec3Like`) - third column
+
+        # Returns
+
+        - `Self` - Matrix with the given columns
+        """
+    @staticmethod
+    def from_cols(x_axis: Vec3Like, y_axis: Vec3Like, z_axis: Vec3Like) -> DMat3:
+        r"""
+        Create a new matrix from three column vectors
+        """
+    @staticmethod
+    def from_rows(row0: Vec3Like, row1: Vec3Like, row2: Vec3Like) -> DMat3:
+        r"""
+        Create a new matrix from three row vectors
+        """
+    @staticmethod
+    def from_diagonal(diagonal: Vec3Like) -> DMat3:
+        r"""
+        Create a matrix with the given diagonal and all other elements set to 0
+        """
+    @staticmethod
+    def from_quat(rotation: QuatLike) -> DMat3:
+        r"""
+        Create a rotation matrix from a quaternion
+
+        # Arguments
+
+        - `rotation` (`QuatLike`) - Rotation, must be normalized
+
+        # Returns
+
+        - `Self` - Rotation matrix equivalent to `rotation`
+        """
+    def col(self, index: builtins.int) -> DVec3:
+        r"""
+        Get a column of this matrix
+        """
+    def row(self, index: builtins.int) -> DVec3:
+        r"""
+        Get a row of this matrix
+        """
+    def to_tuple(
+        self,
+    ) -> tuple[
+        tuple[builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float],
+    ]:
+        r"""
+        Convert this matrix to a tuple of 3 column tuples
+
+        # Returns
+
+        - `((float, float, float), (float, float, float), (float, float, float))` - Column major tuple
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[DMat3, DVec3]:
+        r"""
+        Multiply this matrix with a scalar, another matrix or a vector
+
+        Mixing f32 and f64 operands returns f64
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - Scalar, mat3 or vec3 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmul__(self, lhs: typing.Any) -> DMat3:
+        r"""
+        Multiply a scalar or another matrix with this matrix, `lhs * self`
+
+        A vector on the left raises TypeError, vectors are transformed as `m * v`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - Scalar or mat3 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __matmul__(self, rhs: typing.Any) -> typing.Union[DMat3, DVec3]:
+        r"""
+        Matrix multiply this matrix with another matrix or a vector
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - mat3 or vec3 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmatmul__(self, lhs: typing.Any) -> DMat3:
+        r"""
+        Matrix multiply another matrix with this matrix, `lhs @ self`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - mat3 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __add__(self, rhs: DMat3) -> DMat3: ...
+    def __sub__(self, rhs: DMat3) -> DMat3: ...
+    def __neg__(self) -> DMat3: ...
+    def transpose(self) -> DMat3:
+        r"""
+        Returns the transpose of this matrix
+        """
+    def determinant(self) -> builtins.float:
+        r"""
+        Returns the determinant of this matrix
+        """
+    def inverse(self) -> DMat3:
+        r"""
+        Returns the inverse of this matrix
+
+        Only an exactly zero determinant raises, a nearly singular matrix inverts to very large or
+        non-finite components
+
+        # Returns
+
+        - `PyResult<Self>` - Inverse matrix, raises SingularMatrixError if this matrix is not invertible
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the column major components
+        """
+    def __copy__(self) -> DMat3:
+        r"""
+        Copy this matrix
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DMat3: ...
+
+@typing.final
+class DMat4:
+    r"""
+    4x4 column major matrix
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x_axis(self) -> DVec4: ...
+    @x_axis.setter
+    def x_axis(self, value: DVec4) -> None: ...
+    @property
+    def y_axis(self) -> DVec4: ...
+    @y_axis.setter
+    def y_axis(self, value: DVec4) -> None: ...
+    @property
+    def z_axis(self) -> DVec4: ...
+    @z_axis.setter
+    def z_axis(self, value: DVec4) -> None: ...
+    @property
+    def w_axis(self) -> DVec4: ...
+    @w_axis.setter
+    def w_axis(self, value: DVec4) -> None: ...
+    def __new__(
+        cls, x_axis: DVec4, y_axis: DVec4, z_axis: DVec4, w_axis: DVec4
+    ) -> DMat4:
+        r"""
+        Create a new matrix from four column vectors
+
+        # Arguments
+
+        - `x_axis` (`vec4`) - first column
+        - `y_axis` (`vec4`) - second column
+        - `z_axis` (`vec4`) - third column
+        - `w_axis` (`vec4`) - fourth column
+
+        # Returns
+
+        - `Self` - Matrix with the given columns
+        """
+    @staticmethod
+    def from_cols(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4, w_axis: DVec4) -> DMat4:
+        r"""
+        Create a new matrix from four column vectors
+        """
+    @staticmethod
+    def from_rows(row0: DVec4, row1: DVec4, row2: DVec4, row3: DVec4) -> DMat4:
+        r"""
+        Create a new matrix from four row vectors
+        """
+    @staticmethod
+    def from_scale_rotation_translation(
+        scale: Vec3Like, rotation: QuatLike, translation: Vec3Like
+    ) -> DMat4:
+        r"""
+        Create an affine transformation matrix from the given 3D `scale`, `rotation` and `translation`.
+
+        The resulting matrix applies scale first, then rotation, then translation.
+
+        # Arguments
+
+        - `scale` (`Vec3Like`) - Scale along each axis
+        - `rotation` (`QuatLike`) - Rotation, must be normalized
+        - `translation` (`Vec3Like`) - Translation
+
+        # Returns
+
+        - `Self` - Affine transformation matrix
+        """
+    @staticmethod
+    def look_at_rh(eye: Vec3Like, center: Vec3Like, up: Vec3Like) -> DMat4:
+        r"""
+        Create a right-handed view matrix using a camera position, a focal point and an up direction.
+
+        For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+
+        # Arguments
+
+        - `eye` (`Vec3Like`) - Camera position
+        - `center` (`Vec3Like`) - Focal point
+        - `up` (`Vec3Like`) - Up direction, must be normalized
+
+        # Returns
+
+        - `Self` - World to view matrix
+        """
+    @staticmethod
+    def look_at_lh(eye: Vec3Like, center: Vec3Like, up: Vec3Like) -> DMat4:
+        r"""
+        Create a left-handed view matrix using a camera position, a focal point and an up direction.
+
+        For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+
+        # Arguments
+
+        - `eye` (`Vec3Like`) - Camera position
+        - `center` (`Vec3Like`) - Focal point
+        - `up` (`Vec3Like`) - Up direction, must be normalized
+
+        # Returns
+
+        - `Self` - World to view matrix
+        """
+    @staticmethod
+    def perspective_rh(
+        fov_y_radians: builtins.float,
+        aspect_ratio: builtins.float,
+        z_near: builtins.float,
+        z_far: builtins.float,
+    ) -> DMat4:
+        r"""
+        Create a right-handed perspective projection matrix with `[0,1]` depth range.
+
+        # Arguments
+
+        - `fov_y_radians` (`float`) - Vertical field of view in radians
+        - `aspect_ratio` (`float`) - Width divided by height
+        - `z_near` (`float`) - Distance to the near plane, must be greater than 0
+        - `z_far` (`float`) - Distance to the far plane, must be greater than 0
+
+        # Returns
+
+        - `PyResult<Self>` - Projection matrix
+        """
+    @staticmethod
+    def orthographic_rh(
+        left: builtins.float,
+        right: builtins.float,
+        bottom: builtins.float,
+        top: builtins.float,
+        near: builtins.float,
+        far: builtins.float,
+    ) -> DMat4:
+        r"""
+        Create a right-handed orthographic projection matrix with `[0,1]` depth range.
+
+        # Arguments
+
+        - `left` (`float`) - Left plane
+        - `right` (`float`) - Right plane
+        - `bottom` (`float`) - Bottom plane
+        - `top` (`float`) - Top plane
+        - `near` (`float`) - Near plane
+        - `far` (`float`) - Far plane
+
+        # Returns
+
+        - `Self` - Projection matrix
+        """
+    def col(self, index: builtins.int) -> DVec4:
+        r"""
+        Get a column of this matrix
+        """
+    def row(self, index: builtins.int) -> DVec4:
+        r"""
+        Get a row of this matrix
+        """
+    def to_tuple(
+        self,
+    ) -> tuple[
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+    ]:
+        r"""
+        Convert this matrix to a tuple of 4 column tuples
+
+        # Returns
+
+        - `((float, float, float, float), ...)` - Column major tuple
+        """
+    def to_scale_rotation_translation(self) -> tuple[DVec3, DQuat, DVec3]:
+        r"""
+        Decompose this affine matrix into its scale, rotation and translation
+
+        The matrix must be an affine transform. A negative determinant, i.e. a reflection, gives a
+        negative x scale, only a determinant of exactly 0 raises
+
+        # Returns
+
+        - `(vec3, quat, vec3)` - scale, rotation and translation, raises SingularMatrixError if the determinant is 0
+        """
+    def transform_point3(self, point: DVec3) -> DVec3:
+        r"""
+        Transform a 3D point, applying translation. The perspective divide is not performed.
+        """
+    def transform_vector3(self, vector: DVec3) -> DVec3:
+        r"""
+        Transform a 3D direction, ignoring translation
+        """
+    def project_point3(self, point: DVec3) -> DVec3:
+        r"""
+        Transform a 3D point, applying the perspective divide. Use this with projection matrices
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[DMat4, DVec4]:
+        r"""
+        Multiply this matrix with a scalar, another matrix or a vector
+
+        Mixing f32 and f64 operands returns f64
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - Scalar, mat4 or vec4 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmul__(self, lhs: typing.Any) -> DMat4:
+        r"""
+        Multiply a scalar or another matrix with this matrix, `lhs * self`
+
+        A vector on the left raises TypeError, vectors are transformed as `m * v`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - Scalar or mat4 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __matmul__(self, rhs: typing.Any) -> typing.Union[DMat4, DVec4]:
+        r"""
+        Matrix multiply this matrix with another matrix or a vector
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - mat4 or vec4 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmatmul__(self, lhs: typing.Any) -> DMat4:
+        r"""
+        Matrix multiply another matrix with this matrix, `lhs @ self`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - mat4 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __add__(self, rhs: DMat4) -> DMat4: ...
+    def __sub__(self, rhs: DMat4) -> DMat4: ...
+    def __neg__(self) -> DMat4: ...
+    def transpose(self) -> DMat4:
+        r"""
+        Returns the transpose of this matrix
+        """
+    def determinant(self) -> builtins.float:
+        r"""
+        Returns the determinant of this matrix
+        """
+    def inverse(self) -> DMat4:
+        r"""
+        Returns the inverse of this matrix
+
+        Only an exactly zero determinant raises, a nearly singular matrix inverts to very large or
+        non-finite components
+
+        # Returns
+
+        - `PyResult<Self>` - Inverse matrix, raises SingularMatrixError if this matrix is not invertible
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the column major components
+        """
+    def __copy__(self) -> DMat4:
+        r"""
+        Copy this matrix
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DMat4: ...
+
+@typing.final
+class DQuat:
+    r"""
+    4 Component Quaternion wxyz
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+    """
+    IDENTITY: typing.ClassVar[DQuat]
+    r"""
+    The identity rotation
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    def __new__(
+        cls, x: builtins.float, y: builtins.float, z: builtins.float, w: builtins.float
+    ) -> DQuat:
+        r"""
+        Create a new quaternion from components.
+        Usually you want `from_axis_angle` or `from_rotation_arc` instead of this
+
+        # Arguments
+
+        - `x` (`float`) - x component
+        - `y` (`float`) - y component
+        - `z` (`float`) - z component
+        - `w` (`float`) - scalar component
+
+        # Returns
+
+        - `PyResult<Self>` - Describe the return value.
+        """
+    @staticmethod
+    def rand_uniform() -> DQuat:
+        r"""
+        Generates a random rotation, uniformly distributed over all rotations
+        """
+    @staticmethod
+    def from_axis_angle(axis: Vec3Like, angle: builtins.float) -> DQuat:
+        r"""
+        Create a new quaternion from an axis and angle
+
+        # Arguments
+
+        - `axis` (`Vec3Like`) - Axis, should be normalized
+        - `angle` (`float`) - Angle in radians
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing this axis/angle rotation
+        """
+    @staticmethod
+    def from_scaled_axis(v: Vec3Like) -> DQuat:
+        r"""
+        Create a quaternion from a rotation vector, rotating `v.length()` radians about `v.normalize()`
+
+        A zero vector gives the identity rotation
+
+        # Arguments
+
+        - `v` (`Vec3Like`) - Rotation vector, also known as scaled axis
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing this rotation
+        """
+    @staticmethod
+    def from_rotation_x(angle: builtins.float) -> DQuat:
+        r"""
+        Create a quaternion rotating `angle` radians about the x axis
+        """
+    @staticmethod
+    def from_rotation_y(angle: builtins.float) -> DQuat:
+        r"""
+        Create a quaternion rotating `angle` radians about the y axis
+        """
+    @staticmethod
+    def from_rotation_z(angle: builtins.float) -> DQuat:
+        r"""
+        Create a quaternion rotating `angle` radians about the z axis
+        """
+    @staticmethod
+    def from_rotation_arc(from_: Vec3Like, to: Vec3Like) -> DQuat:
+        r"""
+        Gets the minimal rotation for transforming `from` to `to`.  The rotation is in the
+        plane spanned by the two vectors.  Will rotate at most 180 degrees.
+
+        `from_rotation_arc(from, to) * from ≈ to`.
+
+        For near-singular cases (from≈to and from≈-to) the current implementation
+        is only accurate to about 0.001 (for `f32`).
+
+        # Arguments
+
+        - `from_` (`Vec3Like`) - starting vector. Must be a unit vector
+        - `to` (`Vec3Like`) - ending vector. Must be a unit vector
+
+        # Returns
+
+        - `PyResult<Self>` - Returns a quaternion that would rotate vector from onto to
+        """
+    @staticmethod
+    def from_mat3(mat: DMat3) -> DQuat:
+        r"""
+        Create a quaternion from a 3x3 rotation matrix
+
+        # Arguments
+
+        - `mat` (`mat3`) - Rotation matrix, must not contain scale or shear
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing the same rotation as `mat`
+        """
+    def to_mat3(self) -> DMat3:
+        r"""
+        Convert this quaternion into a 3x3 rotation matrix
+        """
+    def to_axis_angle(self) -> tuple[DVec3, builtins.float]:
+        r"""
+        Decompose this quaternion into a rotation axis and angle
+
+        # Returns
+
+        - `(vec3, float)` - Normalized axis and angle in radians
+        """
+    def to_scaled_axis(self) -> DVec3:
+        r"""
+        Convert this quaternion into a rotation vector, the axis scaled by the angle in radians
+        """
+    @staticmethod
+    def from_euler(
+        order: typing.Union[EulerRot, builtins.str],
+        a: builtins.float,
+        b: builtins.float,
+        c: builtins.float,
+        degrees: builtins.bool = False,
+    ) -> DQuat:
+        r"""
+        Create a quaternion from euler angles applied in the given order
+
+        # Arguments
+
+        - `order` (`EulerRot | str`) - Rotation sequence, e.g. `EulerRot.ZYX` or `"ZYX"` for yaw-pitch-roll
+        - `a` (`float`) - Angle about the first axis of `order`
+        - `b` (`float`) - Angle about the second axis of `order`
+        - `c` (`float`) - Angle about the third axis of `order`
+        - `degrees` (`bool`) - Angles are in degrees instead of radians
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing the combined rotation
+        """
+    def to_euler(
+        self, order: typing.Union[EulerRot, builtins.str], degrees: builtins.bool = False
+    ) -> tuple[builtins.float, builtins.float, builtins.float]:
+        r"""
+        Decompose this quaternion into euler angles in the given order
+
+        Near gimbal lock the decomposition isn't unique. This happens when the middle angle
+        is ±90 degrees for three-axis orders, or 0 or 180 degrees for two-axis orders such as `ZYZ`.
+        One of the outer angles is then set to 0 and the other carries the whole rotation about
+        the aligned axes, so `from_euler(order, *to_euler(order))` still gives back the same rotation
+
+        # Arguments
+
+        - `order` (`EulerRot | str`) - Rotation sequence, e.g. `EulerRot.ZYX` or `"ZYX"` for yaw-pitch-roll
+        - `degrees` (`bool`) - Return the angles in degrees instead of radians
+
+        # Returns
+
+        - `PyResult<(float, float, float)>` - Angles about the first, second and third axis of `order`
+        """
+    def to_tuple(
+        self,
+    ) -> tuple[builtins.float, builtins.float, builtins.float, builtins.float]:
+        r"""
+        Convert this quat to a 4 component tuple
+
+        # Returns
+
+        - `(float, float, float, float)` - XYZW tuple
+        """
+    def as_dquat(self) -> DQuat:
+        r"""
+        Convert this quat to f64 precision
+
+        # Returns
+
+        - `DQuat` - Copy with every component widened to f64
+        """
+    def as_quat(self) -> Quat:
+        r"""
+        Convert this quat to f32 precision
+
+        # Returns
+
+        - `Quat` - Copy with every component rounded to the nearest f32
+        """
+    @staticmethod
+    def from_numpy(array: numpy.typing.ArrayLike) -> DQuat:
+        r"""
+        Create a quaternion from a numpy array of 4 xyzw components
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 1-D array of length 4, other dtypes are cast to this type's precision
+
+        # Returns
+
+        - `quat` - New quaternion holding a copy of the components
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        View this quaternion as a numpy array, see `numpy.asarray`
+
+        Without a dtype conversion or `copy=True` the array is a read-only view of this quaternion
+
+        # Arguments
+
+        - `dtype` (`numpy.dtype | None`) - Requested dtype, defaults to this type's precision
+        - `copy` (`bool | None`) - True to always copy, False to raise ValueError instead of copying
+
+        # Returns
+
+        - `numpy.ndarray` - 1-D array of the xyzw components
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any quat, regardless of precision
+
+        `q` and `-q` describe the same rotation but are not equal.
+        Follows float semantics, so a quat containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def is_close(
+        self,
+        other: QuatLike,
+        rel_tol: builtins.float = 1e-09,
+        abs_tol: builtins.float = 0.0,
+    ) -> builtins.bool:
+        r"""
+        Determine whether this quat describes a rotation close to `other`, following `math.isclose`
+
+        `q` and `-q` describe the same rotation, so `other` is also compared with its sign flipped
+
+        # Arguments
+
+        - `other` (`QuatLike`) - Quaternion to compare against, any quat or xyzw sequence
+        - `rel_tol` (`float`) - Maximum difference relative to the larger absolute component
+        - `abs_tol` (`float`) - Minimum absolute tolerance, useful for comparisons near zero
+
+        # Returns
+
+        - `bool` - True if every component pair of `other` or `-other` is close
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __mul__(
+        self, rhs: typing.Union[QuatLike, Vec3Like]
+    ) -> typing.Union[typing.Union[DQuat, DVec3], DQuatArray]:
+        r"""
+        Multiply this quaternion with another quaternion, a vector or a quaternion array
+
+        A quaternion multiplication means combining two rotations into a single rotation
+        A vector multiplication yields a rotated vec3
+        A quaternion array multiplication combines this rotation with every element
+
+        # Arguments
+
+        - `rhs` (`typing.Union[QuatLike, Vec3Like, quat array]`) - Quat, Vec or quat array to multiply, 4 component sequences are treated as xyzw quats
+
+        # Returns
+
+        - `PyResult<Either<Either<, >, >>` - Either a new rotation, a rotated vector or an array of combined rotations
+        """
+    def __rmul__(self, lhs: QuatLike) -> DQuat:
+        r"""
+        Multiply a quaternion on the left with this quaternion, `lhs * self`
+
+        Combines the rotations, so the result rotates by this quaternion first and then by `lhs`.
+        A vector on the left, `v * q`, raises TypeError since it's ambiguous whether it should
+        rotate or inverse rotate, use `q * v`, `q.rotate(v)` or `q.inverse_rotate(v)` instead
+
+        # Arguments
+
+        - `lhs` (`QuatLike`) - Left hand side rotation, 4 component sequences are treated as xyzw quats
+
+        # Returns
+
+        - `PyResult<Self>` - Combined rotation
+        """
+    def rotate(self, v: Vec3Like) -> DVec3:
+        r"""
+        Rotate a vector by this quaternion, same as `self * v`
+
+        # Arguments
+
+        - `v` (`Vec3Like`) - Vector to rotate
+
+        # Returns
+
+        - `PyResult<vec3>` - Rotated vector
+        """
+    def inverse_rotate(self, v: Vec3Like) -> DVec3:
+        r"""
+        Rotate a vector by the inverse of this quaternion, undoing `rotate`
+
+        This quaternion must be normalized
+
+        # Arguments
+
+        - `v` (`Vec3Like`) - Vector to rotate
+
+        # Returns
+
+        - `PyResult<vec3>` - Inverse rotated vector
+        """
+    def then(self, other: QuatLike) -> DQuat:
+        r"""
+        Combine this rotation with a rotation applied after it, same as `other * self`
+
+        `a.then(b).then(c)` reads in the order the rotations are applied
+
+        # Arguments
+
+        - `other` (`QuatLike`) - Rotation applied after this one
+
+        # Returns
+
+        - `PyResult<Self>` - Combined rotation
+        """
+    def normalize(self) -> DQuat:
+        r"""
+        Normalize this quaternion into a unit quat
+
+        # Returns
+
+        - `PyResult<Self>` - Unit quat, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def conjugate(self) -> DQuat:
+        r"""
+        Compute the conjugate of this quat.
+        If this is a unit quat, the conjugate is equal to the inverse of the rotation
+        """
+    def inverse(self) -> DQuat:
+        r"""
+        Compute the inverse rotation, this quat must be normalized
+        """
+    def dot(self, rhs: QuatLike) -> builtins.float:
+        r"""
+        Dot product of this quat and `rhs`, the cosine of half the angle between unit rotations
+
+        # Arguments
+
+        - `rhs` (`QuatLike`) - Other quaternion, any quat or xyzw sequence
+
+        # Returns
+
+        - `float` - Dot product of the xyzw components
+        """
+    def length(self) -> builtins.float:
+        r"""
+        Length of this quaternion, 1 for a unit quat
+        """
+    def is_normalized(self) -> builtins.bool:
+        r"""
+        Whether this quaternion has a length of 1, i.e. its squared length is within `2e-4` of 1
+        """
+    def is_near_identity(self) -> builtins.bool:
+        r"""
+        Whether this quaternion rotates by less than roughly 0.0028 radians, treating `q` and `-q` alike
+        """
+    def angle_between(self, rhs: QuatLike) -> builtins.float:
+        r"""
+        Angle in radians of the shortest rotation from this quat to `rhs`, both must be normalized
+
+        # Arguments
+
+        - `rhs` (`QuatLike`) - Other rotation
+
+        # Returns
+
+        - `float` - Angle between the two rotations in `[0, pi]`
+        """
+    def slerp(self, end: QuatLike, t: builtins.float) -> DQuat:
+        r"""
+        Spherical linear interpolation between this quat and `end`
+
+        Follows the shortest path, `end` is negated when the quats are more than 180 degrees apart
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`, both quats should be normalized
+        - `t` (`float`) - Interpolation factor, 0 returns this quat and 1 returns `end`
+
+        # Returns
+
+        - `PyResult<Self>` - Interpolated rotation
+        """
+    def lerp(self, end: QuatLike, t: builtins.float) -> DQuat:
+        r"""
+        Normalized linear interpolation between this quat and `end`
+
+        Cheaper than `slerp` but the angular velocity isn't constant.
+        Follows the shortest path, `end` is negated when the quats are more than 180 degrees apart
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`, both quats should be normalized
+        - `t` (`float`) - Interpolation factor, 0 returns this quat and 1 returns `end`
+
+        # Returns
+
+        - `PyResult<Self>` - Interpolated unit rotation
+        """
+    def nlerp(self, end: QuatLike, t: builtins.float) -> DQuat:
+        r"""
+        Alias of `lerp`, the result is always normalized
+        """
+    @staticmethod
+    def squad(
+        q0: QuatLike, a: QuatLike, b: QuatLike, q1: QuatLike, t: builtins.float
+    ) -> DQuat:
+        r"""
+        Spherical cubic interpolation between `q0` and `q1` through the control quats `a` and `b`
+
+        Computed as `slerp(slerp(q0, q1, t), slerp(a, b, t), 2t(1 - t))`, so every slerp follows the shortest path
+
+        # Arguments
+
+        - `q0` (`QuatLike`) - Rotation at `t = 0`
+        - `a` (`QuatLike`) - Control rotation for `q0`
+        - `b` (`QuatLike`) - Control rotation for `q1`
+        - `q1` (`QuatLike`) - Rotation at `t = 1`
+        - `t` (`float`) - Interpolation factor
+
+        # Returns
+
+        - `PyResult<Self>` - Interpolated rotation
+        """
+    def slerp_many(
+        self, end: QuatLike, ts: typing.Sequence[builtins.float]
+    ) -> DQuatArray:
+        r"""
+        Spherical linear interpolation at every value of `ts`, see `slerp`
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`
+        - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+
+        # Returns
+
+        - `PyResult<quat array>` - One interpolated rotation per factor
+        """
+    def nlerp_many(
+        self, end: QuatLike, ts: typing.Sequence[builtins.float]
+    ) -> DQuatArray:
+        r"""
+        Normalized linear interpolation at every value of `ts`, see `lerp`
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`
+        - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+
+        # Returns
+
+        - `PyResult<quat array>` - One interpolated rotation per factor
+        """
+    @staticmethod
+    def squad_many(
+        q0: QuatLike,
+        a: QuatLike,
+        b: QuatLike,
+        q1: QuatLike,
+        ts: typing.Sequence[builtins.float],
+    ) -> DQuatArray:
+        r"""
+        Spherical cubic interpolation at every value of `ts`, see `squad`
+
+        # Arguments
+
+        - `q0` (`QuatLike`) - Rotation at `t = 0`
+        - `a` (`QuatLike`) - Control rotation for `q0`
+        - `b` (`QuatLike`) - Control rotation for `q1`
+        - `q1` (`QuatLike`) - Rotation at `t = 1`
+        - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+
+        # Returns
+
+        - `PyResult<quat array>` - One interpolated rotation per factor
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the xyzw components
+        """
+    def __copy__(self) -> DQuat:
+        r"""
+        Copy this quaternion
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DQuat: ...
+
+@typing.final
+class DQuatArray:
+    r"""
+    Contiguous array of quaternions for rotating and composing many rotations at once
+
+    Arithmetic doesn't mix precisions, f32 arrays refuse DQuat and DVec3 operands and arrays only
+    combine with arrays of the same precision. Convert with `quat_array(array, dtype=...)` first
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    def __new__(cls, quats: typing.Optional[typing.Any] = None) -> DQuatArray:
+        r"""
+        Create an array from an iterable of quaternions
+
+        # Arguments
+
+        - `quats` (`Iterable[QuatLike] | None`) - Quaternions or xyzw sequences, or an array with shape `(N, 4)`, empty if not given
+
+        # Returns
+
+        - `PyResult<Self>` - Array holding a copy of each quaternion
+        """
+    @staticmethod
+    def identity(len: builtins.int) -> DQuatArray:
+        r"""
+        Create an array of `len` identity rotations
+        """
+    @staticmethod
+    def from_numpy(
+        array: numpy.typing.ArrayLike, dtype: typing.Any = None
+    ) -> typing.Union[DQuatArray, QuatArray]:
+        r"""
+        Create an array from a numpy array with shape `(N, 4)` in xyzw order
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 2-D array of any float or integer dtype
+        - `dtype` (`str | numpy.dtype | None`) - Precision of the new array, `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `DQuatArray | QuatArray` - Array holding a copy of each row
+        """
+    def to_numpy(self, dtype: typing.Any = None) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        Copy this array into a numpy array with shape `(N, 4)` in xyzw order
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `numpy.ndarray` - New array holding the components of every quaternion
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> typing.Any:
+        r"""
+        Copy this array into a numpy array, numpy's `__array__` protocol
+
+        The storage can't be shared, so `copy=False` raises ValueError
+        """
+    def to_list(self) -> builtins.list[DQuat]:
+        r"""
+        Copy this array into a list of quaternions
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(self, index: typing.Any) -> typing.Union[DQuat, DQuatArray]:
+        r"""
+        Get a quaternion by index, or a new array by slice
+        """
+    def __setitem__(self, index: builtins.int, value: QuatLike) -> None:
+        r"""
+        Set a quaternion by index, raises IndexError if out of range
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[DQuatArray, DVec3Array]:
+        r"""
+        Elementwise multiplication with quaternions or vectors
+
+        Quaternion operands compose rotations, `self[i] * rhs` or `self[i] * rhs[i]` for an array.
+        Vector operands are rotated, a single vector by every rotation or a vec3 array elementwise
+
+        # Arguments
+
+        - `rhs` (`QuatLike | Vec3Like | quat array | vec3 array`) - Right hand side multiplicand
+
+        # Returns
+
+        - `quat array | vec3 array` - Composed rotations or rotated vectors
+        """
+    def __rmul__(self, lhs: QuatLike) -> DQuatArray:
+        r"""
+        Compose a quaternion with every rotation in this array, `lhs * self[i]`
+
+        # Arguments
+
+        - `lhs` (`QuatLike`) - Left hand side rotation
+
+        # Returns
+
+        - `quat array` - Composed rotations
+        """
+    def normalize(self) -> DQuatArray:
+        r"""
+        Normalize every quaternion into a unit quat
+        """
+    def conjugate(self) -> DQuatArray:
+        r"""
+        Conjugate of every quaternion, equal to the inverse for unit quats
+        """
+    def inverse(self) -> DQuatArray:
+        r"""
+        Inverse of every quaternion, the quaternions must be normalized
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged buffer of little endian xyzw components
+        """
+    def __copy__(self) -> DQuatArray: ...
+    def __deepcopy__(self, _memo: typing.Any) -> DQuatArray: ...
+
+@typing.final
+class DVec2:
+    r"""
+    2 Component vector xy
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x(self) -> builtins.float: ...
+    @x.setter
+    def x(self, value: builtins.float) -> None: ...
+    @property
+    def y(self) -> builtins.float: ...
+    @y.setter
+    def y(self, value: builtins.float) -> None: ...
+    def __new__(
+        cls, x: builtins.float, y: typing.Optional[builtins.float] = None
+    ) -> DVec2: ...
+    def to_tuple(self) -> tuple[builtins.float, builtins.float]:
+        r"""
+        Convert this vector to a 2 component tuple
+
+        # Returns
+
+        - `(float, float)` - XY tuple
+        """
+    def as_dvec2(self) -> DVec2:
+        r"""
+        Convert this vector to f64 precision
+
+        # Returns
+
+        - `DVec2` - Copy with every component widened to f64
+        """
+    def as_vec2(self) -> Vec2:
+        r"""
+        Convert this vector to f32 precision
+
+        # Returns
+
+        - `Vec2` - Copy with every component rounded to the nearest f32
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any vec2, regardless of precision
+
+        Follows float semantics, so a vector containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __add__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __radd__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __sub__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __rsub__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __mul__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __rmul__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __truediv__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __rtruediv__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> DVec2: ...
+    def __iadd__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __isub__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __imul__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __itruediv__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __neg__(self) -> DVec2: ...
+    def normalize(self) -> DVec2:
+        r"""
+        Normalize this vector to unit length
+
+        # Returns
+
+        - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def length(self) -> builtins.float: ...
+    def dot(self, rhs: Vec2Like) -> builtins.float:
+        r"""
+        Computes the dot product of this vector and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec2Like`) - Other vector
+
+        # Returns
+
+        - `float` - Dot product
+        """
+    def perp_dot(self, rhs: Vec2Like) -> builtins.float:
+        r"""
+        The perpendicular dot product of this vector and `rhs`.
+        Also known as the wedge product, 2D cross product, and determinant.
+
+        # Arguments
+
+        - `rhs` (`Vec2Like`) - Other vector
+
+        # Returns
+
+        - `float` - `x * rhs.y - y * rhs.x`
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the components
+        """
+    def __copy__(self) -> DVec2:
+        r"""
+        Copy this vector
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DVec2: ...
+
+@typing.final
+class DVec3:
+    r"""
+    3 Component vector xyz
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+
+    Swizzles such as `v.zyx` or `v.xy` read components in any order and return a vec of that length,
+    the ones without repeated components can also be assigned, e.g. `v.xy = (1.0, 2.0)`
+    """
+    ZERO: typing.ClassVar[DVec3]
+    r"""
+    All zeros
+    """
+    ONE: typing.ClassVar[DVec3]
+    r"""
+    All ones
+    """
+    NEG_ONE: typing.ClassVar[DVec3]
+    r"""
+    All negative ones
+    """
+    X: typing.ClassVar[DVec3]
+    r"""
+    Unit vector along the positive x axis
+    """
+    Y: typing.ClassVar[DVec3]
+    r"""
+    Unit vector along the positive y axis
+    """
+    Z: typing.ClassVar[DVec3]
+    r"""
+    Unit vector along the positive z axis
+    """
+    NEG_X: typing.ClassVar[DVec3]
+    r"""
+    Unit vector along the negative x axis
+    """
+    NEG_Y: typing.ClassVar[DVec3]
+    r"""
+    Unit vector along the negative y axis
+    """
+    NEG_Z: typing.ClassVar[DVec3]
+    r"""
+    Unit vector along the negative z axis
+    """
+    NAN: typing.ClassVar[DVec3]
+    r"""
+    All NaN
+    """
+    INFINITY: typing.ClassVar[DVec3]
+    r"""
+    All positive infinity
+    """
+    NEG_INFINITY: typing.ClassVar[DVec3]
+    r"""
+    All negative infinity
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x(self) -> builtins.float: ...
+    @x.setter
+    def x(self, value: builtins.float) -> None: ...
+    @property
+    def y(self) -> builtins.float: ...
+    @y.setter
+    def y(self, value: builtins.float) -> None: ...
+    @property
+    def z(self) -> builtins.float: ...
+    @z.setter
+    def z(self, value: builtins.float) -> None: ...
+    @property
+    def xx(self) -> DVec2: ...
+    @property
+    def xy(self) -> DVec2: ...
+    @xy.setter
+    def xy(self, value: Vec2Like) -> None: ...
+    @property
+    def xz(self) -> DVec2: ...
+    @xz.setter
+    def xz(self, value: Vec2Like) -> None: ...
+    @property
+    def yx(self) -> DVec2: ...
+    @yx.setter
+    def yx(self, value: Vec2Like) -> None: ...
+    @property
+    def yy(self) -> DVec2: ...
+    @property
+    def yz(self) -> DVec2: ...
+    @yz.setter
+    def yz(self, value: Vec2Like) -> None: ...
+    @property
+    def zx(self) -> DVec2: ...
+    @zx.setter
+    def zx(self, value: Vec2Like) -> None: ...
+    @property
+    def zy(self) -> DVec2: ...
+    @zy.setter
+    def zy(self, value: Vec2Like) -> None: ...
+    @property
+    def zz(self) -> DVec2: ...
+    @property
+    def xxx(self) -> DVec3: ...
+    @property
+    def xxy(self) -> DVec3: ...
+    @property
+    def xxz(self) -> DVec3: ...
+    @property
+    def xyx(self) -> DVec3: ...
+    @property
+    def xyy(self) -> DVec3: ...
+    @property
+    def xyz(self) -> DVec3: ...
+    @xyz.setter
+    def xyz(self, value: Vec3Like) -> None: ...
+    @property
+    def xzx(self) -> DVec3: ...
+    @property
+    def xzy(self) -> DVec3: ...
+    @xzy.setter
+    def xzy(self, value: Vec3Like) -> None: ...
+    @property
+    def xzz(self) -> DVec3: ...
+    @property
+    def yxx(self) -> DVec3: ...
+    @property
+    def yxy(self) -> DVec3: ...
+    @property
+    def yxz(self) -> DVec3: ...
+    @yxz.setter
+    def yxz(self, value: Vec3Like) -> None: ...
+    @property
+    def yyx(self) -> DVec3: ...
+    @property
+    def yyy(self) -> DVec3: ...
+    @property
+    def yyz(self) -> DVec3: ...
+    @property
+    def yzx(self) -> DVec3: ...
+    @yzx.setter
+    def yzx(self, value: Vec3Like) -> None: ...
+    @property
+    def yzy(self) -> DVec3: ...
+    @property
+    def yzz(self) -> DVec3: ...
+    @property
+    def zxx(self) -> DVec3: ...
+    @property
+    def zxy(self) -> DVec3: ...
+    @zxy.setter
+    def zxy(self, value: Vec3Like) -> None: ...
+    @property
+    def zxz(self) -> DVec3: ...
+    @property
+    def zyx(self) -> DVec3: ...
+    @zyx.setter
+    def zyx(self, value: Vec3Like) -> None: ...
+    @property
+    def zyy(self) -> DVec3: ...
+    @property
+    def zyz(self) -> DVec3: ...
+    @property
+    def zzx(self) -> DVec3: ...
+    @property
+    def zzy(self) -> DVec3: ...
+    @property
+    def zzz(self) -> DVec3: ...
+    @property
+    def xxxx(self) -> DVec4: ...
+    @property
+    def xxxy(self) -> DVec4: ...
+    @property
+    def xxxz(self) -> DVec4: ...
+    @property
+    def xxyx(self) -> DVec4: ...
+    @property
+    def xxyy(self) -> DVec4: ...
+    @property
+    def xxyz(self) -> DVec4: ...
+    @property
+    def xxzx(self) -> DVec4: ...
+    @property
+    def xxzy(self) -> DVec4: ...
+    @property
+    def xxzz(self) -> DVec4: ...
+    @property
+    def xyxx(self) -> DVec4: ...
+    @property
+    def xyxy(self) -> DVec4: ...
+    @property
+    def xyxz(self) -> DVec4: ...
+    @property
+    def xyyx(self) -> DVec4: ...
+    @property
+    def xyyy(self) -> DVec4: ...
+    @property
+    def xyyz(self) -> DVec4: ...
+    @property
+    def xyzx(self) -> DVec4: ...
+    @property
+    def xyzy(self) -> DVec4: ...
+    @property
+    def xyzz(self) -> DVec4: ...
+    @property
+    def xzxx(self) -> DVec4: ...
+    @property
+    def xzxy(self) -> DVec4: ...
+    @property
+    def xzxz(self) -> DVec4: ...
+    @property
+    def xzyx(self) -> DVec4: ...
+    @property
+    def xzyy(self) -> DVec4: ...
+    @property
+    def xzyz(self) -> DVec4: ...
+    @property
+    def xzzx(self) -> DVec4: ...
+    @property
+    def xzzy(self) -> DVec4: ...
+    @property
+    def xzzz(self) -> DVec4: ...
+    @property
+    def yxxx(self) -> DVec4: ...
+    @property
+    def yxxy(self) -> DVec4: ...
+    @property
+    def yxxz(self) -> DVec4: ...
+    @property
+    def yxyx(self) -> DVec4: ...
+    @property
+    def yxyy(self) -> DVec4: ...
+    @property
+    def yxyz(self) -> DVec4: ...
+    @property
+    def yxzx(self) -> DVec4: ...
+    @property
+    def yxzy(self) -> DVec4: ...
+    @property
+    def yxzz(self) -> DVec4: ...
+    @property
+    def yyxx(self) -> DVec4: ...
+    @property
+    def yyxy(self) -> DVec4: ...
+    @property
+    def yyxz(self) -> DVec4: ...
+    @property
+    def yyyx(self) -> DVec4: ...
+    @property
+    def yyyy(self) -> DVec4: ...
+    @property
+    def yyyz(self) -> DVec4: ...
+    @property
+    def yyzx(self) -> DVec4: ...
+    @property
+    def yyzy(self) -> DVec4: ...
+    @property
+    def yyzz(self) -> DVec4: ...
+    @property
+    def yzxx(self) -> DVec4: ...
+    @property
+    def yzxy(self) -> DVec4: ...
+    @property
+    def yzxz(self) -> DVec4: ...
+    @property
+    def yzyx(self) -> DVec4: ...
+    @property
+    def yzyy(self) -> DVec4: ...
+    @property
+    def yzyz(self) -> DVec4: ...
+    @property
+    def yzzx(self) -> DVec4: ...
+    @property
+    def yzzy(self) -> DVec4: ...
+    @property
+    def yzzz(self) -> DVec4: ...
+    @property
+    def zxxx(self) -> DVec4: ...
+    @property
+    def zxxy(self) -> DVec4: ...
+    @property
+    def zxxz(self) -> DVec4: ...
+    @property
+    def zxyx(self) -> DVec4: ...
+    @property
+    def zxyy(self) -> DVec4: ...
+    @property
+    def zxyz(self) -> DVec4: ...
+    @property
+    def zxzx(self) -> DVec4: ...
+    @property
+    def zxzy(self) -> DVec4: ...
+    @property
+    def zxzz(self) -> DVec4: ...
+    @property
+    def zyxx(self) -> DVec4: ...
+    @property
+    def zyxy(self) -> DVec4: ...
+    @property
+    def zyxz(self) -> DVec4: ...
+    @property
+    def zyyx(self) -> DVec4: ...
+    @property
+    def zyyy(self) -> DVec4: ...
+    @property
+    def zyyz(self) -> DVec4: ...
+    @property
+    def zyzx(self) -> DVec4: ...
+    @property
+    def zyzy(self) -> DVec4: ...
+    @property
+    def zyzz(self) -> DVec4: ...
+    @property
+    def zzxx(self) -> DVec4: ...
+    @property
+    def zzxy(self) -> DVec4: ...
+    @property
+    def zzxz(self) -> DVec4: ...
+    @property
+    def zzyx(self) -> DVec4: ...
+    @property
+    def zzyy(self) -> DVec4: ...
+    @property
+    def zzyz(self) -> DVec4: ...
+    @property
+    def zzzx(self) -> DVec4: ...
+    @property
+    def zzzy(self) -> DVec4: ...
+    @property
+    def zzzz(self) -> DVec4: ...
+    def __new__(
+        cls,
+        x: builtins.float,
+        y: typing.Optional[builtins.float] = None,
+        z: typing.Optional[builtins.float] = None,
+    ) -> DVec3: ...
+    @staticmethod
+    def rand_uv() -> DVec3:
+        r"""
+        Generates a random unit vector, uniformly distributed over the sphere
+        """
+    @staticmethod
+    def rand_in_ball() -> DVec3:
+        r"""
+        Generates a random vector, uniformly distributed inside the unit ball
+        """
+    @staticmethod
+    def rand_in_cube() -> DVec3:
+        r"""
+        Generates a random vector, uniformly distributed inside the cube spanning -1 to 1 on every axis
+        """
+    @staticmethod
+    def rand_gaussian(std_dev: builtins.float = 1.0) -> DVec3:
+        r"""
+        Generates a random vector with independent normally distributed components
+
+        # Arguments
+
+        - `std_dev` (`float`) - Standard deviation of each component, the mean is 0
+
+        # Returns
+
+        - `Self` - Random vector
+        """
+    @staticmethod
+    def rand_in_cone(axis: Vec3Like, half_angle: builtins.float) -> DVec3:
+        r"""
+        Generates a random unit vector, uniformly distributed within `half_angle` of `axis`
+
+        # Arguments
+
+        - `axis` (`Vec3Like`) - Center of the cone, normalized before sampling
+        - `half_angle` (`float`) - Maximum angle from `axis` in radians, `pi` covers the whole sphere
+
+        # Returns
+
+        - `PyResult<Self>` - Random unit vector, raises NormalizationError if `axis` is zero or not finite
+        """
+    def to_tuple(self) -> tuple[builtins.float, builtins.float, builtins.float]:
+        r"""
+        Convert this vector to a 3 component tuple
+
+        # Returns
+
+        - `(float, float, float)` - XYZ tuple
+        """
+    def as_dvec3(self) -> DVec3:
+        r"""
+        Convert this vector to f64 precision
+
+        # Returns
+
+        - `DVec3` - Copy with every component widened to f64
+        """
+    def as_vec3(self) -> Vec3:
+        r"""
+        Convert this vector to f32 precision
+
+        # Returns
+
+        - `Vec3` - Copy with every component rounded to the nearest f32
+        """
+    @staticmethod
+    def from_numpy(array: numpy.typing.ArrayLike) -> DVec3:
+        r"""
+        Create a vector from a numpy array of 3 xyz components
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 1-D array of length 3, other dtypes are cast to this type's precision
+
+        # Returns
+
+        - `vec3` - New vector holding a copy of the components
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        View this vector as a numpy array, see `numpy.asarray`
+
+        Without a dtype conversion or `copy=True` the array is a read-only view of this vector
+
+        # Arguments
+
+        - `dtype` (`numpy.dtype | None`) - Requested dtype, defaults to this type's precision
+        - `copy` (`bool | None`) - True to always copy, False to raise ValueError instead of copying
+
+        # Returns
+
+        - `numpy.ndarray` - 1-D array of the xyz components
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any vec3, regardless of precision
+
+        Follows float semantics, so a vector containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def is_close(
+        self,
+        other: Vec3Like,
+        rel_tol: builtins.float = 1e-09,
+        abs_tol: builtins.float = 0.0,
+    ) -> builtins.bool:
+        r"""
+        Determine whether each component of this vector is close to `other`, following `math.isclose`
+
+        # Arguments
+
+        - `other` (`Vec3Like`) - Vector to compare against, any vec3 or 3 component sequence
+        - `rel_tol` (`float`) - Maximum difference relative to the larger absolute component
+        - `abs_tol` (`float`) - Minimum absolute tolerance, useful for comparisons near zero
+
+        # Returns
+
+        - `bool` - True if every component pair is close
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __add__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __radd__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __sub__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __rsub__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __mul__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __rmul__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __truediv__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __rtruediv__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> DVec3: ...
+    def __iadd__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __isub__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __imul__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __itruediv__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __neg__(self) -> DVec3: ...
+    def normalize(self) -> DVec3:
+        r"""
+        Normalize this vector to unit length
+
+        # Returns
+
+        - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def length(self) -> builtins.float: ...
+    def extend(self, w: builtins.float) -> DVec4:
+        r"""
+        Creates a vec4 from the `x`, `y` and `z` elements of this vector and `w`
+
+        # Arguments
+
+        - `w` (`float`) - w component, use 1.0 for points and 0.0 for directions
+
+        # Returns
+
+        - `vec4` - Homogeneous xyzw vector
+        """
+    def dot(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Computes the dot product of this vector and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `float` - Dot product
+        """
+    def cross(self, rhs: Vec3Like) -> DVec3:
+        r"""
+        Computes the cross product of this vector and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `vec3` - Vector perpendicular to both inputs
+        """
+    def length_squared(self) -> builtins.float:
+        r"""
+        Squared length of this vector, cheaper than `length` for comparisons
+        """
+    def distance(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Euclidean distance between this point and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Other point, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `float` - Distance between the points
+        """
+    def distance_squared(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Squared euclidean distance between this point and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Other point, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `float` - Squared distance between the points
+        """
+    def lerp(self, rhs: Vec3Like, s: builtins.float) -> DVec3:
+        r"""
+        Linear interpolation between this vector and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Vector at `s = 1`
+        - `s` (`float`) - Interpolation factor, values outside `[0, 1]` extrapolate
+
+        # Returns
+
+        - `vec3` - `self + (rhs - self) * s`
+        """
+    def midpoint(self, rhs: Vec3Like) -> DVec3:
+        r"""
+        Point halfway between this point and `rhs`
+        """
+    def project_onto(self, rhs: Vec3Like) -> DVec3:
+        r"""
+        Vector projection of this vector onto `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Vector to project onto, the result is NaN if it is zero
+
+        # Returns
+
+        - `vec3` - Component of this vector parallel to `rhs`
+        """
+    def reject_from(self, rhs: Vec3Like) -> DVec3:
+        r"""
+        Vector rejection of this vector from `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Vector to reject from, the result is NaN if it is zero
+
+        # Returns
+
+        - `vec3` - Component of this vector perpendicular to `rhs`
+        """
+    def reflect(self, normal: Vec3Like) -> DVec3:
+        r"""
+        Reflects this direction off a surface
+
+        # Arguments
+
+        - `normal` (`Vec3Like`) - Surface normal, must be normalized
+
+        # Returns
+
+        - `vec3` - Reflected direction
+        """
+    def refract(self, normal: Vec3Like, eta: builtins.float) -> DVec3:
+        r"""
+        Refracts this direction through a surface, this vector must be normalized
+
+        # Arguments
+
+        - `normal` (`Vec3Like`) - Surface normal, must be normalized
+        - `eta` (`float`) - Ratio of the refractive indices, incident over transmitted
+
+        # Returns
+
+        - `vec3` - Refracted direction, zero on total internal reflection
+        """
+    def angle_between(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Angle in radians between this vector and `rhs`, neither needs to be normalized
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `float` - Angle in `[0, pi]`
+        """
+    def clamp_length(self, min: builtins.float, max: builtins.float) -> DVec3:
+        r"""
+        Scales this vector so its length lies within `[min, max]`
+
+        # Arguments
+
+        - `min` (`float`) - Minimum length
+        - `max` (`float`) - Maximum length
+
+        # Returns
+
+        - `PyResult<vec3>` - Clamped vector, raises ValueError if `min > max`
+        """
+    def normalize_or_zero(self) -> DVec3:
+        r"""
+        Normalize this vector, or return zero if it can't be normalized
+        """
+    def try_normalize(self) -> typing.Optional[DVec3]:
+        r"""
+        Normalize this vector, or return None if its length is zero, infinite or NaN
+        """
+    def abs(self) -> DVec3:
+        r"""
+        Absolute value of each component
+        """
+    def signum(self) -> DVec3:
+        r"""
+        Sign of each component, 1.0 for positive values including `+0.0` and -1.0 for negative values including `-0.0`
+        """
+    def min(self, rhs: Vec3Like) -> DVec3:
+        r"""
+        Componentwise minimum of this vector and `rhs`
+        """
+    def max(self, rhs: Vec3Like) -> DVec3:
+        r"""
+        Componentwise maximum of this vector and `rhs`
+        """
+    def clamp(self, min: Vec3Like, max: Vec3Like) -> DVec3:
+        r"""
+        Clamps each component between the matching components of `min` and `max`
+
+        # Arguments
+
+        - `min` (`Vec3Like`) - Lower bound per component
+        - `max` (`Vec3Like`) - Upper bound per component
+
+        # Returns
+
+        - `PyResult<vec3>` - Clamped vector, raises ValueError if any `min` component exceeds `max`
+        """
+    def element_sum(self) -> builtins.float:
+        r"""
+        Sum of the components
+        """
+    def element_product(self) -> builtins.float:
+        r"""
+        Product of the components
+        """
+    def max_element(self) -> builtins.float:
+        r"""
+        Largest component
+        """
+    def min_element(self) -> builtins.float:
+        r"""
+        Smallest component
+        """
+    def is_finite(self) -> builtins.bool:
+        r"""
+        Whether every component is neither infinite nor NaN
+        """
+    def is_nan(self) -> builtins.bool:
+        r"""
+        Whether any component is NaN
+        """
+    def any_orthonormal_pair(self) -> tuple[DVec3, DVec3]:
+        r"""
+        Two unit vectors perpendicular to this vector and to each other, this vector must be normalized
+
+        # Returns
+
+        - `(vec3, vec3)` - Orthonormal pair completing a right handed basis with this vector
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the components
+        """
+    def __copy__(self) -> DVec3:
+        r"""
+        Copy this vector
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DVec3: ...
+
+@typing.final
+class DVec3Array:
+    r"""
+    Contiguous array of 3 component vectors for vectorised operations
+
+    Arithmetic doesn't mix precisions, f32 arrays refuse DVec3 operands and arrays only combine with
+    arrays of the same precision. Convert with `vec3_array(array, dtype=...)` first
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    def __new__(cls, vecs: typing.Optional[typing.Any] = None) -> DVec3Array:
+        r"""
+        Create an array from an iterable of vectors
+
+        # Arguments
+
+        - `vecs` (`Iterable[Vec3Like] | None`) - Vectors or 3 component sequences, or an array with shape `(N, 3)`, empty if not given
+
+        # Returns
+
+        - `PyResult<Self>` - Array holding a copy of each vector
+        """
+    @staticmethod
+    def zeros(len: builtins.int) -> DVec3Array:
+        r"""
+        Create an array of `len` zero vectors
+        """
+    @staticmethod
+    def from_numpy(
+        array: numpy.typing.ArrayLike, dtype: typing.Any = None
+    ) -> typing.Union[DVec3Array, Vec3Array]:
+        r"""
+        Create an array from a numpy array with shape `(N, 3)`
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 2-D array of any float or integer dtype
+        - `dtype` (`str | numpy.dtype | None`) - Precision of the new array, `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `DVec3Array | Vec3Array` - Array holding a copy of each row
+        """
+    def to_numpy(self, dtype: typing.Any = None) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        Copy this array into a numpy array with shape `(N, 3)`
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `numpy.ndarray` - New array holding the components of every vector
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> typing.Any:
+        r"""
+        Copy this array into a numpy array, numpy's `__array__` protocol
+
+        The storage can't be shared, so `copy=False` raises ValueError
+        """
+    def to_list(self) -> builtins.list[DVec3]:
+        r"""
+        Copy this array into a list of vectors
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(self, index: typing.Any) -> typing.Union[DVec3, DVec3Array]:
+        r"""
+        Get a vector by index, or a new array by slice
+        """
+    def __setitem__(self, index: builtins.int, value: Vec3Like) -> None:
+        r"""
+        Set a vector by index, raises IndexError if out of range
+        """
+    def __add__(self, rhs: typing.Any) -> DVec3Array: ...
+    def __radd__(self, lhs: typing.Any) -> DVec3Array: ...
+    def __sub__(self, rhs: typing.Any) -> DVec3Array: ...
+    def __rsub__(self, lhs: typing.Any) -> DVec3Array: ...
+    def __mul__(self, rhs: typing.Any) -> DVec3Array: ...
+    def __rmul__(self, lhs: typing.Any) -> DVec3Array: ...
+    def __truediv__(self, rhs: typing.Any) -> DVec3Array: ...
+    def __rtruediv__(self, lhs: typing.Any) -> DVec3Array: ...
+    def __neg__(self) -> DVec3Array: ...
+    def dot(self, rhs: typing.Any) -> builtins.list[builtins.float]:
+        r"""
+        Elementwise dot product with a vector or a same length array
+
+        # Arguments
+
+        - `rhs` (`Vec3Like | vec3 array`) - Vector applied to every element, or one vector per element
+
+        # Returns
+
+        - `list[float]` - Dot product per element
+        """
+    def cross(self, rhs: typing.Any) -> DVec3Array:
+        r"""
+        Elementwise cross product with a vector or a same length array
+
+        # Arguments
+
+        - `rhs` (`Vec3Like | vec3 array`) - Vector applied to every element, or one vector per element
+
+        # Returns
+
+        - `vec3 array` - Cross product per element
+        """
+    def length(self) -> builtins.list[builtins.float]:
+        r"""
+        Length of each vector
+        """
+    def normalize(self) -> DVec3Array:
+        r"""
+        Normalize each vector to unit length
+        """
+    def sum(self) -> DVec3:
+        r"""
+        Sum of all vectors, zero for an empty array
+        """
+    def mean(self) -> DVec3:
+        r"""
+        Mean of all vectors, raises ValueError for an empty array
+        """
+    def min(self) -> DVec3:
+        r"""
+        Componentwise minimum of all vectors, raises ValueError for an empty array
+        """
+    def max(self) -> DVec3:
+        r"""
+        Componentwise maximum of all vectors, raises ValueError for an empty array
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged buffer of little endian xyz components
+        """
+    def __copy__(self) -> DVec3Array: ...
+    def __deepcopy__(self, _memo: typing.Any) -> DVec3Array: ...
+
+@typing.final
+class DVec4:
+    r"""
+    4 Component vector xyzw
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x(self) -> builtins.float: ...
+    @x.setter
+    def x(self, value: builtins.float) -> None: ...
+    @property
+    def y(self) -> builtins.float: ...
+    @y.setter
+    def y(self, value: builtins.float) -> None: ...
+    @property
+    def z(self) -> builtins.float: ...
+    @z.setter
+    def z(self, value: builtins.float) -> None: ...
+    @property
+    def w(self) -> builtins.float: ...
+    @w.setter
+    def w(self, value: builtins.float) -> None: ...
+    def __new__(
+        cls,
+        x: builtins.float,
+        y: typing.Optional[builtins.float] = None,
+        z: typing.Optional[builtins.float] = None,
+        w: typing.Optional[builtins.float] = None,
+    ) -> DVec4: ...
+    def to_tuple(
+        self,
+    ) -> tuple[builtins.float, builtins.float, builtins.float, builtins.float]:
+        r"""
+        Convert this vector to a 4 component tuple
+
+        # Returns
+
+        - `(float, float, float, float)` - XYZW tuple
+        """
+    def as_dvec4(self) -> DVec4:
+        r"""
+        Convert this vector to f64 precision
+
+        # Returns
+
+        - `DVec4` - Copy with every component widened to f64
+        """
+    def as_vec4(self) -> Vec4:
+        r"""
+        Convert this vector to f32 precision
+
+        # Returns
+
+        - `Vec4` - Copy with every component rounded to the nearest f32
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any vec4, regardless of precision
+
+        Follows float semantics, so a vector containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __add__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __radd__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __sub__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __rsub__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __mul__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __rmul__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __truediv__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __rtruediv__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> DVec4: ...
+    def __iadd__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __isub__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __imul__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __itruediv__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __neg__(self) -> DVec4: ...
+    def normalize(self) -> DVec4:
+        r"""
+        Normalize this vector to unit length
+
+        # Returns
+
+        - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def length(self) -> builtins.float: ...
+    def dot(self, rhs: typing.Union[DVec4, Vec4]) -> builtins.float: ...
+    def truncate(self) -> DVec3:
+        r"""
+        Creates a vec3 from the `x`, `y` and `z` elements of this vector, discarding `w`
+        """
+    def project_homogeneous(self) -> DVec3:
+        r"""
+        Projects this homogeneous coordinate to 3D space by dividing `x`, `y` and `z` by `w`
+
+        # Returns
+
+        - `vec3` - The projected point. Contains inf/nan when `w` is zero
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the components
+        """
+    def __copy__(self) -> DVec4:
+        r"""
+        Copy this vector
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> DVec4: ...
+
+@typing.final
+class EulerRot(enum.Enum):
+    r"""
+    Euler rotation sequences
+
+    Intrinsic orders rotate about the axes of the rotating body, so `XYZ` applies x then the
+    rotated y then the twice rotated z. Extrinsic orders (the `Ex` suffix) rotate about the fixed
+    world axes.
+    Angles are always given in the order the axes appear in the name
+    """
+    ZYX = ...
+    r"""
+    Intrinsic three-axis rotation ZYX
+    """
+    ZXY = ...
+    r"""
+    Intrinsic three-axis rotation ZXY
+    """
+    YXZ = ...
+    r"""
+    Intrinsic three-axis rotation YXZ
+    """
+    YZX = ...
+    r"""
+    Intrinsic three-axis rotation YZX
+    """
+    XYZ = ...
+    r"""
+    Intrinsic three-axis rotation XYZ
+    """
+    XZY = ...
+    r"""
+    Intrinsic three-axis rotation XZY
+    """
+    ZYZ = ...
+    r"""
+    Intrinsic two-axis rotation ZYZ
+    """
+    ZXZ = ...
+    r"""
+    Intrinsic two-axis rotation ZXZ
+    """
+    YXY = ...
+    r"""
+    Intrinsic two-axis rotation YXY
+    """
+    YZY = ...
+    r"""
+    Intrinsic two-axis rotation YZY
+    """
+    XYX = ...
+    r"""
+    Intrinsic two-axis rotation XYX
+    """
+    XZX = ...
+    r"""
+    Intrinsic two-axis rotation XZX
+    """
+    ZYXEx = ...
+    r"""
+    Extrinsic three-axis rotation ZYX
+    """
+    ZXYEx = ...
+    r"""
+    Extrinsic three-axis rotation ZXY
+    """
+    YXZEx = ...
+    r"""
+    Extrinsic three-axis rotation YXZ
+    """
+    YZXEx = ...
+    r"""
+    Extrinsic three-axis rotation YZX
+    """
+    XYZEx = ...
+    r"""
+    Extrinsic three-axis rotation XYZ
+    """
+    XZYEx = ...
+    r"""
+    Extrinsic three-axis rotation XZY
+    """
+    ZYZEx = ...
+    r"""
+    Extrinsic two-axis rotation ZYZ
+    """
+    ZXZEx = ...
+    r"""
+    Extrinsic two-axis rotation ZXZ
+    """
+    YXYEx = ...
+    r"""
+    Extrinsic two-axis rotation YXY
+    """
+    YZYEx = ...
+    r"""
+    Extrinsic two-axis rotation YZY
+    """
+    XYXEx = ...
+    r"""
+    Extrinsic two-axis rotation XYX
+    """
+    XZXEx = ...
+    r"""
+    Extrinsic two-axis rotation XZX
+    """
+
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored by looking up the member by name
+        """
+    def __copy__(self) -> EulerRot: ...
+    def __deepcopy__(self, _memo: typing.Any) -> EulerRot: ...
+
+@typing.final
+class Mat3:
+    r"""
+    3x3 column major matrix
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x_axis(self) -> Vec3: ...
+    @x_axis.setter
+    def x_axis(self, value: Vec3) -> None: ...
+    @property
+    def y_axis(self) -> Vec3: ...
+    @y_axis.setter
+    def y_axis(self, value: Vec3) -> None: ...
+    @property
+    def z_axis(self) -> Vec3: ...
+    @z_axis.setter
+    def z_axis(self, value: Vec3) -> None: ...
+    def __new__(cls, x_axis: Vec3Like, y_axis: Vec3Like, z_axis: Vec3Like) -> Mat3:
+        r"""
+        Create a new matrix from three column vectors
+
+        # Arguments
+
+        - `x_axis` (`Vec3Like`) - first column
+        - `y_axis` (`Vec3Like`) - second column
+        - `z_axis` (`Vec3Like`) - third column
+
+        # Returns
+
+        - `Self` - Matrix with the given columns
+        """
+    @staticmethod
+    def from_cols(x_axis: Vec3Like, y_axis: Vec3Like, z_axis: Vec3Like) -> Mat3:
+        r"""
+        Create a new matrix from three column vectors
+        """
+    @staticmethod
+    def from_rows(row0: Vec3Like, row1: Vec3Like, row2: Vec3Like) -> Mat3:
+        r"""
+        Create a new matrix from three row vectors
+        """
+    @staticmethod
+    def from_diagonal(diagonal: Vec3Like) -> Mat3:
+        r"""
+        Create a matrix with the given diagonal and all other elements set to 0
+        """
+    @staticmethod
+    def from_quat(rotation: QuatLike) -> Mat3:
+        r"""
+        Create a rotation matrix from a quaternion
+
+        # Arguments
+
+        - `rotation` (`QuatLike`) - Rotation, must be normalized
+
+        # Returns
+
+        - `Self` - Rotation matrix equivalent to `rotation`
+        """
+    def col(self, index: builtins.int) -> Vec3:
+        r"""
+        Get a column of this matrix
+        """
+    def row(self, index: builtins.int) -> Vec3:
+        r"""
+        Get a row of this matrix
+        """
+    def to_tuple(
+        self,
+    ) -> tuple[
+        tuple[builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float],
+    ]:
+        r"""
+        Convert this matrix to a tuple of 3 column tuples
+
+        # Returns
+
+        - `((float, float, float), (float, float, float), (float, float, float))` - Column major tuple
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[Mat3, typing.Union[Vec3, DVec3]]:
+        r"""
+        Multiply this matrix with a scalar, another matrix or a vector
+
+        Mixing f32 and f64 operands returns f64
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - Scalar, mat3 or vec3 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmul__(self, lhs: typing.Any) -> Mat3:
+        r"""
+        Multiply a scalar or another matrix with this matrix, `lhs * self`
+
+        A vector on the left raises TypeError, vectors are transformed as `m * v`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - Scalar or mat3 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __matmul__(self, rhs: typing.Any) -> typing.Union[Mat3, typing.Union[Vec3, DVec3]]:
+        r"""
+        Matrix multiply this matrix with another matrix or a vector
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - mat3 or vec3 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmatmul__(self, lhs: typing.Any) -> Mat3:
+        r"""
+        Matrix multiply another matrix with this matrix, `lhs @ self`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - mat3 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __add__(self, rhs: Mat3) -> Mat3: ...
+    def __sub__(self, rhs: Mat3) -> Mat3: ...
+    def __neg__(self) -> Mat3: ...
+    def transpose(self) -> Mat3:
+        r"""
+        Returns the transpose of this matrix
+        """
+    def determinant(self) -> builtins.float:
+        r"""
+        Returns the determinant of this matrix
+        """
+    def inverse(self) -> Mat3:
+        r"""
+        Returns the inverse of this matrix
+
+        Only an exactly zero determinant raises, a nearly singular matrix inverts to very large or
+        non-finite components
+
+        # Returns
+
+        - `PyResult<Self>` - Inverse matrix, raises SingularMatrixError if this matrix is not invertible
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the column major components
+        """
+    def __copy__(self) -> Mat3:
+        r"""
+        Copy this matrix
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Mat3: ...
+
+@typing.final
+class Mat4:
+    r"""
+    4x4 column major matrix
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x_axis(self) -> Vec4: ...
+    @x_axis.setter
+    def x_axis(self, value: Vec4) -> None: ...
+    @property
+    def y_axis(self) -> Vec4: ...
+    @y_axis.setter
+    def y_axis(self, value: Vec4) -> None: ...
+    @property
+    def z_axis(self) -> Vec4: ...
+    @z_axis.setter
+    def z_axis(self, value: Vec4) -> None: ...
+    @property
+    def w_axis(self) -> Vec4: ...
+    @w_axis.setter
+    def w_axis(self, value: Vec4) -> None: ...
+    def __new__(
+        cls, x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4
+    ) -> Mat4:
+        r"""
+        Create a new matrix from four column vectors
+
+        # Arguments
+
+        - `x_axis` (`vec4`) - first column
+        - `y_axis` (`vec4`) - second column
+        - `z_axis` (`vec4`) - third column
+        - `w_axis` (`vec4`) - fourth column
+
+        # Returns
+
+        - `Self` - Matrix with the given columns
+        """
+    @staticmethod
+    def from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Mat4:
+        r"""
+        Create a new matrix from four column vectors
+        """
+    @staticmethod
+    def from_rows(row0: Vec4, row1: Vec4, row2: Vec4, row3: Vec4) -> Mat4:
+        r"""
+        Create a new matrix from four row vectors
+        """
+    @staticmethod
+    def from_scale_rotation_translation(
+        scale: Vec3Like, rotation: QuatLike, translation: Vec3Like
+    ) -> Mat4:
+        r"""
+        Create an affine transformation matrix from the given 3D `scale`, `rotation` and `translation`.
+
+        The resulting matrix applies scale first, then rotation, then translation.
+
+        # Arguments
+
+        - `scale` (`Vec3Like`) - Scale along each axis
+        - `rotation` (`QuatLike`) - Rotation, must be normalized
+        - `translation` (`Vec3Like`) - Translation
+
+        # Returns
+
+        - `Self` - Affine transformation matrix
+        """
+    @staticmethod
+    def look_at_rh(eye: Vec3Like, center: Vec3Like, up: Vec3Like) -> Mat4:
+        r"""
+        Create a right-handed view matrix using a camera position, a focal point and an up direction.
+
+        For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+
+        # Arguments
+
+        - `eye` (`Vec3Like`) - Camera position
+        - `center` (`Vec3Like`) - Focal point
+        - `up` (`Vec3Like`) - Up direction, must be normalized
+
+        # Returns
+
+        - `Self` - World to view matrix
+        """
+    @staticmethod
+    def look_at_lh(eye: Vec3Like, center: Vec3Like, up: Vec3Like) -> Mat4:
+        r"""
+        Create a left-handed view matrix using a camera position, a focal point and an up direction.
+
+        For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+
+        # Arguments
+
+        - `eye` (`Vec3Like`) - Camera position
+        - `center` (`Vec3Like`) - Focal point
+        - `up` (`Vec3Like`) - Up direction, must be normalized
+
+        # Returns
+
+        - `Self` - World to view matrix
+        """
+    @staticmethod
+    def perspective_rh(
+        fov_y_radians: builtins.float,
+        aspect_ratio: builtins.float,
+        z_near: builtins.float,
+        z_far: builtins.float,
+    ) -> Mat4:
+        r"""
+        Create a right-handed perspective projection matrix with `[0,1]` depth range.
+
+        # Arguments
+
+        - `fov_y_radians` (`float`) - Vertical field of view in radians
+        - `aspect_ratio` (`float`) - Width divided by height
+        - `z_near` (`float`) - Distance to the near plane, must be greater than 0
+        - `z_far` (`float`) - Distance to the far plane, must be greater than 0
+
+        # Returns
+
+        - `PyResult<Self>` - Projection matrix
+        """
+    @staticmethod
+    def orthographic_rh(
+        left: builtins.float,
+        right: builtins.float,
+        bottom: builtins.float,
+        top: builtins.float,
+        near: builtins.float,
+        far: builtins.float,
+    ) -> Mat4:
+        r"""
+        Create a right-handed orthographic projection matrix with `[0,1]` depth range.
+
+        # Arguments
+
+        - `left` (`float`) - Left plane
+        - `right` (`float`) - Right plane
+        - `bottom` (`float`) - Bottom plane
+        - `top` (`float`) - Top plane
+        - `near` (`float`) - Near plane
+        - `far` (`float`) - Far plane
+
+        # Returns
+
+        - `Self` - Projection matrix
+        """
+    def col(self, index: builtins.int) -> Vec4:
+        r"""
+        Get a column of this matrix
+        """
+    def row(self, index: builtins.int) -> Vec4:
+        r"""
+        Get a row of this matrix
+        """
+    def to_tuple(
+        self,
+    ) -> tuple[
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+        tuple[builtins.float, builtins.float, builtins.float, builtins.float],
+    ]:
+        r"""
+        Convert this matrix to a tuple of 4 column tuples
+
+        # Returns
+
+        - `((float, float, float, float), ...)` - Column major tuple
+        """
+    def to_scale_rotation_translation(self) -> tuple[Vec3, Quat, Vec3]:
+        r"""
+        Decompose this affine matrix into its scale, rotation and translation
+
+        The matrix must be an affine transform. A negative determinant, i.e. a reflection, gives a
+        negative x scale, only a determinant of exactly 0 raises
+
+        # Returns
+
+        - `(vec3, quat, vec3)` - scale, rotation and translation, raises SingularMatrixError if the determinant is 0
+        """
+    def transform_point3(self, point: Vec3) -> Vec3:
+        r"""
+        Transform a 3D point, applying translation. The perspective divide is not performed.
+        """
+    def transform_vector3(self, vector: Vec3) -> Vec3:
+        r"""
+        Transform a 3D direction, ignoring translation
+        """
+    def project_point3(self, point: Vec3) -> Vec3:
+        r"""
+        Transform a 3D point, applying the perspective divide. Use this with projection matrices
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[Mat4, typing.Union[Vec4, DVec4]]:
+        r"""
+        Multiply this matrix with a scalar, another matrix or a vector
+
+        Mixing f32 and f64 operands returns f64
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - Scalar, mat4 or vec4 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmul__(self, lhs: typing.Any) -> Mat4:
+        r"""
+        Multiply a scalar or another matrix with this matrix, `lhs * self`
+
+        A vector on the left raises TypeError, vectors are transformed as `m * v`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - Scalar or mat4 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __matmul__(self, rhs: typing.Any) -> typing.Union[Mat4, typing.Union[Vec4, DVec4]]:
+        r"""
+        Matrix multiply this matrix with another matrix or a vector
+
+        # Arguments
+
+        - `rhs` (`Bound<'_, PyAny>`) - mat4 or vec4 to multiply
+
+        # Returns
+
+        - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+        """
+    def __rmatmul__(self, lhs: typing.Any) -> Mat4:
+        r"""
+        Matrix multiply another matrix with this matrix, `lhs @ self`
+
+        # Arguments
+
+        - `lhs` (`Bound<'_, PyAny>`) - mat4 to multiply
+
+        # Returns
+
+        - `PyResult<Self>` - New matrix
+        """
+    def __add__(self, rhs: Mat4) -> Mat4: ...
+    def __sub__(self, rhs: Mat4) -> Mat4: ...
+    def __neg__(self) -> Mat4: ...
+    def transpose(self) -> Mat4:
+        r"""
+        Returns the transpose of this matrix
+        """
+    def determinant(self) -> builtins.float:
+        r"""
+        Returns the determinant of this matrix
+        """
+    def inverse(self) -> Mat4:
+        r"""
+        Returns the inverse of this matrix
+
+        Only an exactly zero determinant raises, a nearly singular matrix inverts to very large or
+        non-finite components
+
+        # Returns
+
+        - `PyResult<Self>` - Inverse matrix, raises SingularMatrixError if this matrix is not invertible
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the column major components
+        """
+    def __copy__(self) -> Mat4:
+        r"""
+        Copy this matrix
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Mat4: ...
+
+class NormalizationError(PyglamError):
+    r"""
+    Raised when normalizing a vector or quaternion whose length is zero, infinite or NaN
+    """
+    ...
+
+class PyglamError(builtins.ValueError):
+    r"""
+    Base class of the domain errors raised by pyglam
+    """
+    ...
+
+@typing.final
+class Quat:
+    r"""
+    4 Component Quaternion wxyz
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+    """
+    IDENTITY: typing.ClassVar[Quat]
+    r"""
+    The identity rotation
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    def __new__(
+        cls, x: builtins.float, y: builtins.float, z: builtins.float, w: builtins.float
+    ) -> Quat:
+        r"""
+        Create a new quaternion from components.
+        Usually you want `from_axis_angle` or `from_rotation_arc` instead of this
+
+        # Arguments
+
+        - `x` (`float`) - x component
+        - `y` (`float`) - y component
+        - `z` (`float`) - z component
+        - `w` (`float`) - scalar component
+
+        # Returns
+
+        - `PyResult<Self>` - Describe the return value.
+        """
+    @staticmethod
+    def rand_uniform() -> Quat:
+        r"""
+        Generates a random rotation, uniformly distributed over all rotations
+        """
+    @staticmethod
+    def from_axis_angle(axis: Vec3Like, angle: builtins.float) -> Quat:
+        r"""
+        Create a new quaternion from an axis and angle
+
+        # Arguments
+
+        - `axis` (`Vec3Like`) - Axis, should be normalized
+        - `angle` (`float`) - Angle in radians
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing this axis/angle rotation
+        """
+    @staticmethod
+    def from_scaled_axis(v: Vec3Like) -> Quat:
+        r"""
+        Create a quaternion from a rotation vector, rotating `v.length()` radians about `v.normalize()`
+
+        A zero vector gives the identity rotation
+
+        # Arguments
+
+        - `v` (`Vec3Like`) - Rotation vector, also known as scaled axis
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing this rotation
+        """
+    @staticmethod
+    def from_rotation_x(angle: builtins.float) -> Quat:
+        r"""
+        Create a quaternion rotating `angle` radians about the x axis
+        """
+    @staticmethod
+    def from_rotation_y(angle: builtins.float) -> Quat:
+        r"""
+        Create a quaternion rotating `angle` radians about the y axis
+        """
+    @staticmethod
+    def from_rotation_z(angle: builtins.float) -> Quat:
+        r"""
+        Create a quaternion rotating `angle` radians about the z axis
+        """
+    @staticmethod
+    def from_rotation_arc(from_: Vec3Like, to: Vec3Like) -> Quat:
+        r"""
+        Gets the minimal rotation for transforming `from` to `to`.  The rotation is in the
+        plane spanned by the two vectors.  Will rotate at most 180 degrees.
+
+        `from_rotation_arc(from, to) * from ≈ to`.
+
+        For near-singular cases (from≈to and from≈-to) the current implementation
+        is only accurate to about 0.001 (for `f32`).
+
+        # Arguments
+
+        - `from_` (`Vec3Like`) - starting vector. Must be a unit vector
+        - `to` (`Vec3Like`) - ending vector. Must be a unit vector
+
+        # Returns
+
+        - `PyResult<Self>` - Returns a quaternion that would rotate vector from onto to
+        """
+    @staticmethod
+    def from_mat3(mat: Mat3) -> Quat:
+        r"""
+        Create a quaternion from a 3x3 rotation matrix
+
+        # Arguments
+
+        - `mat` (`mat3`) - Rotation matrix, must not contain scale or shear
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing the same rotation as `mat`
+        """
+    def to_mat3(self) -> Mat3:
+        r"""
+        Convert this quaternion into a 3x3 rotation matrix
+        """
+    def to_axis_angle(self) -> tuple[Vec3, builtins.float]:
+        r"""
+        Decompose this quaternion into a rotation axis and angle
+
+        # Returns
+
+        - `(vec3, float)` - Normalized axis and angle in radians
+        """
+    def to_scaled_axis(self) -> Vec3:
+        r"""
+        Convert this quaternion into a rotation vector, the axis scaled by the angle in radians
+        """
+    @staticmethod
+    def from_euler(
+        order: typing.Union[EulerRot, builtins.str],
+        a: builtins.float,
+        b: builtins.float,
+        c: builtins.float,
+        degrees: builtins.bool = False,
+    ) -> Quat:
+        r"""
+        Create a quaternion from euler angles applied in the given order
+
+        # Arguments
+
+        - `order` (`EulerRot | str`) - Rotation sequence, e.g. `EulerRot.ZYX` or `"ZYX"` for yaw-pitch-roll
+        - `a` (`float`) - Angle about the first axis of `order`
+        - `b` (`float`) - Angle about the second axis of `order`
+        - `c` (`float`) - Angle about the third axis of `order`
+        - `degrees` (`bool`) - Angles are in degrees instead of radians
+
+        # Returns
+
+        - `PyResult<Self>` - Quaternion describing the combined rotation
+        """
+    def to_euler(
+        self, order: typing.Union[EulerRot, builtins.str], degrees: builtins.bool = False
+    ) -> tuple[builtins.float, builtins.float, builtins.float]:
+        r"""
+        Decompose this quaternion into euler angles in the given order
+
+        Near gimbal lock the decomposition isn't unique. This happens when the middle angle
+        is ±90 degrees for three-axis orders, or 0 or 180 degrees for two-axis orders such as `ZYZ`.
+        One of the outer angles is then set to 0 and the other carries the whole rotation about
+        the aligned axes, so `from_euler(order, *to_euler(order))` still gives back the same rotation
+
+        # Arguments
+
+        - `order` (`EulerRot | str`) - Rotation sequence, e.g. `EulerRot.ZYX` or `"ZYX"` for yaw-pitch-roll
+        - `degrees` (`bool`) - Return the angles in degrees instead of radians
+
+        # Returns
+
+        - `PyResult<(float, float, float)>` - Angles about the first, second and third axis of `order`
+        """
+    def to_tuple(
+        self,
+    ) -> tuple[builtins.float, builtins.float, builtins.float, builtins.float]:
+        r"""
+        Convert this quat to a 4 component tuple
+
+        # Returns
+
+        - `(float, float, float, float)` - XYZW tuple
+        """
+    def as_dquat(self) -> DQuat:
+        r"""
+        Convert this quat to f64 precision
+
+        # Returns
+
+        - `DQuat` - Copy with every component widened to f64
+        """
+    def as_quat(self) -> Quat:
+        r"""
+        Convert this quat to f32 precision
+
+        # Returns
+
+        - `Quat` - Copy with every component rounded to the nearest f32
+        """
+    @staticmethod
+    def from_numpy(array: numpy.typing.ArrayLike) -> Quat:
+        r"""
+        Create a quaternion from a numpy array of 4 xyzw components
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 1-D array of length 4, other dtypes are cast to this type's precision
+
+        # Returns
+
+        - `quat` - New quaternion holding a copy of the components
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        View this quaternion as a numpy array, see `numpy.asarray`
+
+        Without a dtype conversion or `copy=True` the array is a read-only view of this quaternion
+
+        # Arguments
+
+        - `dtype` (`numpy.dtype | None`) - Requested dtype, defaults to this type's precision
+        - `copy` (`bool | None`) - True to always copy, False to raise ValueError instead of copying
+
+        # Returns
+
+        - `numpy.ndarray` - 1-D array of the xyzw components
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any quat, regardless of precision
+
+        `q` and `-q` describe the same rotation but are not equal.
+        Follows float semantics, so a quat containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def is_close(
+        self,
+        other: QuatLike,
+        rel_tol: builtins.float = 1e-09,
+        abs_tol: builtins.float = 0.0,
+    ) -> builtins.bool:
+        r"""
+        Determine whether this quat describes a rotation close to `other`, following `math.isclose`
+
+        `q` and `-q` describe the same rotation, so `other` is also compared with its sign flipped
+
+        # Arguments
+
+        - `other` (`QuatLike`) - Quaternion to compare against, any quat or xyzw sequence
+        - `rel_tol` (`float`) - Maximum difference relative to the larger absolute component
+        - `abs_tol` (`float`) - Minimum absolute tolerance, useful for comparisons near zero
+
+        # Returns
+
+        - `bool` - True if every component pair of `other` or `-other` is close
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __mul__(
+        self, rhs: typing.Union[QuatLike, Vec3Like]
+    ) -> typing.Union[typing.Union[Quat, Vec3], typing.Union[QuatArray, DVec3]]:
+        r"""
+        Multiply this quaternion with another quaternion, a vector or a quaternion array
+
+        A quaternion multiplication means combining two rotations into a single rotation
+        A vector multiplication yields a rotated vec3
+        A quaternion array multiplication combines this rotation with every element
+
+        # Arguments
+
+        - `rhs` (`typing.Union[QuatLike, Vec3Like, quat array]`) - Quat, Vec or quat array to multiply, 4 component sequences are treated as xyzw quats
+
+        # Returns
+
+        - `PyResult<Either<Either<, >, >>` - Either a new rotation, a rotated vector or an array of combined rotations
+        """
+    def __rmul__(self, lhs: QuatLike) -> Quat:
+        r"""
+        Multiply a quaternion on the left with this quaternion, `lhs * self`
+
+        Combines the rotations, so the result rotates by this quaternion first and then by `lhs`.
+        A vector on the left, `v * q`, raises TypeError since it's ambiguous whether it should
+        rotate or inverse rotate, use `q * v`, `q.rotate(v)` or `q.inverse_rotate(v)` instead
+
+        # Arguments
+
+        - `lhs` (`QuatLike`) - Left hand side rotation, 4 component sequences are treated as xyzw quats
+
+        # Returns
+
+        - `PyResult<Self>` - Combined rotation
+        """
+    def rotate(self, v: Vec3Like) -> Vec3:
+        r"""
+        Rotate a vector by this quaternion, same as `self * v`
+
+        # Arguments
+
+        - `v` (`Vec3Like`) - Vector to rotate
+
+        # Returns
+
+        - `PyResult<vec3>` - Rotated vector
+        """
+    def inverse_rotate(self, v: Vec3Like) -> Vec3:
+        r"""
+        Rotate a vector by the inverse of this quaternion, undoing `rotate`
+
+        This quaternion must be normalized
+
+        # Arguments
+
+        - `v` (`Vec3Like`) - Vector to rotate
+
+        # Returns
+
+        - `PyResult<vec3>` - Inverse rotated vector
+        """
+    def then(self, other: QuatLike) -> Quat:
+        r"""
+        Combine this rotation with a rotation applied after it, same as `other * self`
+
+        `a.then(b).then(c)` reads in the order the rotations are applied
+
+        # Arguments
+
+        - `other` (`QuatLike`) - Rotation applied after this one
+
+        # Returns
+
+        - `PyResult<Self>` - Combined rotation
+        """
+    def normalize(self) -> Quat:
+        r"""
+        Normalize this quaternion into a unit quat
+
+        # Returns
+
+        - `PyResult<Self>` - Unit quat, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def conjugate(self) -> Quat:
+        r"""
+        Compute the conjugate of this quat.
+        If this is a unit quat, the conjugate is equal to the inverse of the rotation
+        """
+    def inverse(self) -> Quat:
+        r"""
+        Compute the inverse rotation, this quat must be normalized
+        """
+    def dot(self, rhs: QuatLike) -> builtins.float:
+        r"""
+        Dot product of this quat and `rhs`, the cosine of half the angle between unit rotations
+
+        # Arguments
+
+        - `rhs` (`QuatLike`) - Other quaternion, any quat or xyzw sequence
+
+        # Returns
+
+        - `float` - Dot product of the xyzw components
+        """
+    def length(self) -> builtins.float:
+        r"""
+        Length of this quaternion, 1 for a unit quat
+        """
+    def is_normalized(self) -> builtins.bool:
+        r"""
+        Whether this quaternion has a length of 1, i.e. its squared length is within `2e-4` of 1
+        """
+    def is_near_identity(self) -> builtins.bool:
+        r"""
+        Whether this quaternion rotates by less than roughly 0.0028 radians, treating `q` and `-q` alike
+        """
+    def angle_between(self, rhs: QuatLike) -> builtins.float:
+        r"""
+        Angle in radians of the shortest rotation from this quat to `rhs`, both must be normalized
+
+        # Arguments
+
+        - `rhs` (`QuatLike`) - Other rotation
+
+        # Returns
+
+        - `float` - Angle between the two rotations in `[0, pi]`
+        """
+    def slerp(self, end: QuatLike, t: builtins.float) -> Quat:
+        r"""
+        Spherical linear interpolation between this quat and `end`
+
+        Follows the shortest path, `end` is negated when the quats are more than 180 degrees apart
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`, both quats should be normalized
+        - `t` (`float`) - Interpolation factor, 0 returns this quat and 1 returns `end`
+
+        # Returns
+
+        - `PyResult<Self>` - Interpolated rotation
+        """
+    def lerp(self, end: QuatLike, t: builtins.float) -> Quat:
+        r"""
+        Normalized linear interpolation between this quat and `end`
+
+        Cheaper than `slerp` but the angular velocity isn't constant.
+        Follows the shortest path, `end` is negated when the quats are more than 180 degrees apart
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`, both quats should be normalized
+        - `t` (`float`) - Interpolation factor, 0 returns this quat and 1 returns `end`
+
+        # Returns
+
+        - `PyResult<Self>` - Interpolated unit rotation
+        """
+    def nlerp(self, end: QuatLike, t: builtins.float) -> Quat:
+        r"""
+        Alias of `lerp`, the result is always normalized
+        """
+    @staticmethod
+    def squad(
+        q0: QuatLike, a: QuatLike, b: QuatLike, q1: QuatLike, t: builtins.float
+    ) -> Quat:
+        r"""
+        Spherical cubic interpolation between `q0` and `q1` through the control quats `a` and `b`
+
+        Computed as `slerp(slerp(q0, q1, t), slerp(a, b, t), 2t(1 - t))`, so every slerp follows the shortest path
+
+        # Arguments
+
+        - `q0` (`QuatLike`) - Rotation at `t = 0`
+        - `a` (`QuatLike`) - Control rotation for `q0`
+        - `b` (`QuatLike`) - Control rotation for `q1`
+        - `q1` (`QuatLike`) - Rotation at `t = 1`
+        - `t` (`float`) - Interpolation factor
+
+        # Returns
+
+        - `PyResult<Self>` - Interpolated rotation
+        """
+    def slerp_many(
+        self, end: QuatLike, ts: typing.Sequence[builtins.float]
+    ) -> QuatArray:
+        r"""
+        Spherical linear interpolation at every value of `ts`, see `slerp`
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`
+        - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+
+        # Returns
+
+        - `PyResult<quat array>` - One interpolated rotation per factor
+        """
+    def nlerp_many(
+        self, end: QuatLike, ts: typing.Sequence[builtins.float]
+    ) -> QuatArray:
+        r"""
+        Normalized linear interpolation at every value of `ts`, see `lerp`
+
+        # Arguments
+
+        - `end` (`QuatLike`) - Rotation at `t = 1`
+        - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+
+        # Returns
+
+        - `PyResult<quat array>` - One interpolated rotation per factor
+        """
+    @staticmethod
+    def squad_many(
+        q0: QuatLike,
+        a: QuatLike,
+        b: QuatLike,
+        q1: QuatLike,
+        ts: typing.Sequence[builtins.float],
+    ) -> QuatArray:
+        r"""
+        Spherical cubic interpolation at every value of `ts`, see `squad`
+
+        # Arguments
+
+        - `q0` (`QuatLike`) - Rotation at `t = 0`
+        - `a` (`QuatLike`) - Control rotation for `q0`
+        - `b` (`QuatLike`) - Control rotation for `q1`
+        - `q1` (`QuatLike`) - Rotation at `t = 1`
+        - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+
+        # Returns
+
+        - `PyResult<quat array>` - One interpolated rotation per factor
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the xyzw components
+        """
+    def __copy__(self) -> Quat:
+        r"""
+        Copy this quaternion
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Quat: ...
+
 @typing.final
-class DQuat:
+class QuatArray:
+    r"""
+    Contiguous array of quaternions for rotating and composing many rotations at once
+
+    Arithmetic doesn't mix precisions, f32 arrays refuse DQuat and DVec3 operands and arrays only
+    combine with arrays of the same precision. Convert with `quat_array(array, dtype=...)` first
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    def __new__(cls, quats: typing.Optional[typing.Any] = None) -> QuatArray:
+        r"""
+        Create an array from an iterable of quaternions
+
+        # Arguments
+
+        - `quats` (`Iterable[QuatLike] | None`) - Quaternions or xyzw sequences, or an array with shape `(N, 4)`, empty if not given
+
+        # Returns
+
+        - `PyResult<Self>` - Array holding a copy of each quaternion
+        """
+    @staticmethod
+    def identity(len: builtins.int) -> QuatArray:
+        r"""
+        Create an array of `len` identity rotations
+        """
+    @staticmethod
+    def from_numpy(
+        array: numpy.typing.ArrayLike, dtype: typing.Any = None
+    ) -> typing.Union[DQuatArray, QuatArray]:
+        r"""
+        Create an array from a numpy array with shape `(N, 4)` in xyzw order
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 2-D array of any float or integer dtype
+        - `dtype` (`str | numpy.dtype | None`) - Precision of the new array, `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `DQuatArray | QuatArray` - Array holding a copy of each row
+        """
+    def to_numpy(self, dtype: typing.Any = None) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        Copy this array into a numpy array with shape `(N, 4)` in xyzw order
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `numpy.ndarray` - New array holding the components of every quaternion
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> typing.Any:
+        r"""
+        Copy this array into a numpy array, numpy's `__array__` protocol
+
+        The storage can't be shared, so `copy=False` raises ValueError
+        """
+    def to_list(self) -> builtins.list[Quat]:
+        r"""
+        Copy this array into a list of quaternions
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(self, index: typing.Any) -> typing.Union[Quat, QuatArray]:
+        r"""
+        Get a quaternion by index, or a new array by slice
+        """
+    def __setitem__(self, index: builtins.int, value: QuatLike) -> None:
+        r"""
+        Set a quaternion by index, raises IndexError if out of range
+        """
+    def __mul__(self, rhs: typing.Any) -> typing.Union[QuatArray, Vec3Array]:
+        r"""
+        Elementwise multiplication with quaternions or vectors
+
+        Quaternion operands compose rotations, `self[i] * rhs` or `self[i] * rhs[i]` for an array.
+        Vector operands are rotated, a single vector by every rotation or a vec3 array elementwise
+
+        # Arguments
+
+        - `rhs` (`QuatLike | Vec3Like | quat array | vec3 array`) - Right hand side multiplicand
+
+        # Returns
+
+        - `quat array | vec3 array` - Composed rotations or rotated vectors
+        """
+    def __rmul__(self, lhs: QuatLike) -> QuatArray:
+        r"""
+        Compose a quaternion with every rotation in this array, `lhs * self[i]`
+
+        # Arguments
+
+        - `lhs` (`QuatLike`) - Left hand side rotation
+
+        # Returns
+
+        - `quat array` - Composed rotations
+        """
+    def normalize(self) -> QuatArray:
+        r"""
+        Normalize every quaternion into a unit quat
+        """
+    def conjugate(self) -> QuatArray:
+        r"""
+        Conjugate of every quaternion, equal to the inverse for unit quats
+        """
+    def inverse(self) -> QuatArray:
+        r"""
+        Inverse of every quaternion, the quaternions must be normalized
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged buffer of little endian xyzw components
+        """
+    def __copy__(self) -> QuatArray: ...
+    def __deepcopy__(self, _memo: typing.Any) -> QuatArray: ...
+
+@typing.final
+class Rng:
+    r"""
+    Seedable random number generator for reproducible sampling
+
+    Uses ChaCha8, whose output for a given seed is stable across platforms and releases
+    """
+    def __new__(cls, seed: typing.Optional[builtins.int] = None) -> Rng:
+        r"""
+        Create a generator
+
+        # Arguments
+
+        - `seed` (`int | None`) - Seed for a reproducible sequence, seeded from OS randomness if not given
+
+        # Returns
+
+        - `Self` - New generator
+        """
+    def seed(self, seed: typing.Optional[builtins.int] = None) -> None:
+        r"""
+        Reseed this generator, restarting its sequence
+
+        # Arguments
+
+        - `seed` (`int | None`) - Seed for a reproducible sequence, seeded from OS randomness if not given
+        """
+    def getstate(self) -> tuple[builtins.bytes, builtins.int, builtins.int]:
+        r"""
+        Capture the state of this generator, see `setstate`
+
+        # Returns
+
+        - `(bytes, int, int)` - 32 byte seed, stream and word position
+        """
+    def setstate(
+        self, state: tuple[builtins.bytes, builtins.int, builtins.int]
+    ) -> None:
+        r"""
+        Restore a state captured by `getstate`, continuing that sequence
+
+        # Arguments
+
+        - `state` (`(bytes, int, int)`) - 32 byte seed, stream and word position
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged `getstate` tuple
+        """
+    def __copy__(self) -> Rng:
+        r"""
+        Copy this generator, the copy continues the same sequence independently
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Rng: ...
+    def random(self) -> builtins.float:
+        r"""
+        Generate a random float uniformly distributed in `[0, 1)`
+        """
+    def rand_uv(self, dtype: typing.Any = None) -> typing.Union[DVec3, Vec3]:
+        r"""
+        Generate a random unit vector, uniformly distributed over the sphere
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+
+        # Returns
+
+        - `DVec3 | Vec3` - Random unit vector
+        """
+    def rand_in_ball(self, dtype: typing.Any = None) -> typing.Union[DVec3, Vec3]:
+        r"""
+        Generate a random vector, uniformly distributed inside the unit ball
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+
+        # Returns
+
+        - `DVec3 | Vec3` - Random vector with a length of at most 1
+        """
+    def rand_in_cube(self, dtype: typing.Any = None) -> typing.Union[DVec3, Vec3]:
+        r"""
+        Generate a random vector, uniformly distributed inside the cube spanning -1 to 1 on every axis
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+
+        # Returns
+
+        - `DVec3 | Vec3` - Random vector with every component in `[-1, 1]`
+        """
+    def rand_gaussian(
+        self, std_dev: builtins.float = 1.0, dtype: typing.Any = None
+    ) -> typing.Union[DVec3, Vec3]:
+        r"""
+        Generate a random vector with independent normally distributed components
+
+        # Arguments
+
+        - `std_dev` (`float`) - Standard deviation of each component, the mean is 0
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+
+        # Returns
+
+        - `DVec3 | Vec3` - Random vector
+        """
+    def rand_in_cone(
+        self, axis: Vec3Like, half_angle: builtins.float, dtype: typing.Any = None
+    ) -> typing.Union[DVec3, Vec3]:
+        r"""
+        Generate a random unit vector, uniformly distributed within `half_angle` of `axis`
+
+        # Arguments
+
+        - `axis` (`Vec3Like`) - Center of the cone, normalized before sampling
+        - `half_angle` (`float`) - Maximum angle from `axis` in radians, `pi` covers the whole sphere
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+
+        # Returns
+
+        - `DVec3 | Vec3` - Random unit vector, raises NormalizationError if `axis` is zero or not finite
+        """
+    def rand_uniform(self, dtype: typing.Any = None) -> typing.Union[DQuat, Quat]:
+        r"""
+        Generate a random rotation, uniformly distributed over all rotations
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+
+        # Returns
+
+        - `DQuat | Quat` - Random unit quaternion
+        """
+
+class SingularMatrixError(PyglamError):
+    r"""
+    Raised when inverting or decomposing a matrix or transform whose determinant is zero
+    """
+    ...
+
+@typing.final
+class Vec2:
+    r"""
+    2 Component vector xy
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x(self) -> builtins.float: ...
+    @x.setter
+    def x(self, value: builtins.float) -> None: ...
+    @property
+    def y(self) -> builtins.float: ...
+    @y.setter
+    def y(self, value: builtins.float) -> None: ...
+    def __new__(
+        cls, x: builtins.float, y: typing.Optional[builtins.float] = None
+    ) -> Vec2: ...
+    def to_tuple(self) -> tuple[builtins.float, builtins.float]:
+        r"""
+        Convert this vector to a 2 component tuple
+
+        # Returns
+
+        - `(float, float)` - XY tuple
+        """
+    def as_dvec2(self) -> DVec2:
+        r"""
+        Convert this vector to f64 precision
+
+        # Returns
+
+        - `DVec2` - Copy with every component widened to f64
+        """
+    def as_vec2(self) -> Vec2:
+        r"""
+        Convert this vector to f32 precision
+
+        # Returns
+
+        - `Vec2` - Copy with every component rounded to the nearest f32
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any vec2, regardless of precision
+
+        Follows float semantics, so a vector containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __add__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __radd__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __sub__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __rsub__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __mul__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __rmul__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __truediv__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __rtruediv__(self, lhs: typing.Union[Vec2Like, builtins.float]) -> Vec2: ...
+    def __iadd__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __isub__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __imul__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __itruediv__(self, rhs: typing.Union[Vec2Like, builtins.float]) -> None: ...
+    def __neg__(self) -> Vec2: ...
+    def normalize(self) -> Vec2:
+        r"""
+        Normalize this vector to unit length
+
+        # Returns
+
+        - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def length(self) -> builtins.float: ...
+    def dot(self, rhs: Vec2Like) -> builtins.float:
+        r"""
+        Computes the dot product of this vector and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec2Like`) - Other vector
+
+        # Returns
+
+        - `float` - Dot product
+        """
+    def perp_dot(self, rhs: Vec2Like) -> builtins.float:
+        r"""
+        The perpendicular dot product of this vector and `rhs`.
+        Also known as the wedge product, 2D cross product, and determinant.
+
+        # Arguments
+
+        - `rhs` (`Vec2Like`) - Other vector
+
+        # Returns
+
+        - `float` - `x * rhs.y - y * rhs.x`
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the components
+        """
+    def __copy__(self) -> Vec2:
+        r"""
+        Copy this vector
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Vec2: ...
+
+@typing.final
+class Vec3:
+    r"""
+    3 Component vector xyz
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+
+    Swizzles such as `v.zyx` or `v.xy` read components in any order and return a vec of that length,
+    the ones without repeated components can also be assigned, e.g. `v.xy = (1.0, 2.0)`
+    """
+    ZERO: typing.ClassVar[Vec3]
+    r"""
+    All zeros
+    """
+    ONE: typing.ClassVar[Vec3]
+    r"""
+    All ones
+    """
+    NEG_ONE: typing.ClassVar[Vec3]
+    r"""
+    All negative ones
+    """
+    X: typing.ClassVar[Vec3]
+    r"""
+    Unit vector along the positive x axis
+    """
+    Y: typing.ClassVar[Vec3]
+    r"""
+    Unit vector along the positive y axis
+    """
+    Z: typing.ClassVar[Vec3]
     r"""
-    4 Component Quaternion wxyz
+    Unit vector along the positive z axis
+    """
+    NEG_X: typing.ClassVar[Vec3]
+    r"""
+    Unit vector along the negative x axis
+    """
+    NEG_Y: typing.ClassVar[Vec3]
+    r"""
+    Unit vector along the negative y axis
+    """
+    NEG_Z: typing.ClassVar[Vec3]
+    r"""
+    Unit vector along the negative z axis
     """
+    NAN: typing.ClassVar[Vec3]
+    r"""
+    All NaN
+    """
+    INFINITY: typing.ClassVar[Vec3]
+    r"""
+    All positive infinity
+    """
+    NEG_INFINITY: typing.ClassVar[Vec3]
+    r"""
+    All negative infinity
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
+    def x(self) -> builtins.float: ...
+    @x.setter
+    def x(self, value: builtins.float) -> None: ...
+    @property
+    def y(self) -> builtins.float: ...
+    @y.setter
+    def y(self, value: builtins.float) -> None: ...
+    @property
+    def z(self) -> builtins.float: ...
+    @z.setter
+    def z(self, value: builtins.float) -> None: ...
+    @property
+    def xx(self) -> Vec2: ...
+    @property
+    def xy(self) -> Vec2: ...
+    @xy.setter
+    def xy(self, value: Vec2Like) -> None: ...
+    @property
+    def xz(self) -> Vec2: ...
+    @xz.setter
+    def xz(self, value: Vec2Like) -> None: ...
+    @property
+    def yx(self) -> Vec2: ...
+    @yx.setter
+    def yx(self, value: Vec2Like) -> None: ...
+    @property
+    def yy(self) -> Vec2: ...
+    @property
+    def yz(self) -> Vec2: ...
+    @yz.setter
+    def yz(self, value: Vec2Like) -> None: ...
+    @property
+    def zx(self) -> Vec2: ...
+    @zx.setter
+    def zx(self, value: Vec2Like) -> None: ...
+    @property
+    def zy(self) -> Vec2: ...
+    @zy.setter
+    def zy(self, value: Vec2Like) -> None: ...
+    @property
+    def zz(self) -> Vec2: ...
+    @property
+    def xxx(self) -> Vec3: ...
+    @property
+    def xxy(self) -> Vec3: ...
+    @property
+    def xxz(self) -> Vec3: ...
+    @property
+    def xyx(self) -> Vec3: ...
+    @property
+    def xyy(self) -> Vec3: ...
+    @property
+    def xyz(self) -> Vec3: ...
+    @xyz.setter
+    def xyz(self, value: Vec3Like) -> None: ...
+    @property
+    def xzx(self) -> Vec3: ...
+    @property
+    def xzy(self) -> Vec3: ...
+    @xzy.setter
+    def xzy(self, value: Vec3Like) -> None: ...
+    @property
+    def xzz(self) -> Vec3: ...
+    @property
+    def yxx(self) -> Vec3: ...
+    @property
+    def yxy(self) -> Vec3: ...
+    @property
+    def yxz(self) -> Vec3: ...
+    @yxz.setter
+    def yxz(self, value: Vec3Like) -> None: ...
+    @property
+    def yyx(self) -> Vec3: ...
+    @property
+    def yyy(self) -> Vec3: ...
+    @property
+    def yyz(self) -> Vec3: ...
+    @property
+    def yzx(self) -> Vec3: ...
+    @yzx.setter
+    def yzx(self, value: Vec3Like) -> None: ...
+    @property
+    def yzy(self) -> Vec3: ...
+    @property
+    def yzz(self) -> Vec3: ...
+    @property
+    def zxx(self) -> Vec3: ...
+    @property
+    def zxy(self) -> Vec3: ...
+    @zxy.setter
+    def zxy(self, value: Vec3Like) -> None: ...
+    @property
+    def zxz(self) -> Vec3: ...
+    @property
+    def zyx(self) -> Vec3: ...
+    @zyx.setter
+    def zyx(self, value: Vec3Like) -> None: ...
+    @property
+    def zyy(self) -> Vec3: ...
+    @property
+    def zyz(self) -> Vec3: ...
+    @property
+    def zzx(self) -> Vec3: ...
+    @property
+    def zzy(self) -> Vec3: ...
+    @property
+    def zzz(self) -> Vec3: ...
+    @property
+    def xxxx(self) -> Vec4: ...
+    @property
+    def xxxy(self) -> Vec4: ...
+    @property
+    def xxxz(self) -> Vec4: ...
+    @property
+    def xxyx(self) -> Vec4: ...
+    @property
+    def xxyy(self) -> Vec4: ...
+    @property
+    def xxyz(self) -> Vec4: ...
+    @property
+    def xxzx(self) -> Vec4: ...
+    @property
+    def xxzy(self) -> Vec4: ...
+    @property
+    def xxzz(self) -> Vec4: ...
+    @property
+    def xyxx(self) -> Vec4: ...
+    @property
+    def xyxy(self) -> Vec4: ...
+    @property
+    def xyxz(self) -> Vec4: ...
+    @property
+    def xyyx(self) -> Vec4: ...
+    @property
+    def xyyy(self) -> Vec4: ...
+    @property
+    def xyyz(self) -> Vec4: ...
+    @property
+    def xyzx(self) -> Vec4: ...
+    @property
+    def xyzy(self) -> Vec4: ...
+    @property
+    def xyzz(self) -> Vec4: ...
+    @property
+    def xzxx(self) -> Vec4: ...
+    @property
+    def xzxy(self) -> Vec4: ...
+    @property
+    def xzxz(self) -> Vec4: ...
+    @property
+    def xzyx(self) -> Vec4: ...
+    @property
+    def xzyy(self) -> Vec4: ...
+    @property
+    def xzyz(self) -> Vec4: ...
+    @property
+    def xzzx(self) -> Vec4: ...
+    @property
+    def xzzy(self) -> Vec4: ...
+    @property
+    def xzzz(self) -> Vec4: ...
+    @property
+    def yxxx(self) -> Vec4: ...
+    @property
+    def yxxy(self) -> Vec4: ...
+    @property
+    def yxxz(self) -> Vec4: ...
+    @property
+    def yxyx(self) -> Vec4: ...
+    @property
+    def yxyy(self) -> Vec4: ...
+    @property
+    def yxyz(self) -> Vec4: ...
+    @property
+    def yxzx(self) -> Vec4: ...
+    @property
+    def yxzy(self) -> Vec4: ...
+    @property
+    def yxzz(self) -> Vec4: ...
+    @property
+    def yyxx(self) -> Vec4: ...
+    @property
+    def yyxy(self) -> Vec4: ...
+    @property
+    def yyxz(self) -> Vec4: ...
+    @property
+    def yyyx(self) -> Vec4: ...
+    @property
+    def yyyy(self) -> Vec4: ...
+    @property
+    def yyyz(self) -> Vec4: ...
+    @property
+    def yyzx(self) -> Vec4: ...
+    @property
+    def yyzy(self) -> Vec4: ...
+    @property
+    def yyzz(self) -> Vec4: ...
+    @property
+    def yzxx(self) -> Vec4: ...
+    @property
+    def yzxy(self) -> Vec4: ...
+    @property
+    def yzxz(self) -> Vec4: ...
+    @property
+    def yzyx(self) -> Vec4: ...
+    @property
+    def yzyy(self) -> Vec4: ...
+    @property
+    def yzyz(self) -> Vec4: ...
+    @property
+    def yzzx(self) -> Vec4: ...
+    @property
+    def yzzy(self) -> Vec4: ...
+    @property
+    def yzzz(self) -> Vec4: ...
+    @property
+    def zxxx(self) -> Vec4: ...
+    @property
+    def zxxy(self) -> Vec4: ...
+    @property
+    def zxxz(self) -> Vec4: ...
+    @property
+    def zxyx(self) -> Vec4: ...
+    @property
+    def zxyy(self) -> Vec4: ...
+    @property
+    def zxyz(self) -> Vec4: ...
+    @property
+    def zxzx(self) -> Vec4: ...
+    @property
+    def zxzy(self) -> Vec4: ...
+    @property
+    def zxzz(self) -> Vec4: ...
+    @property
+    def zyxx(self) -> Vec4: ...
+    @property
+    def zyxy(self) -> Vec4: ...
+    @property
+    def zyxz(self) -> Vec4: ...
+    @property
+    def zyyx(self) -> Vec4: ...
+    @property
+    def zyyy(self) -> Vec4: ...
+    @property
+    def zyyz(self) -> Vec4: ...
+    @property
+    def zyzx(self) -> Vec4: ...
+    @property
+    def zyzy(self) -> Vec4: ...
+    @property
+    def zyzz(self) -> Vec4: ...
+    @property
+    def zzxx(self) -> Vec4: ...
+    @property
+    def zzxy(self) -> Vec4: ...
+    @property
+    def zzxz(self) -> Vec4: ...
+    @property
+    def zzyx(self) -> Vec4: ...
+    @property
+    def zzyy(self) -> Vec4: ...
+    @property
+    def zzyz(self) -> Vec4: ...
+    @property
+    def zzzx(self) -> Vec4: ...
+    @property
+    def zzzy(self) -> Vec4: ...
+    @property
+    def zzzz(self) -> Vec4: ...
     def __new__(
-        cls, x: builtins.float, y: builtins.float, z: builtins.float, w: builtins.float
-    ) -> DQuat:
+        cls,
+        x: builtins.float,
+        y: typing.Optional[builtins.float] = None,
+        z: typing.Optional[builtins.float] = None,
+    ) -> Vec3: ...
+    @staticmethod
+    def rand_uv() -> Vec3:
+        r"""
+        Generates a random unit vector, uniformly distributed over the sphere
+        """
+    @staticmethod
+    def rand_in_ball() -> Vec3:
+        r"""
+        Generates a random vector, uniformly distributed inside the unit ball
+        """
+    @staticmethod
+    def rand_in_cube() -> Vec3:
+        r"""
+        Generates a random vector, uniformly distributed inside the cube spanning -1 to 1 on every axis
+        """
+    @staticmethod
+    def rand_gaussian(std_dev: builtins.float = 1.0) -> Vec3:
+        r"""
+        Generates a random vector with independent normally distributed components
+
+        # Arguments
+
+        - `std_dev` (`float`) - Standard deviation of each component, the mean is 0
+
+        # Returns
+
+        - `Self` - Random vector
+        """
+    @staticmethod
+    def rand_in_cone(axis: Vec3Like, half_angle: builtins.float) -> Vec3:
+        r"""
+        Generates a random unit vector, uniformly distributed within `half_angle` of `axis`
+
+        # Arguments
+
+        - `axis` (`Vec3Like`) - Center of the cone, normalized before sampling
+        - `half_angle` (`float`) - Maximum angle from `axis` in radians, `pi` covers the whole sphere
+
+        # Returns
+
+        - `PyResult<Self>` - Random unit vector, raises NormalizationError if `axis` is zero or not finite
+        """
+    def to_tuple(self) -> tuple[builtins.float, builtins.float, builtins.float]:
+        r"""
+        Convert this vector to a 3 component tuple
+
+        # Returns
+
+        - `(float, float, float)` - XYZ tuple
+        """
+    def as_dvec3(self) -> DVec3:
+        r"""
+        Convert this vector to f64 precision
+
+        # Returns
+
+        - `DVec3` - Copy with every component widened to f64
+        """
+    def as_vec3(self) -> Vec3:
+        r"""
+        Convert this vector to f32 precision
+
+        # Returns
+
+        - `Vec3` - Copy with every component rounded to the nearest f32
+        """
+    @staticmethod
+    def from_numpy(array: numpy.typing.ArrayLike) -> Vec3:
+        r"""
+        Create a vector from a numpy array of 3 xyz components
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 1-D array of length 3, other dtypes are cast to this type's precision
+
+        # Returns
+
+        - `vec3` - New vector holding a copy of the components
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        View this vector as a numpy array, see `numpy.asarray`
+
+        Without a dtype conversion or `copy=True` the array is a read-only view of this vector
+
+        # Arguments
+
+        - `dtype` (`numpy.dtype | None`) - Requested dtype, defaults to this type's precision
+        - `copy` (`bool | None`) - True to always copy, False to raise ValueError instead of copying
+
+        # Returns
+
+        - `numpy.ndarray` - 1-D array of the xyz components
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any vec3, regardless of precision
+
+        Follows float semantics, so a vector containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def is_close(
+        self,
+        other: Vec3Like,
+        rel_tol: builtins.float = 1e-09,
+        abs_tol: builtins.float = 0.0,
+    ) -> builtins.bool:
+        r"""
+        Determine whether each component of this vector is close to `other`, following `math.isclose`
+
+        # Arguments
+
+        - `other` (`Vec3Like`) - Vector to compare against, any vec3 or 3 component sequence
+        - `rel_tol` (`float`) - Maximum difference relative to the larger absolute component
+        - `abs_tol` (`float`) - Minimum absolute tolerance, useful for comparisons near zero
+
+        # Returns
+
+        - `bool` - True if every component pair is close
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __add__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __radd__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __sub__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __rsub__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __mul__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __rmul__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __truediv__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __rtruediv__(
+        self, lhs: typing.Union[Vec3Like, builtins.float]
+    ) -> Vec3: ...
+    def __iadd__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __isub__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __imul__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __itruediv__(
+        self, rhs: typing.Union[Vec3Like, builtins.float]
+    ) -> None: ...
+    def __neg__(self) -> Vec3: ...
+    def normalize(self) -> Vec3:
+        r"""
+        Normalize this vector to unit length
+
+        # Returns
+
+        - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+        """
+    def length(self) -> builtins.float: ...
+    def extend(self, w: builtins.float) -> Vec4:
+        r"""
+        Creates a vec4 from the `x`, `y` and `z` elements of this vector and `w`
+
+        # Arguments
+
+        - `w` (`float`) - w component, use 1.0 for points and 0.0 for directions
+
+        # Returns
+
+        - `vec4` - Homogeneous xyzw vector
+        """
+    def dot(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Computes the dot product of this vector and `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `float` - Dot product
+        """
+    def cross(self, rhs: Vec3Like) -> Vec3:
         r"""
-        Create a new quaternion from components.
-        Usually you want `from_axis_angle` or `from_rotation_arc` instead of this
+        Computes the cross product of this vector and `rhs`
 
         # Arguments
 
-        - `x` (`float`) - x component
-        - `y` (`float`) - y component
-        - `z` (`float`) - z component
-        - `w` (`float`) - scalar component
+        - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
 
         # Returns
 
-        - `PyResult<Self>` - Describe the return value.
+        - `vec3` - Vector perpendicular to both inputs
         """
-    @staticmethod
-    def from_axis_angle(axis: DVec3, angle: builtins.float) -> DQuat:
+    def length_squared(self) -> builtins.float:
         r"""
-        Create a new quaternion from an axis and angle
+        Squared length of this vector, cheaper than `length` for comparisons
+        """
+    def distance(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Euclidean distance between this point and `rhs`
 
         # Arguments
 
-        - `axis` (`vec3`) - Axis, should be normalized
-        - `angle` (`float`) - Angle in radians
+        - `rhs` (`Vec3Like`) - Other point, any vec3 or 3 component sequence
 
         # Returns
 
-        - `PyResult<Self>` - Quaternion describing this axis/angle rotation
+        - `float` - Distance between the points
         """
-    @staticmethod
-    def from_rotation_arc(from_: DVec3, to: DVec3) -> DQuat:
+    def distance_squared(self, rhs: Vec3Like) -> builtins.float:
         r"""
-        Gets the minimal rotation for transforming `from` to `to`.  The rotation is in the
-        plane spanned by the two vectors.  Will rotate at most 180 degrees.
+        Squared euclidean distance between this point and `rhs`
 
-        `from_rotation_arc(from, to) * from ≈ to`.
+        # Arguments
 
-        For near-singular cases (from≈to and from≈-to) the current implementation
-        is only accurate to about 0.001 (for `f32`).
+        - `rhs` (`Vec3Like`) - Other point, any vec3 or 3 component sequence
+
+        # Returns
+
+        - `float` - Squared distance between the points
+        """
+    def lerp(self, rhs: Vec3Like, s: builtins.float) -> Vec3:
+        r"""
+        Linear interpolation between this vector and `rhs`
 
         # Arguments
 
-        - `from_` (`vec3`) - starting vector. Must be a unit vector
-        - `to` (`vec3`) - ending vector. Must be a unit vector
+        - `rhs` (`Vec3Like`) - Vector at `s = 1`
+        - `s` (`float`) - Interpolation factor, values outside `[0, 1]` extrapolate
 
         # Returns
 
-        - `PyResult<Self>` - Returns a quaternion that would rotate vector from onto to
+        - `vec3` - `self + (rhs - self) * s`
         """
-    def to_tuple(
-        self,
-    ) -> tuple[builtins.float, builtins.float, builtins.float, builtins.float]:
+    def midpoint(self, rhs: Vec3Like) -> Vec3:
         r"""
-        Convert this quat to a 4 component tuple
+        Point halfway between this point and `rhs`
+        """
+    def project_onto(self, rhs: Vec3Like) -> Vec3:
+        r"""
+        Vector projection of this vector onto `rhs`
+
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Vector to project onto, the result is NaN if it is zero
 
         # Returns
 
-        - `(float, float, float, float)` - XYZW tuple
+        - `vec3` - Component of this vector parallel to `rhs`
         """
-    def __mul__(self, rhs: typing.Any) -> typing.Union[DQuat, DVec3]:
+    def reject_from(self, rhs: Vec3Like) -> Vec3:
         r"""
-        Multiply this quaternion with either another quaternion or a vector
+        Vector rejection of this vector from `rhs`
 
-        A quaternion multiplication means combining two rotations into a single rotation
-        A vector multiplication yields a rotated vec3
+        # Arguments
+
+        - `rhs` (`Vec3Like`) - Vector to reject from, the result is NaN if it is zero
+
+        # Returns
+
+        - `vec3` - Component of this vector perpendicular to `rhs`
+        """
+    def reflect(self, normal: Vec3Like) -> Vec3:
+        r"""
+        Reflects this direction off a surface
 
         # Arguments
 
-        - `rhs` (`Bound<'_, PyAny>`) - Quat or Vec to multiply
+        - `normal` (`Vec3Like`) - Surface normal, must be normalized
 
         # Returns
 
-        - `PyResult<Either<, >>` - Either a new rotation or a rotated vector
+        - `vec3` - Reflected direction
         """
-    def __rmul__(self, lhs: typing.Any) -> typing.Union[DQuat, DVec3]:
+    def refract(self, normal: Vec3Like, eta: builtins.float) -> Vec3:
         r"""
-        Multiply this quaternion with either another quaternion or a vec3
+        Refracts this direction through a surface, this vector must be normalized
+
+        # Arguments
+
+        - `normal` (`Vec3Like`) - Surface normal, must be normalized
+        - `eta` (`float`) - Ratio of the refractive indices, incident over transmitted
+
+        # Returns
 
-        Order doesn't matter for multiplying a vector and a quat, but order matters for multiplying two quats
+        - `vec3` - Refracted direction, zero on total internal reflection
+        """
+    def angle_between(self, rhs: Vec3Like) -> builtins.float:
+        r"""
+        Angle in radians between this vector and `rhs`, neither needs to be normalized
 
         # Arguments
 
-        - `lhs` (`Bound<'_, PyAny>`) - left hand side multiplicand
+        - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
 
         # Returns
 
-        - `PyResult<Either<, >>` - Either a quat equivalent to the combined rotation or a rotated vector
+        - `float` - Angle in `[0, pi]`
         """
-    def normalize(self) -> DQuat:
+    def clamp_length(self, min: builtins.float, max: builtins.float) -> Vec3:
         r"""
-        Normalize this quaternion into a unit quat
+        Scales this vector so its length lies within `[min, max]`
+
+        # Arguments
+
+        - `min` (`float`) - Minimum length
+        - `max` (`float`) - Maximum length
+
+        # Returns
+
+        - `PyResult<vec3>` - Clamped vector, raises ValueError if `min > max`
         """
-    def conjugate(self) -> DQuat:
+    def normalize_or_zero(self) -> Vec3:
         r"""
-        Compute the conjugate of this quat.
-        If this is a unit quat, the conjugate is equal to the inverse of the rotation
+        Normalize this vector, or return zero if it can't be normalized
+        """
+    def try_normalize(self) -> typing.Optional[Vec3]:
+        r"""
+        Normalize this vector, or return None if its length is zero, infinite or NaN
+        """
+    def abs(self) -> Vec3:
+        r"""
+        Absolute value of each component
+        """
+    def signum(self) -> Vec3:
+        r"""
+        Sign of each component, 1.0 for positive values including `+0.0` and -1.0 for negative values including `-0.0`
+        """
+    def min(self, rhs: Vec3Like) -> Vec3:
+        r"""
+        Componentwise minimum of this vector and `rhs`
+        """
+    def max(self, rhs: Vec3Like) -> Vec3:
+        r"""
+        Componentwise maximum of this vector and `rhs`
+        """
+    def clamp(self, min: Vec3Like, max: Vec3Like) -> Vec3:
+        r"""
+        Clamps each component between the matching components of `min` and `max`
+
+        # Arguments
+
+        - `min` (`Vec3Like`) - Lower bound per component
+        - `max` (`Vec3Like`) - Upper bound per component
+
+        # Returns
+
+        - `PyResult<vec3>` - Clamped vector, raises ValueError if any `min` component exceeds `max`
+        """
+    def element_sum(self) -> builtins.float:
+        r"""
+        Sum of the components
+        """
+    def element_product(self) -> builtins.float:
+        r"""
+        Product of the components
+        """
+    def max_element(self) -> builtins.float:
+        r"""
+        Largest component
+        """
+    def min_element(self) -> builtins.float:
+        r"""
+        Smallest component
+        """
+    def is_finite(self) -> builtins.bool:
+        r"""
+        Whether every component is neither infinite nor NaN
+        """
+    def is_nan(self) -> builtins.bool:
+        r"""
+        Whether any component is NaN
+        """
+    def any_orthonormal_pair(self) -> tuple[Vec3, Vec3]:
+        r"""
+        Two unit vectors perpendicular to this vector and to each other, this vector must be normalized
+
+        # Returns
+
+        - `(vec3, vec3)` - Orthonormal pair completing a right handed basis with this vector
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the components
+        """
+    def __copy__(self) -> Vec3:
+        r"""
+        Copy this vector
         """
+    def __deepcopy__(self, _memo: typing.Any) -> Vec3: ...
 
 @typing.final
-class DVec3:
+class Vec3Array:
     r"""
-    3 Component vector xyz
+    Contiguous array of 3 component vectors for vectorised operations
+
+    Arithmetic doesn't mix precisions, f32 arrays refuse DVec3 operands and arrays only combine with
+    arrays of the same precision. Convert with `vec3_array(array, dtype=...)` first
+    """
+    @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    def __new__(cls, vecs: typing.Optional[typing.Any] = None) -> Vec3Array:
+        r"""
+        Create an array from an iterable of vectors
+
+        # Arguments
+
+        - `vecs` (`Iterable[Vec3Like] | None`) - Vectors or 3 component sequences, or an array with shape `(N, 3)`, empty if not given
+
+        # Returns
+
+        - `PyResult<Self>` - Array holding a copy of each vector
+        """
+    @staticmethod
+    def zeros(len: builtins.int) -> Vec3Array:
+        r"""
+        Create an array of `len` zero vectors
+        """
+    @staticmethod
+    def from_numpy(
+        array: numpy.typing.ArrayLike, dtype: typing.Any = None
+    ) -> typing.Union[DVec3Array, Vec3Array]:
+        r"""
+        Create an array from a numpy array with shape `(N, 3)`
+
+        # Arguments
+
+        - `array` (`numpy.typing.ArrayLike`) - 2-D array of any float or integer dtype
+        - `dtype` (`str | numpy.dtype | None`) - Precision of the new array, `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `DVec3Array | Vec3Array` - Array holding a copy of each row
+        """
+    def to_numpy(self, dtype: typing.Any = None) -> numpy.typing.NDArray[typing.Any]:
+        r"""
+        Copy this array into a numpy array with shape `(N, 3)`
+
+        # Arguments
+
+        - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to this type's precision
+
+        # Returns
+
+        - `numpy.ndarray` - New array holding the components of every vector
+        """
+    def __array__(
+        self,
+        dtype: typing.Optional[typing.Any] = None,
+        copy: typing.Optional[builtins.bool] = None,
+    ) -> typing.Any:
+        r"""
+        Copy this array into a numpy array, numpy's `__array__` protocol
+
+        The storage can't be shared, so `copy=False` raises ValueError
+        """
+    def to_list(self) -> builtins.list[Vec3]:
+        r"""
+        Copy this array into a list of vectors
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(self, index: typing.Any) -> typing.Union[Vec3, Vec3Array]:
+        r"""
+        Get a vector by index, or a new array by slice
+        """
+    def __setitem__(self, index: builtins.int, value: Vec3Like) -> None:
+        r"""
+        Set a vector by index, raises IndexError if out of range
+        """
+    def __add__(self, rhs: typing.Any) -> Vec3Array: ...
+    def __radd__(self, lhs: typing.Any) -> Vec3Array: ...
+    def __sub__(self, rhs: typing.Any) -> Vec3Array: ...
+    def __rsub__(self, lhs: typing.Any) -> Vec3Array: ...
+    def __mul__(self, rhs: typing.Any) -> Vec3Array: ...
+    def __rmul__(self, lhs: typing.Any) -> Vec3Array: ...
+    def __truediv__(self, rhs: typing.Any) -> Vec3Array: ...
+    def __rtruediv__(self, lhs: typing.Any) -> Vec3Array: ...
+    def __neg__(self) -> Vec3Array: ...
+    def dot(self, rhs: typing.Any) -> builtins.list[builtins.float]:
+        r"""
+        Elementwise dot product with a vector or a same length array
+
+        # Arguments
+
+        - `rhs` (`Vec3Like | vec3 array`) - Vector applied to every element, or one vector per element
+
+        # Returns
+
+        - `list[float]` - Dot product per element
+        """
+    def cross(self, rhs: typing.Any) -> Vec3Array:
+        r"""
+        Elementwise cross product with a vector or a same length array
+
+        # Arguments
+
+        - `rhs` (`Vec3Like | vec3 array`) - Vector applied to every element, or one vector per element
+
+        # Returns
+
+        - `vec3 array` - Cross product per element
+        """
+    def length(self) -> builtins.list[builtins.float]:
+        r"""
+        Length of each vector
+        """
+    def normalize(self) -> Vec3Array:
+        r"""
+        Normalize each vector to unit length
+        """
+    def sum(self) -> Vec3:
+        r"""
+        Sum of all vectors, zero for an empty array
+        """
+    def mean(self) -> Vec3:
+        r"""
+        Mean of all vectors, raises ValueError for an empty array
+        """
+    def min(self) -> Vec3:
+        r"""
+        Componentwise minimum of all vectors, raises ValueError for an empty array
+        """
+    def max(self) -> Vec3:
+        r"""
+        Componentwise maximum of all vectors, raises ValueError for an empty array
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged buffer of little endian xyz components
+        """
+    def __copy__(self) -> Vec3Array: ...
+    def __deepcopy__(self, _memo: typing.Any) -> Vec3Array: ...
+
+@typing.final
+class Vec4:
+    r"""
+    4 Component vector xyzw
+
+    Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
     """
     @property
+    def dtype(self) -> builtins.str:
+        r"""
+        Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+        """
+    @property
     def x(self) -> builtins.float: ...
     @x.setter
     def x(self, value: builtins.float) -> None: ...
@@ -132,39 +5066,154 @@ class DVec3:
     def z(self) -> builtins.float: ...
     @z.setter
     def z(self, value: builtins.float) -> None: ...
+    @property
+    def w(self) -> builtins.float: ...
+    @w.setter
+    def w(self, value: builtins.float) -> None: ...
     def __new__(
         cls,
         x: builtins.float,
         y: typing.Optional[builtins.float] = None,
         z: typing.Optional[builtins.float] = None,
-    ) -> DVec3: ...
-    @staticmethod
-    def rand_uv() -> DVec3:
+        w: typing.Optional[builtins.float] = None,
+    ) -> Vec4: ...
+    def to_tuple(
+        self,
+    ) -> tuple[builtins.float, builtins.float, builtins.float, builtins.float]:
         r"""
-        Generates a random unit vector, normalized to 1.
+        Convert this vector to a 4 component tuple
+
+        # Returns
+
+        - `(float, float, float, float)` - XYZW tuple
         """
-    def to_tuple(self) -> tuple[builtins.float, builtins.float, builtins.float]:
+    def as_dvec4(self) -> DVec4:
         r"""
-        Convert this vector to a 3 component tuple
+        Convert this vector to f64 precision
 
         # Returns
 
-        - `(float, float, float)` - XYZ tuple
+        - `DVec4` - Copy with every component widened to f64
+        """
+    def as_vec4(self) -> Vec4:
+        r"""
+        Convert this vector to f32 precision
+
+        # Returns
+
+        - `Vec4` - Copy with every component rounded to the nearest f32
+        """
+    def __repr__(self) -> builtins.str: ...
+    def __str__(self) -> builtins.str: ...
+    def __eq__(self, other: typing.Any) -> builtins.bool:
+        r"""
+        Component-wise value equality with any vec4, regardless of precision
+
+        Follows float semantics, so a vector containing NaN is never equal to anything.
+        Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+        """
+    def __hash__(self) -> builtins.int:
+        r"""
+        Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+        """
+    def __len__(self) -> builtins.int: ...
+    def __getitem__(
+        self, index: typing.Any
+    ) -> typing.Union[builtins.float, tuple[builtins.float, ...]]:
+        r"""
+        Get a component by index, or a tuple of components by slice
+        """
+    def __setitem__(self, index: typing.Any, value: typing.Any) -> None:
+        r"""
+        Set a component by index, or a slice of components from a sequence of the same length
+        """
+    def __iter__(self) -> typing.Iterator[typing.Any]: ...
+    def __contains__(self, value: typing.Any) -> builtins.bool:
+        r"""
+        Whether any component equals `value`, False for values that aren't numbers
+        """
+    def __add__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __radd__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __sub__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __rsub__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __mul__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __rmul__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __truediv__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __rtruediv__(self, lhs: typing.Union[DVec4, Vec4, builtins.float]) -> Vec4: ...
+    def __iadd__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __isub__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __imul__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __itruediv__(self, rhs: typing.Union[DVec4, Vec4, builtins.float]) -> None: ...
+    def __neg__(self) -> Vec4: ...
+    def normalize(self) -> Vec4:
+        r"""
+        Normalize this vector to unit length
+
+        # Returns
+
+        - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
         """
-    def __add__(self, rhs: typing.Any) -> DVec3: ...
-    def __radd__(self, lhs: typing.Any) -> DVec3: ...
-    def __sub__(self, rhs: typing.Any) -> DVec3: ...
-    def __rsub__(self, lhs: typing.Any) -> DVec3: ...
-    def __mul__(self, rhs: typing.Any) -> DVec3: ...
-    def __rmul__(self, lhs: typing.Any) -> DVec3: ...
-    def __truediv__(self, rhs: typing.Any) -> DVec3: ...
-    def __rtruediv__(self, lhs: typing.Any) -> DVec3: ...
-    def __iadd__(self, rhs: typing.Any) -> None: ...
-    def __isub__(self, rhs: typing.Any) -> None: ...
-    def __imul__(self, rhs: typing.Any) -> None: ...
-    def __itruediv__(self, rhs: typing.Any) -> None: ...
-    def __neg__(self) -> DVec3: ...
-    def normalize(self) -> DVec3: ...
     def length(self) -> builtins.float: ...
-    def dot(self, rhs: typing.Any) -> builtins.float: ...
-    def cross(self, rhs: typing.Any) -> DVec3: ...
+    def dot(self, rhs: typing.Union[DVec4, Vec4]) -> builtins.float: ...
+    def truncate(self) -> Vec3:
+        r"""
+        Creates a vec3 from the `x`, `y` and `z` elements of this vector, discarding `w`
+        """
+    def project_homogeneous(self) -> Vec3:
+        r"""
+        Projects this homogeneous coordinate to 3D space by dividing `x`, `y` and `z` by `w`
+
+        # Returns
+
+        - `vec3` - The projected point. Contains inf/nan when `w` is zero
+        """
+    def __reduce__(self) -> tuple[typing.Any, ...]:
+        r"""
+        Pickle support, restored from a version tagged list of the components
+        """
+    def __copy__(self) -> Vec4:
+        r"""
+        Copy this vector
+        """
+    def __deepcopy__(self, _memo: typing.Any) -> Vec4: ...
+
+
+
+def quat_array(quats: typing.Optional[typing.Any] = None, dtype: typing.Any = None) -> typing.Union[DQuatArray, QuatArray]:
+    r"""
+    Creates a quat array of the requested precision
+
+    # Arguments
+
+    - `quats` (`Iterable[QuatLike] | None`) - Quaternions or xyzw sequences, or an array with shape `(N, 4)`, empty if not given
+    - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to the dtype of `quats` if it has one, e.g. a numpy array, and f64 otherwise
+
+    # Returns
+
+    - `DQuatArray | QuatArray` - Array holding a copy of each quaternion
+    """
+
+def seed(seed: typing.Optional[builtins.int] = None) -> None:
+    r"""
+    Seeds the default generator used by the random samplers
+
+    # Arguments
+
+    - `seed` (`int | None`) - Seed making every following sample deterministic, `None` restores OS seeded randomness
+    """
+
+
+def vec3_array(vecs: typing.Optional[typing.Any] = None, dtype: typing.Any = None) -> typing.Union[DVec3Array, Vec3Array]:
+    r"""
+    Creates a vec3 array of the requested precision
+
+    # Arguments
+
+    - `vecs` (`Iterable[Vec3Like] | None`) - Vectors or 3 component sequences, or an array with shape `(N, 3)`, empty if not given
+    - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to the dtype of `vecs` if it has one, e.g. a numpy array, and f64 otherwise
+
+    # Returns
+
+    - `DVec3Array | Vec3Array` - Array holding a copy of each vector
+    """
+
@@ -0,0 +1,446 @@
+#[cfg(feature = "pyo3")]
+use crate::errors::SingularMatrixError;
+#[cfg(feature = "pyo3")]
+use crate::mat3;
+use crate::mat4;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::quat;
+#[cfg(feature = "pyo3")]
+use crate::vec3;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "pyo3")]
+use pyo3::{exceptions::PyTypeError, prelude::*, types::PyTuple};
+use std::ops::{Deref, DerefMut, Mul};
+
+/// Supported affine transform operands of either precision
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Affine3AffineOpsEnum {
+    DAffine3(DAffine3),
+    #[cfg(feature = "f32")]
+    Affine3A(Affine3A),
+    #[cfg(feature = "py-ref")]
+    DAffine3Ref(DAffine3Ref),
+}
+
+#[cfg(feature = "pyo3")]
+impl Affine3AffineOpsEnum {
+    /// Widens the operand to the f64 glam transform
+    fn to_daffine3(&self) -> PyResult<glam::DAffine3> {
+        match self {
+            Affine3AffineOpsEnum::DAffine3(affine) => Ok(affine.0),
+            #[cfg(feature = "f32")]
+            Affine3AffineOpsEnum::Affine3A(affine) => {
+                Ok(glam::DAffine3::from_cols_array(&affine.to_cols_array().map(|c| c as f64)))
+            }
+            #[cfg(feature = "py-ref")]
+            Affine3AffineOpsEnum::DAffine3Ref(affine_ref) => Ok(*affine_ref.get_inner_ref()?),
+        }
+    }
+}
+
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<Affine3AffineOpsEnum, DAffine3>> for Affine3AffineOpsEnum {
+    fn from(value: py_value::F32Operand<Affine3AffineOpsEnum, DAffine3>) -> Self {
+        value.0
+    }
+}
+
+/// Supported types for multiplying an affine transform
+/// affine3 * Some
+///
+/// `A` is the transform operand, f32 transforms refuse DAffine3 operands so that mixed precision promotes
+/// to f64 through `DAffine3.__rmul__`. A DVec3 is always transformed at f64 precision for the same reason
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Affine3OpsEnum<A> {
+    Affine3(A),
+    DVec3(vec3::DVec3),
+    #[cfg(feature = "f32")]
+    Vec3(vec3::Vec3),
+    #[cfg(feature = "py-ref")]
+    DVec3Ref(vec3::DVec3Ref),
+}
+
+macro_rules! affine3_glam_wrapper {
+    ($py_class_name: ident, $py_vec_class_name: ty, $py_quat_class_name: ty, $py_mat3_class_name: ty, $py_mat4_class_name: ty, $glam_class_name: ty, $glam_vec_class_name: ty, $var_type: ty, $affine_operand: ty) => {
+        /// 3D affine transform: a 3x3 linear transform followed by a translation
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(feature = "py-ref", derive(simple_py_bevy::PyStructRef))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $py_class_name($glam_class_name);
+
+        impl $py_class_name {
+            pub fn new(affine: $glam_class_name) -> Self {
+                Self(affine)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Converts a transform operand to this transform's precision
+            fn narrow(affine: &Affine3AffineOpsEnum) -> PyResult<$glam_class_name> {
+                Ok(<$glam_class_name>::from_cols_array(&affine.to_daffine3()?.to_cols_array().map(|c| c as $var_type)))
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            /// Create an affine transform from a rotation followed by a translation
+            ///
+            /// # Arguments
+            ///
+            /// - `rotation` (`quat`) - Rotation, must be normalized
+            /// - `translation` (`vec3`) - Translation
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Rigid transform
+            ///
+            #[new]
+            pub fn py_new(rotation: &$py_quat_class_name, translation: &$py_vec_class_name) -> Self {
+                Self::from_rotation_translation(rotation, translation)
+            }
+
+            /// Create an affine transform from a rotation followed by a translation
+            #[staticmethod]
+            pub fn from_rotation_translation(
+                rotation: &$py_quat_class_name,
+                translation: &$py_vec_class_name,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_rotation_translation(rotation.into(), translation.into());
+                $py_class_name(inner)
+            }
+            /// Create an affine transform that applies scale first, then rotation, then translation
+            ///
+            /// # Arguments
+            ///
+            /// - `scale` (`vec3`) - Scale along each axis
+            /// - `rotation` (`quat`) - Rotation, must be normalized
+            /// - `translation` (`vec3`) - Translation
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Affine transform
+            ///
+            #[staticmethod]
+            pub fn from_scale_rotation_translation(
+                scale: &$py_vec_class_name,
+                rotation: &$py_quat_class_name,
+                translation: &$py_vec_class_name,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_scale_rotation_translation(
+                    scale.into(),
+                    rotation.into(),
+                    translation.into(),
+                );
+                $py_class_name(inner)
+            }
+            /// Create an affine transform from a 4x4 matrix, discarding the last row
+            ///
+            /// The matrix is expected to be a 3D affine transformation matrix
+            #[staticmethod]
+            pub fn from_mat4(mat: &$py_mat4_class_name) -> Self {
+                $py_class_name(<$glam_class_name>::from_mat4(mat.into()))
+            }
+            /// Convert this transform into the equivalent 4x4 matrix
+            #[allow(clippy::wrong_self_convention)]
+            fn to_mat4(&self) -> $py_mat4_class_name {
+                return <$py_mat4_class_name>::new(self.0.into());
+            }
+            /// Decompose this transform into its scale, rotation and translation
+            ///
+            /// # Returns
+            ///
+            /// - `(vec3, quat, vec3)` - scale, rotation and translation, raises SingularMatrixError if the determinant is 0
+            ///
+            #[allow(clippy::wrong_self_convention)]
+            fn to_scale_rotation_translation(&self) -> PyResult<($py_vec_class_name, $py_quat_class_name, $py_vec_class_name)> {
+                if self.0.matrix3.determinant() == 0.0 {
+                    return Err(SingularMatrixError::new_err("Transform can not be decomposed, determinant is 0"));
+                }
+                let (scale, rotation, translation) = self.0.to_scale_rotation_translation();
+                Ok((
+                    <$py_vec_class_name>::new(scale),
+                    <$py_quat_class_name>::new(rotation),
+                    <$py_vec_class_name>::new(translation),
+                ))
+            }
+
+            #[getter]
+            fn get_matrix3(&self) -> $py_mat3_class_name {
+                return <$py_mat3_class_name>::new(self.0.matrix3.into());
+            }
+            #[getter]
+            fn get_translation(&self) -> $py_vec_class_name {
+                return <$py_vec_class_name>::new(self.0.translation.into());
+            }
+            #[setter]
+            fn set_matrix3(&mut self, matrix3: &$py_mat3_class_name) {
+                self.0.matrix3 = (**matrix3).into();
+            }
+            #[setter]
+            fn set_translation(&mut self, translation: &$py_vec_class_name) {
+                self.0.translation = (**translation).into();
+            }
+
+            /// Transform a 3D point, applying rotation, scale and translation
+            fn transform_point3(&self, point: &$py_vec_class_name) -> $py_vec_class_name {
+                return <$py_vec_class_name>::new(self.0.transform_point3(point.into()));
+            }
+            /// Transform a 3D direction, ignoring translation
+            fn transform_vector3(&self, vector: &$py_vec_class_name) -> $py_vec_class_name {
+                return <$py_vec_class_name>::new(self.0.transform_vector3(vector.into()));
+            }
+            /// Returns the inverse of this transform
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Inverse transform, raises SingularMatrixError if this transform is not invertible
+            ///
+            fn inverse(&self) -> PyResult<$py_class_name> {
+                if self.0.matrix3.determinant() == 0.0 {
+                    return Err(SingularMatrixError::new_err("Transform is not invertible, determinant is 0"));
+                }
+                return Ok($py_class_name::new(self.0.inverse()));
+            }
+
+            /// Compose this transform with another transform or transform a point
+            ///
+            /// `a * b` yields a transform that applies `b` first and then `a`.
+            /// A vector operand is treated as a point, see `transform_point3`.
+            /// Mixing f32 and f64 operands returns f64
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Bound<'_, PyAny>`) - Affine transform or vec3 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Either<, >>` - Either a combined transform or a transformed point
+            ///
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Affine3OpsEnum<$affine_operand>,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec_class_name, vec3::DVec3>>> {
+                // this * rhs
+                let this = self.0;
+                let widened = || glam::DAffine3::from_cols_array(&this.to_cols_array().map(|c| c as f64));
+                match rhs {
+                    Affine3OpsEnum::Affine3(affine) => {
+                        return Ok(Either::Left($py_class_name::new(this * Self::narrow(&affine.into())?)));
+                    }
+                    Affine3OpsEnum::DVec3(vec) => {
+                        return Ok(Either::Right(Either::Right(vec3::DVec3::new(widened().transform_point3(*vec)))));
+                    }
+                    #[cfg(feature = "f32")]
+                    Affine3OpsEnum::Vec3(vec) => {
+                        return Ok(Either::Right(Either::Left(<$py_vec_class_name>::new(this.transform_point3(
+                            <$glam_vec_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type),
+                        )))));
+                    }
+                    #[cfg(feature = "py-ref")]
+                    Affine3OpsEnum::DVec3Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        return Ok(Either::Right(Either::Right(vec3::DVec3::new(widened().transform_point3(*vec)))));
+                    }
+                }
+            }
+            /// Compose another transform with this transform, `lhs * self`
+            ///
+            /// # Arguments
+            ///
+            /// - `lhs` (`Bound<'_, PyAny>`) - Affine transform applied after this one
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Combined transform
+            ///
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Affine3OpsEnum<$affine_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs * this
+                match lhs {
+                    Affine3OpsEnum::Affine3(affine) => Ok($py_class_name::new(Self::narrow(&affine.into())? * self.0)),
+                    _ => Err(PyTypeError::new_err(
+                        "A vector can't be multiplied by a transform on the right, use `t * v`",
+                    )),
+                }
+            }
+            /// Compose this transform with another transform or transform a point, same as `*`
+            fn __matmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Affine3OpsEnum<$affine_operand>,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec_class_name, vec3::DVec3>>> {
+                // this @ rhs
+                return self.__mul__(rhs);
+            }
+            /// Compose another transform with this transform, same as reflected `*`
+            fn __rmatmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Affine3OpsEnum<$affine_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs @ this
+                return self.__rmul__(lhs);
+            }
+
+            /// Pickle support, restored from a version tagged list of the column major components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_cols_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_cols_array(&py_value::unpack_state(&state)?)))
+            }
+            /// Copy this transform
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
+        }
+
+        macro_rules! from_glam {
+            ($a:ty, $b:ty) => {
+                impl From<$a> for $b {
+                    fn from(value: $a) -> Self {
+                        Self(value.clone())
+                    }
+                }
+            };
+        }
+        from_glam!($glam_class_name, $py_class_name);
+        from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<$py_class_name> for $py_mat4_class_name {
+            fn from(value: $py_class_name) -> Self {
+                <$py_mat4_class_name>::new(value.0.into())
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = $glam_class_name;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        macro_rules! ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs.0)
+                    }
+                }
+            };
+        }
+        ops_with_self!($py_class_name, $py_class_name);
+        ops_with_self!($py_class_name, &$py_class_name);
+        ops_with_self!(&$py_class_name, $py_class_name);
+        ops_with_self!(&$py_class_name, &$py_class_name);
+
+        macro_rules! ops_with_glam {
+            ($a:ty, $b:ty) => {
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs)
+                    }
+                }
+            };
+        }
+        ops_with_glam!($glam_class_name, $py_class_name);
+        ops_with_glam!(&$glam_class_name, $py_class_name);
+        ops_with_glam!($glam_class_name, &$py_class_name);
+        ops_with_glam!(&$glam_class_name, &$py_class_name);
+    };
+}
+affine3_glam_wrapper!(
+    DAffine3,
+    vec3::DVec3,
+    quat::DQuat,
+    mat3::DMat3,
+    mat4::DMat4,
+    glam::DAffine3,
+    glam::DVec3,
+    f64,
+    Affine3AffineOpsEnum
+);
+#[cfg(feature = "f32")]
+affine3_glam_wrapper!(
+    Affine3A,
+    vec3::Vec3,
+    quat::Quat,
+    mat3::Mat3,
+    mat4::Mat4,
+    glam::Affine3A,
+    glam::Vec3,
+    f32,
+    py_value::F32Operand<Affine3AffineOpsEnum, DAffine3>
+);
+
+#[cfg(test)]
+mod test_affine3 {
+    use super::*;
+
+    mod test_daffine3 {
+        use super::*;
+
+        #[test]
+        fn test_compose() {
+            let rotate = DAffine3::new(glam::DAffine3::from_rotation_z(std::f64::consts::FRAC_PI_2));
+            let translate = DAffine3::new(glam::DAffine3::from_translation(glam::dvec3(1., 0., 0.)));
+            let actual = (translate * rotate).0.transform_point3(glam::dvec3(1., 0., 0.));
+            assert!(actual.abs_diff_eq(glam::dvec3(1., 1., 0.), 1e-12));
+        }
+
+        #[test]
+        fn test_into_mat4() {
+            let affine = DAffine3::new(glam::DAffine3::from_translation(glam::dvec3(1., 2., 3.)));
+            let actual: mat4::DMat4 = affine.into();
+            assert_eq!(actual.w_axis, glam::dvec4(1., 2., 3., 1.));
+        }
+    }
+}
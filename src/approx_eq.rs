@@ -0,0 +1,76 @@
+//! `approx` crate integration for the wrapper types, delegating to glam's implementations
+use crate::{affine3, mat3, mat4, quat, vec2, vec3, vec4};
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+macro_rules! impl_approx {
+    ($py_class_name: ty, $glam_class_name: ty) => {
+        impl AbsDiffEq for $py_class_name {
+            type Epsilon = <$glam_class_name as AbsDiffEq>::Epsilon;
+            fn default_epsilon() -> Self::Epsilon {
+                <$glam_class_name as AbsDiffEq>::default_epsilon()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                AbsDiffEq::abs_diff_eq(&**self, &**other, epsilon)
+            }
+        }
+
+        impl RelativeEq for $py_class_name {
+            fn default_max_relative() -> Self::Epsilon {
+                <$glam_class_name as RelativeEq>::default_max_relative()
+            }
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                RelativeEq::relative_eq(&**self, &**other, epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq for $py_class_name {
+            fn default_max_ulps() -> u32 {
+                <$glam_class_name as UlpsEq>::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                UlpsEq::ulps_eq(&**self, &**other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+impl_approx!(vec2::DVec2, glam::DVec2);
+impl_approx!(vec3::DVec3, glam::DVec3);
+impl_approx!(vec4::DVec4, glam::DVec4);
+impl_approx!(quat::DQuat, glam::DQuat);
+impl_approx!(mat3::DMat3, glam::DMat3);
+impl_approx!(mat4::DMat4, glam::DMat4);
+impl_approx!(affine3::DAffine3, glam::DAffine3);
+
+#[cfg(feature = "f32")]
+impl_approx!(vec2::Vec2, glam::Vec2);
+#[cfg(feature = "f32")]
+impl_approx!(vec3::Vec3, glam::Vec3);
+#[cfg(feature = "f32")]
+impl_approx!(vec4::Vec4, glam::Vec4);
+#[cfg(feature = "f32")]
+impl_approx!(quat::Quat, glam::Quat);
+#[cfg(feature = "f32")]
+impl_approx!(mat3::Mat3, glam::Mat3);
+#[cfg(feature = "f32")]
+impl_approx!(mat4::Mat4, glam::Mat4);
+#[cfg(feature = "f32")]
+impl_approx!(affine3::Affine3A, glam::Affine3A);
+
+#[cfg(test)]
+mod test_approx_eq {
+    use super::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
+
+    #[test]
+    fn test_vec3() {
+        let actual = vec3::dvec3(1., 2., 3.) + glam::DVec3::splat(1e-12);
+        assert_abs_diff_eq!(actual, vec3::dvec3(1., 2., 3.), epsilon = 1e-9);
+        assert_relative_eq!(actual, vec3::dvec3(1., 2., 3.), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn test_quat() {
+        let actual = quat::DQuat::new(glam::DQuat::from_rotation_z(0.5));
+        assert_abs_diff_eq!(actual, quat::DQuat::new(glam::DQuat::from_rotation_z(0.5 + 1e-12)), epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,25 @@
+# Stub-only aliases, import them under `typing.TYPE_CHECKING`
+class SupportsArray(typing.Protocol):
+    r"""
+    Object that converts itself with numpy's `__array__` protocol
+    """
+    def __array__(self) -> typing.Any: ...
+
+Vec2Like: typing.TypeAlias = typing.Union["DVec2", "Vec2", typing.Sequence[builtins.float], SupportsArray]
+r"""
+Anything accepted where a vec2 is expected: a pyglam vec2, a 2 component sequence
+such as a tuple or list, a numpy 1-D array of length 2 or an object exposing `__array__`
+"""
+
+Vec3Like: typing.TypeAlias = typing.Union["DVec3", "Vec3", typing.Sequence[builtins.float], SupportsArray]
+r"""
+Anything accepted where a vec3 is expected: a pyglam vec3, a 3 component sequence
+such as a tuple or list, a numpy 1-D array of length 3 or an object exposing `__array__`
+"""
+
+QuatLike: typing.TypeAlias = typing.Union["DQuat", "Quat", typing.Sequence[builtins.float], SupportsArray]
+r"""
+Anything accepted where a quaternion is expected: a pyglam quat, a 4 component xyzw sequence
+such as a tuple or list, a numpy 1-D array of length 4 or an object exposing `__array__`
+"""
+
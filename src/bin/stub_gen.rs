@@ -1,8 +1,25 @@
+/// Stub-only type aliases named by `type_repr` overrides, pyo3_stub_gen can't generate them
+#[cfg(feature = "pyo3")]
+const STUB_ALIASES: &str = include_str!("stub_aliases.pyi");
+
+/// Inserts `STUB_ALIASES` after `__all__` in the generated stub file, before any class uses them
+#[cfg(feature = "pyo3")]
+fn insert_aliases(path: &std::path::Path) -> std::io::Result<()> {
+    let stub = std::fs::read_to_string(path)?;
+    let end_of_all = stub
+        .find("\n]\n")
+        .map(|i| i + "\n]\n".len())
+        .ok_or_else(|| std::io::Error::other("generated stub has no __all__ list"))?;
+    let (head, classes) = stub.split_at(end_of_all);
+    std::fs::write(path, format!("{}\n{}{}", head, STUB_ALIASES, classes.trim_start_matches('\n')))
+}
+
 #[cfg(feature = "pyo3")]
 fn main() -> pyo3_stub_gen::Result<()> {
     env_logger::Builder::from_env(env_logger::Env::default().filter_or("RUST_LOG", "info")).init();
     let stub = pyglam::stub_info()?;
     stub.generate()?;
+    insert_aliases(&std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("pyglam.pyi"))?;
     Ok(())
 }
 #[cfg(not(feature = "pyo3"))]
@@ -0,0 +1,109 @@
+//! Zero-copy numpy interop through the python buffer protocol
+//!
+//! The wrapper types are `#[repr(transparent)]` over glam's `#[repr(C)]` storage, so their
+//! components are contiguous floats that can be exposed to python without copying
+use numpy::PyUntypedArray;
+use pyo3::{
+    exceptions::PyBufferError,
+    ffi,
+    prelude::*,
+    types::{PyDict, PyMemoryView},
+};
+use std::ffi::{CStr, c_int, c_void};
+
+/// Float types that can be exposed through the buffer protocol
+pub(crate) trait BufferElement: Copy {
+    /// struct module format character describing this type
+    const FORMAT: &'static CStr;
+}
+impl BufferElement for f64 {
+    const FORMAT: &'static CStr = c"d";
+}
+impl BufferElement for f32 {
+    const FORMAT: &'static CStr = c"f";
+}
+
+/// Fills `view` with a 1-D, C-contiguous buffer over `len` elements starting at `data`
+///
+/// The buffer is read-only, `data` is derived from a shared borrow so writes through it would
+/// bypass pyo3's borrow checking. Requesting a writable buffer raises BufferError. `owner` is
+/// kept alive by the view since `data` points into it.
+/// Shape storage is heap allocated and must be freed with `release_view`
+///
+/// # Safety
+///
+/// `view` must be a valid `Py_buffer` pointer as passed to `__getbuffer__` and `data` must
+/// point to `len` initialized elements stored inside `owner`
+pub(crate) unsafe fn fill_view<T: BufferElement>(
+    view: *mut ffi::Py_buffer,
+    flags: c_int,
+    data: *const T,
+    len: usize,
+    owner: Bound<'_, PyAny>,
+) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("View is null"));
+    }
+    if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+        return Err(PyBufferError::new_err("Object is not writable"));
+    }
+    unsafe {
+        (*view).obj = owner.into_ptr();
+        (*view).buf = data as *mut c_void;
+        (*view).len = (len * size_of::<T>()) as isize;
+        (*view).readonly = 1;
+        (*view).itemsize = size_of::<T>() as isize;
+        (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+            T::FORMAT.as_ptr() as *mut _
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).ndim = 1;
+        // shape and strides have to outlive this call, keep them in `internal` until release
+        let dims = Box::into_raw(Box::new([len as isize, size_of::<T>() as isize]));
+        (*view).internal = dims as *mut c_void;
+        (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+            dims as *mut isize
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+            (dims as *mut isize).add(1)
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).suboffsets = std::ptr::null_mut();
+    }
+    Ok(())
+}
+
+/// Frees the shape storage allocated by `fill_view`
+///
+/// # Safety
+///
+/// `view` must have been filled by `fill_view`
+pub(crate) unsafe fn release_view(view: *mut ffi::Py_buffer) {
+    unsafe {
+        drop(Box::from_raw((*view).internal as *mut [isize; 2]));
+    }
+}
+
+/// Implements numpy's `__array__(dtype=None, copy=None)` on top of the buffer protocol
+///
+/// Without a dtype or copy request the returned array is a read-only view of `owner`.
+/// `copy=False` raises ValueError if a dtype conversion would require a copy
+pub(crate) fn to_numpy<'py>(
+    owner: &Bound<'py, PyAny>,
+    dtype: Option<Bound<'py, PyAny>>,
+    copy: Option<bool>,
+) -> PyResult<Bound<'py, PyUntypedArray>> {
+    let py = owner.py();
+    let memory_view = PyMemoryView::from(owner)?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("dtype", dtype)?;
+    kwargs.set_item("copy", copy)?;
+    py.import("numpy")?
+        .call_method("array", (memory_view,), Some(&kwargs))?
+        .cast_into::<PyUntypedArray>()
+        .map_err(Into::into)
+}
@@ -0,0 +1,25 @@
+//! Exceptions raised for domain errors such as normalizing a zero vector
+//!
+//! Every exception derives from `PyglamError`, which is itself a `ValueError` so that existing
+//! `except ValueError` handlers keep working. Invalid arguments still raise plain `ValueError`
+//! or `TypeError`
+use pyo3::exceptions::PyValueError;
+
+pyo3_stub_gen::create_exception!(
+    pyglam,
+    PyglamError,
+    PyValueError,
+    "Base class of the domain errors raised by pyglam"
+);
+pyo3_stub_gen::create_exception!(
+    pyglam,
+    NormalizationError,
+    PyglamError,
+    "Raised when normalizing a vector or quaternion whose length is zero, infinite or NaN"
+);
+pyo3_stub_gen::create_exception!(
+    pyglam,
+    SingularMatrixError,
+    PyglamError,
+    "Raised when inverting or decomposing a matrix or transform whose determinant is zero"
+);
@@ -0,0 +1,213 @@
+//! Euler rotation sequences shared by the rotation types
+use glam;
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyString, PyTuple},
+};
+use std::fmt;
+use std::str::FromStr;
+
+/// Euler rotation sequences
+///
+/// Intrinsic orders rotate about the axes of the rotating body, so `XYZ` applies x then the
+/// rotated y then the twice rotated z. Extrinsic orders (the `Ex` suffix) rotate about the fixed
+/// world axes.
+/// Angles are always given in the order the axes appear in the name
+#[cfg_attr(
+    feature = "pyo3",
+    pyo3_stub_gen::derive::gen_stub_pyclass_enum,
+    pyclass(eq, eq_int, hash, frozen, module = "pyglam")
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EulerRot {
+    /// Intrinsic three-axis rotation ZYX
+    ZYX,
+    /// Intrinsic three-axis rotation ZXY
+    ZXY,
+    /// Intrinsic three-axis rotation YXZ
+    YXZ,
+    /// Intrinsic three-axis rotation YZX
+    YZX,
+    /// Intrinsic three-axis rotation XYZ
+    XYZ,
+    /// Intrinsic three-axis rotation XZY
+    XZY,
+
+    /// Intrinsic two-axis rotation ZYZ
+    ZYZ,
+    /// Intrinsic two-axis rotation ZXZ
+    ZXZ,
+    /// Intrinsic two-axis rotation YXY
+    YXY,
+    /// Intrinsic two-axis rotation YZY
+    YZY,
+    /// Intrinsic two-axis rotation XYX
+    XYX,
+    /// Intrinsic two-axis rotation XZX
+    XZX,
+
+    /// Extrinsic three-axis rotation ZYX
+    ZYXEx,
+    /// Extrinsic three-axis rotation ZXY
+    ZXYEx,
+    /// Extrinsic three-axis rotation YXZ
+    YXZEx,
+    /// Extrinsic three-axis rotation YZX
+    YZXEx,
+    /// Extrinsic three-axis rotation XYZ
+    XYZEx,
+    /// Extrinsic three-axis rotation XZY
+    XZYEx,
+
+    /// Extrinsic two-axis rotation ZYZ
+    ZYZEx,
+    /// Extrinsic two-axis rotation ZXZ
+    ZXZEx,
+    /// Extrinsic two-axis rotation YXY
+    YXYEx,
+    /// Extrinsic two-axis rotation YZY
+    YZYEx,
+    /// Extrinsic two-axis rotation XYX
+    XYXEx,
+    /// Extrinsic two-axis rotation XZX
+    XZXEx,
+}
+
+/// Error returned when a string doesn't name an euler rotation sequence
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseEulerRotError(String);
+
+impl fmt::Display for ParseEulerRotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown euler rotation order '{}', expected an axis sequence such as 'XYZ' or 'ZYXEx'",
+            self.0
+        )
+    }
+}
+impl std::error::Error for ParseEulerRotError {}
+
+macro_rules! euler_rot_conversions {
+    ($($name: ident),* $(,)?) => {
+        impl From<EulerRot> for glam::EulerRot {
+            fn from(value: EulerRot) -> Self {
+                match value {
+                    $(EulerRot::$name => glam::EulerRot::$name,)*
+                }
+            }
+        }
+        impl From<glam::EulerRot> for EulerRot {
+            fn from(value: glam::EulerRot) -> Self {
+                match value {
+                    $(glam::EulerRot::$name => EulerRot::$name,)*
+                }
+            }
+        }
+        impl EulerRot {
+            /// Name of this sequence, e.g. `"XYZ"` or `"ZYXEx"`
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(EulerRot::$name => stringify!($name),)*
+                }
+            }
+        }
+        /// Parses a sequence name, ignoring case so that `"xyz"` and `"zyxex"` are accepted
+        impl FromStr for EulerRot {
+            type Err = ParseEulerRotError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($name)) {
+                        return Ok(EulerRot::$name);
+                    }
+                )*
+                Err(ParseEulerRotError(s.to_string()))
+            }
+        }
+    };
+}
+euler_rot_conversions!(
+    ZYX, ZXY, YXZ, YZX, XYZ, XZY, ZYZ, ZXZ, YXY, YZY, XYX, XZX, ZYXEx, ZXYEx, YXZEx, YZXEx, XYZEx,
+    XZYEx, ZYZEx, ZXZEx, YXYEx, YZYEx, XYXEx, XZXEx,
+);
+
+impl fmt::Display for EulerRot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Any python object that can be used where an euler rotation sequence is expected
+///
+/// Accepts `EulerRot` members and their names as strings, ignoring case
+#[cfg(feature = "pyo3")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EulerRotLike(pub EulerRot);
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py> FromPyObject<'a, 'py> for EulerRotLike {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(order) = obj.extract::<EulerRot>() {
+            return Ok(EulerRotLike(order));
+        }
+        if let Ok(name) = obj.cast::<PyString>() {
+            return name
+                .to_str()?
+                .parse::<EulerRot>()
+                .map(EulerRotLike)
+                .map_err(|e| PyValueError::new_err(e.to_string()));
+        }
+        Err(PyTypeError::new_err(format!(
+            "expected an EulerRot or its name as a string, got '{}'",
+            obj.get_type().name()?
+        )))
+    }
+}
+#[cfg(feature = "pyo3")]
+#[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+impl EulerRot {
+    /// Pickle support, restored by looking up the member by name
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        let getattr = py.import("builtins")?.getattr("getattr")?;
+        (getattr, (py.get_type::<EulerRot>(), self.name())).into_pyobject(py)
+    }
+    fn __copy__(&self) -> Self {
+        *self
+    }
+    fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<EulerRotLike> for glam::EulerRot {
+    fn from(value: EulerRotLike) -> Self {
+        value.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("XYZ".parse::<EulerRot>(), Ok(EulerRot::XYZ));
+        assert_eq!("zyxex".parse::<EulerRot>(), Ok(EulerRot::ZYXEx));
+        assert!("XYW".parse::<EulerRot>().is_err());
+    }
+
+    #[test]
+    fn test_round_trip_names() {
+        for order in [EulerRot::ZYX, EulerRot::XZX, EulerRot::YXZEx, EulerRot::XYXEx] {
+            assert_eq!(order.name().parse::<EulerRot>(), Ok(order));
+            assert_eq!(EulerRot::from(glam::EulerRot::from(order)), order);
+        }
+    }
+}
@@ -1,24 +1,95 @@
+pub mod affine3;
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "numpy")]
+mod buffer;
+#[cfg(feature = "pyo3")]
+pub mod errors;
+pub mod euler;
+pub mod mat3;
+pub mod mat4;
+#[cfg(feature = "pyo3")]
+mod py_value;
 pub mod quat;
+pub mod quat_array;
+pub mod random;
+pub mod vec2;
 pub mod vec3;
+pub mod vec3_array;
+pub mod vec4;
 
 #[cfg(feature = "pyo3")]
 use pyo3::prelude::*;
 
+pub use self::affine3::*;
+pub use self::euler::*;
+pub use self::mat3::*;
+pub use self::mat4::*;
 pub use self::quat::*;
+pub use self::quat_array::*;
+pub use self::random::Rng;
+pub use self::vec2::*;
 pub use self::vec3::*;
+pub use self::vec3_array::*;
+pub use self::vec4::*;
 
 /// Simple linear algebra vector library
 #[cfg(feature = "pyo3")]
 #[pymodule]
 mod pyglam {
+    #[pymodule_export]
+    use crate::affine3::DAffine3;
+    #[pymodule_export]
+    use crate::errors::NormalizationError;
+    #[pymodule_export]
+    use crate::errors::PyglamError;
+    #[pymodule_export]
+    use crate::errors::SingularMatrixError;
+    #[pymodule_export]
+    use crate::euler::EulerRot;
+    #[pymodule_export]
+    use crate::mat3::DMat3;
+    #[pymodule_export]
+    use crate::mat3::dmat3;
+    #[pymodule_export]
+    use crate::mat4::DMat4;
+    #[pymodule_export]
+    use crate::mat4::dmat4;
     #[pymodule_export]
     use crate::quat::DQuat;
     #[pymodule_export]
     use crate::quat::dquat;
     #[pymodule_export]
+    use crate::quat_array::DQuatArray;
+    #[pymodule_export]
+    use crate::quat_array::quat_array;
+    #[pymodule_export]
+    use crate::random::Rng;
+    #[pymodule_export]
+    use crate::random::seed;
+    #[pymodule_export]
+    use crate::vec2::DVec2;
+    #[pymodule_export]
+    use crate::vec2::dvec2;
+    #[pymodule_export]
     use crate::vec3::DVec3;
     #[pymodule_export]
     use crate::vec3::dvec3;
+    #[pymodule_export]
+    use crate::vec3_array::DVec3Array;
+    #[pymodule_export]
+    use crate::vec3_array::vec3_array;
+    #[pymodule_export]
+    use crate::vec4::DVec4;
+    #[pymodule_export]
+    use crate::vec4::dvec4;
+
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::vec2::Vec2;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::vec2::vec2;
 
     #[cfg(feature = "f32")]
     #[pymodule_export]
@@ -26,6 +97,32 @@ mod pyglam {
     #[cfg(feature = "f32")]
     #[pymodule_export]
     use crate::vec3::vec3;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::vec3_array::Vec3Array;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::vec4::Vec4;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::vec4::vec4;
+
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::affine3::Affine3A;
+
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::mat3::Mat3;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::mat3::mat3;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::mat4::Mat4;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::mat4::mat4;
 
     #[cfg(feature = "f32")]
     #[pymodule_export]
@@ -33,6 +130,9 @@ mod pyglam {
     #[cfg(feature = "f32")]
     #[pymodule_export]
     use crate::quat::quat;
+    #[cfg(feature = "f32")]
+    #[pymodule_export]
+    use crate::quat_array::QuatArray;
 }
 
 #[cfg(feature = "pyo3")]
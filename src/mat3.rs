@@ -0,0 +1,564 @@
+#[cfg(feature = "pyo3")]
+use crate::errors::SingularMatrixError;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::quat;
+use crate::vec3;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::PyTuple,
+};
+use std::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+/// Supported matrix operands of either precision
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Mat3MatOpsEnum {
+    DMat3(DMat3),
+    #[cfg(feature = "f32")]
+    Mat3(Mat3),
+    #[cfg(feature = "py-ref")]
+    DMat3Ref(DMat3Ref),
+}
+
+#[cfg(feature = "pyo3")]
+impl Mat3MatOpsEnum {
+    /// Widens the operand to an f64 glam matrix
+    fn to_dmat3(&self) -> PyResult<glam::DMat3> {
+        match self {
+            Mat3MatOpsEnum::DMat3(mat) => Ok(mat.0),
+            #[cfg(feature = "f32")]
+            Mat3MatOpsEnum::Mat3(mat) => Ok(mat.0.as_dmat3()),
+            #[cfg(feature = "py-ref")]
+            Mat3MatOpsEnum::DMat3Ref(mat_ref) => Ok(*mat_ref.get_inner_ref()?),
+        }
+    }
+}
+
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<Mat3MatOpsEnum, DMat3>> for Mat3MatOpsEnum {
+    fn from(value: py_value::F32Operand<Mat3MatOpsEnum, DMat3>) -> Self {
+        value.0
+    }
+}
+
+/// Supported types for multiplying a matrix
+/// mat3 * Some
+///
+/// `M` is the matrix operand, f32 matrices refuse DMat3 operands so that mixed precision promotes to f64
+/// through `DMat3.__rmul__`. A DVec3 is always transformed at f64 precision for the same reason
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Mat3OpsEnum<M> {
+    Float(f64),
+    Int(i64),
+    Mat3(M),
+    DVec3(vec3::DVec3),
+    #[cfg(feature = "f32")]
+    Vec3(vec3::Vec3),
+    #[cfg(feature = "py-ref")]
+    DVec3Ref(vec3::DVec3Ref),
+}
+
+macro_rules! mat3_glam_wrapper {
+    ($py_class_name: ident, $py_vec_class_name: ty, $py_quat_class_name: ty, $glam_class_name: ty, $glam_vec_class_name: ty, $var_type: ty, $mat_operand: ty) => {
+        /// 3x3 column major matrix
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(feature = "py-ref", derive(simple_py_bevy::PyStructRef))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $py_class_name($glam_class_name);
+
+        impl $py_class_name {
+            pub fn new(mat: $glam_class_name) -> Self {
+                Self(mat)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Multiplies this matrix with a scalar, matrix or vector operand
+            ///
+            /// f64 vectors are transformed by this matrix widened to f64
+            fn mul_op(
+                &self,
+                rhs: Mat3OpsEnum<$mat_operand>,
+                allow_scalar: bool,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec_class_name, vec3::DVec3>>> {
+                let this = self.0;
+                let widened = || glam::DMat3::from_cols_array(&this.to_cols_array().map(|c| c as f64));
+                match rhs {
+                    Mat3OpsEnum::Float(scalar) if allow_scalar => {
+                        return Ok(Either::Left($py_class_name::new(this * scalar as $var_type)));
+                    }
+                    Mat3OpsEnum::Int(i) if allow_scalar => {
+                        return Ok(Either::Left($py_class_name::new(this * i as $var_type)));
+                    }
+                    Mat3OpsEnum::Float(_) | Mat3OpsEnum::Int(_) => {
+                        return Err(PyTypeError::new_err(
+                            "Matrix multiplication with a scalar is not supported, use `*` instead",
+                        ));
+                    }
+                    Mat3OpsEnum::Mat3(mat) => {
+                        return Ok(Either::Left($py_class_name::new(this * Self::narrow(&mat.into())?)));
+                    }
+                    Mat3OpsEnum::DVec3(vec) => {
+                        return Ok(Either::Right(Either::Right(vec3::DVec3::new(widened() * *vec))));
+                    }
+                    #[cfg(feature = "f32")]
+                    Mat3OpsEnum::Vec3(vec) => {
+                        let vec = <$glam_vec_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
+                        return Ok(Either::Right(Either::Left(<$py_vec_class_name>::new(this * vec))));
+                    }
+                    #[cfg(feature = "py-ref")]
+                    Mat3OpsEnum::DVec3Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        return Ok(Either::Right(Either::Right(vec3::DVec3::new(widened() * *vec))));
+                    }
+                }
+            }
+            /// Multiplies a scalar or matrix operand on the left with this matrix
+            fn rmul_op(&self, lhs: Mat3OpsEnum<$mat_operand>, allow_scalar: bool) -> PyResult<$py_class_name> {
+                match lhs {
+                    Mat3OpsEnum::Mat3(mat) => Ok($py_class_name::new(Self::narrow(&mat.into())? * self.0)),
+                    Mat3OpsEnum::Float(scalar) if allow_scalar => Ok($py_class_name::new(scalar as $var_type * self.0)),
+                    Mat3OpsEnum::Int(i) if allow_scalar => Ok($py_class_name::new(i as $var_type * self.0)),
+                    Mat3OpsEnum::Float(_) | Mat3OpsEnum::Int(_) => Err(PyTypeError::new_err(
+                        "Matrix multiplication with a scalar is not supported, use `*` instead",
+                    )),
+                    _ => Err(PyTypeError::new_err(
+                        "A vector can't be multiplied by a matrix on the right, use `m * v`",
+                    )),
+                }
+            }
+            /// Converts a matrix operand to this matrix's precision
+            fn narrow(mat: &Mat3MatOpsEnum) -> PyResult<$glam_class_name> {
+                Ok(<$glam_class_name>::from_cols_array(&mat.to_dmat3()?.to_cols_array().map(|c| c as $var_type)))
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            /// Create a new matrix from three column vectors
+            ///
+            /// # Arguments
+            ///
+            /// - `x_axis` (`Vec3Like`) - first column
+            /// - `y_axis` (`Vec3Like`) - second column
+            /// - `z_axis` (`Vec3Like`) - third column
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Matrix with the given columns
+            ///
+            #[new]
+            pub fn py_new(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] x_axis: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] y_axis: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] z_axis: vec3::Vec3Like,
+            ) -> Self {
+                Self::from_cols(x_axis, y_axis, z_axis)
+            }
+
+            /// Create a new matrix from three column vectors
+            #[staticmethod]
+            pub fn from_cols(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] x_axis: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] y_axis: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] z_axis: vec3::Vec3Like,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_cols(x_axis.into(), y_axis.into(), z_axis.into());
+                $py_class_name(inner)
+            }
+            /// Create a new matrix from three row vectors
+            #[staticmethod]
+            pub fn from_rows(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] row0: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] row1: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] row2: vec3::Vec3Like,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_cols(row0.into(), row1.into(), row2.into()).transpose();
+                $py_class_name(inner)
+            }
+            /// Create a matrix with the given diagonal and all other elements set to 0
+            #[staticmethod]
+            pub fn from_diagonal(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] diagonal: vec3::Vec3Like,
+            ) -> Self {
+                $py_class_name(<$glam_class_name>::from_diagonal(diagonal.into()))
+            }
+            /// Create a rotation matrix from a quaternion
+            ///
+            /// # Arguments
+            ///
+            /// - `rotation` (`QuatLike`) - Rotation, must be normalized
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Rotation matrix equivalent to `rotation`
+            ///
+            #[staticmethod]
+            pub fn from_quat(
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] rotation: quat::QuatLike,
+            ) -> Self {
+                $py_class_name(<$glam_class_name>::from_quat(rotation.into()))
+            }
+
+            #[getter]
+            fn get_x_axis(&self) -> $py_vec_class_name {
+                return <$py_vec_class_name>::new(self.0.x_axis);
+            }
+            #[getter]
+            fn get_y_axis(&self) -> $py_vec_class_name {
+                return <$py_vec_class_name>::new(self.0.y_axis);
+            }
+            #[getter]
+            fn get_z_axis(&self) -> $py_vec_class_name {
+                return <$py_vec_class_name>::new(self.0.z_axis);
+            }
+            #[setter]
+            fn set_x_axis(&mut self, x_axis: &$py_vec_class_name) {
+                self.0.x_axis = x_axis.into();
+            }
+            #[setter]
+            fn set_y_axis(&mut self, y_axis: &$py_vec_class_name) {
+                self.0.y_axis = y_axis.into();
+            }
+            #[setter]
+            fn set_z_axis(&mut self, z_axis: &$py_vec_class_name) {
+                self.0.z_axis = z_axis.into();
+            }
+
+            /// Get a column of this matrix
+            fn col(&self, index: usize) -> PyResult<$py_vec_class_name> {
+                if index > 2 {
+                    return Err(PyValueError::new_err("Column index must be 0, 1 or 2"));
+                }
+                return Ok(<$py_vec_class_name>::new(self.0.col(index)));
+            }
+            /// Get a row of this matrix
+            fn row(&self, index: usize) -> PyResult<$py_vec_class_name> {
+                if index > 2 {
+                    return Err(PyValueError::new_err("Row index must be 0, 1 or 2"));
+                }
+                return Ok(<$py_vec_class_name>::new(self.0.row(index)));
+            }
+
+            /// Convert this matrix to a tuple of 3 column tuples
+            ///
+            /// # Returns
+            ///
+            /// - `((float, float, float), (float, float, float), (float, float, float))` - Column major tuple
+            ///
+            #[allow(clippy::wrong_self_convention, clippy::type_complexity)]
+            fn to_tuple(
+                &self,
+            ) -> (
+                ($var_type, $var_type, $var_type),
+                ($var_type, $var_type, $var_type),
+                ($var_type, $var_type, $var_type),
+            ) {
+                let [x, y, z] = self.0.to_cols_array_2d();
+                ((x[0], x[1], x[2]), (y[0], y[1], y[2]), (z[0], z[1], z[2]))
+            }
+
+            /// Multiply this matrix with a scalar, another matrix or a vector
+            ///
+            /// Mixing f32 and f64 operands returns f64
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Bound<'_, PyAny>`) - Scalar, mat3 or vec3 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+            ///
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Mat3OpsEnum<$mat_operand>,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec_class_name, vec3::DVec3>>> {
+                // this * rhs
+                return self.mul_op(rhs, true);
+            }
+            /// Multiply a scalar or another matrix with this matrix, `lhs * self`
+            ///
+            /// A vector on the left raises TypeError, vectors are transformed as `m * v`
+            ///
+            /// # Arguments
+            ///
+            /// - `lhs` (`Bound<'_, PyAny>`) - Scalar or mat3 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - New matrix
+            ///
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Mat3OpsEnum<$mat_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs * this
+                return self.rmul_op(lhs, true);
+            }
+            /// Matrix multiply this matrix with another matrix or a vector
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Bound<'_, PyAny>`) - mat3 or vec3 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+            ///
+            fn __matmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Mat3OpsEnum<$mat_operand>,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec_class_name, vec3::DVec3>>> {
+                // this @ rhs
+                return self.mul_op(rhs, false);
+            }
+            /// Matrix multiply another matrix with this matrix, `lhs @ self`
+            ///
+            /// # Arguments
+            ///
+            /// - `lhs` (`Bound<'_, PyAny>`) - mat3 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - New matrix
+            ///
+            fn __rmatmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Mat3OpsEnum<$mat_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs @ this
+                return self.rmul_op(lhs, false);
+            }
+            fn __add__(&self, rhs: &$py_class_name) -> $py_class_name {
+                return $py_class_name::new(self.0 + rhs.0);
+            }
+            fn __sub__(&self, rhs: &$py_class_name) -> $py_class_name {
+                return $py_class_name::new(self.0 - rhs.0);
+            }
+            fn __neg__(&self) -> $py_class_name {
+                return $py_class_name::new(-self.0);
+            }
+
+            /// Returns the transpose of this matrix
+            fn transpose(&self) -> $py_class_name {
+                return $py_class_name::new(self.0.transpose());
+            }
+            /// Returns the determinant of this matrix
+            fn determinant(&self) -> $var_type {
+                return self.0.determinant();
+            }
+            /// Returns the inverse of this matrix
+            ///
+            /// Only an exactly zero determinant raises, a nearly singular matrix inverts to very large or
+            /// non-finite components
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Inverse matrix, raises SingularMatrixError if this matrix is not invertible
+            ///
+            fn inverse(&self) -> PyResult<$py_class_name> {
+                if self.0.determinant() == 0.0 {
+                    return Err(SingularMatrixError::new_err("Matrix is not invertible, determinant is 0"));
+                }
+                return Ok($py_class_name::new(self.0.inverse()));
+            }
+
+            /// Pickle support, restored from a version tagged list of the column major components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_cols_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_cols_array(&py_value::unpack_state(&state)?)))
+            }
+            /// Copy this matrix
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
+        }
+
+        macro_rules! from_glam {
+            ($a:ty, $b:ty) => {
+                impl From<$a> for $b {
+                    fn from(value: $a) -> Self {
+                        Self(value.clone())
+                    }
+                }
+            };
+        }
+        from_glam!($glam_class_name, $py_class_name);
+        from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = $glam_class_name;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        macro_rules! ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Add<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn add(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 + rhs.0)
+                    }
+                }
+                impl Sub<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn sub(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 - rhs.0)
+                    }
+                }
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs.0)
+                    }
+                }
+            };
+        }
+        ops_with_self!($py_class_name, $py_class_name);
+        ops_with_self!($py_class_name, &$py_class_name);
+        ops_with_self!(&$py_class_name, $py_class_name);
+        ops_with_self!(&$py_class_name, &$py_class_name);
+
+        macro_rules! vec3_ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Mul<$a> for $b {
+                    type Output = $py_vec_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        <$py_vec_class_name>::new(self.0 * Into::<$glam_vec_class_name>::into(rhs))
+                    }
+                }
+            };
+        }
+        vec3_ops_with_self!($py_vec_class_name, $py_class_name);
+        vec3_ops_with_self!($py_vec_class_name, &$py_class_name);
+        vec3_ops_with_self!(&$py_vec_class_name, $py_class_name);
+        vec3_ops_with_self!(&$py_vec_class_name, &$py_class_name);
+
+        macro_rules! ops_with_glam {
+            ($a:ty, $b:ty) => {
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs)
+                    }
+                }
+            };
+        }
+        ops_with_glam!($glam_class_name, $py_class_name);
+        ops_with_glam!(&$glam_class_name, $py_class_name);
+        ops_with_glam!($glam_class_name, &$py_class_name);
+        ops_with_glam!(&$glam_class_name, &$py_class_name);
+        ops_with_glam!($var_type, $py_class_name);
+        ops_with_glam!($var_type, &$py_class_name);
+    };
+}
+mat3_glam_wrapper!(DMat3, vec3::DVec3, quat::DQuat, glam::DMat3, glam::DVec3, f64, Mat3MatOpsEnum);
+#[cfg(feature = "f32")]
+mat3_glam_wrapper!(
+    Mat3,
+    vec3::Vec3,
+    quat::Quat,
+    glam::Mat3,
+    glam::Vec3,
+    f32,
+    py_value::F32Operand<Mat3MatOpsEnum, DMat3>
+);
+
+/// Creates a 3x3 f64 matrix from three column vectors
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn dmat3(x_axis: vec3::DVec3, y_axis: vec3::DVec3, z_axis: vec3::DVec3) -> DMat3 {
+    DMat3::new(glam::dmat3(*x_axis, *y_axis, *z_axis))
+}
+#[cfg(feature = "f32")]
+/// Creates a 3x3 f32 matrix from three column vectors
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn mat3(x_axis: vec3::Vec3, y_axis: vec3::Vec3, z_axis: vec3::Vec3) -> Mat3 {
+    Mat3::new(glam::mat3(*x_axis, *y_axis, *z_axis))
+}
+
+#[cfg(test)]
+mod test_mat3 {
+    use super::*;
+
+    mod test_dmat3 {
+        use super::*;
+
+        #[test]
+        fn test_simple_dmat3_api() {
+            let actual = dmat3(vec3::dvec3(1., 0., 0.), vec3::dvec3(0., 2., 0.), vec3::dvec3(0., 0., 3.));
+            assert_eq!(actual.determinant(), 6.);
+        }
+
+        #[test]
+        fn test_mul_vec() {
+            let mat = DMat3::new(glam::DMat3::from_diagonal(glam::dvec3(1., 2., 3.)));
+            let actual = mat * vec3::dvec3(1., 1., 1.);
+            assert_eq!(*actual, glam::dvec3(1., 2., 3.));
+        }
+
+        #[test]
+        fn test_mul_mat() {
+            let mat = DMat3::new(glam::DMat3::from_diagonal(glam::dvec3(1., 2., 3.)));
+            let actual = mat * mat;
+            assert_eq!(actual.z_axis.z, 9.);
+        }
+    }
+}
@@ -0,0 +1,716 @@
+#[cfg(feature = "pyo3")]
+use crate::errors::SingularMatrixError;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::quat;
+#[cfg(feature = "pyo3")]
+use crate::vec3;
+use crate::vec4;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::PyTuple,
+};
+use std::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+/// Supported matrix operands of either precision
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Mat4MatOpsEnum {
+    DMat4(DMat4),
+    #[cfg(feature = "f32")]
+    Mat4(Mat4),
+    #[cfg(feature = "py-ref")]
+    DMat4Ref(DMat4Ref),
+}
+
+#[cfg(feature = "pyo3")]
+impl Mat4MatOpsEnum {
+    /// Widens the operand to an f64 glam matrix
+    fn to_dmat4(&self) -> PyResult<glam::DMat4> {
+        match self {
+            Mat4MatOpsEnum::DMat4(mat) => Ok(mat.0),
+            #[cfg(feature = "f32")]
+            Mat4MatOpsEnum::Mat4(mat) => Ok(mat.0.as_dmat4()),
+            #[cfg(feature = "py-ref")]
+            Mat4MatOpsEnum::DMat4Ref(mat_ref) => Ok(*mat_ref.get_inner_ref()?),
+        }
+    }
+}
+
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<Mat4MatOpsEnum, DMat4>> for Mat4MatOpsEnum {
+    fn from(value: py_value::F32Operand<Mat4MatOpsEnum, DMat4>) -> Self {
+        value.0
+    }
+}
+
+/// Supported types for multiplying a matrix
+/// mat4 * Some
+///
+/// `M` is the matrix operand, f32 matrices refuse DMat4 operands so that mixed precision promotes to f64
+/// through `DMat4.__rmul__`. A DVec4 is always transformed at f64 precision for the same reason
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Mat4OpsEnum<M> {
+    Float(f64),
+    Int(i64),
+    Mat4(M),
+    DVec4(vec4::DVec4),
+    #[cfg(feature = "f32")]
+    Vec4(vec4::Vec4),
+    #[cfg(feature = "py-ref")]
+    DVec4Ref(vec4::DVec4Ref),
+}
+
+macro_rules! mat4_glam_wrapper {
+    ($py_class_name: ident, $py_vec3_class_name: ty, $py_vec4_class_name: ty, $py_quat_class_name: ty, $glam_class_name: ty, $glam_vec4_class_name: ty, $var_type: ty, $mat_operand: ty) => {
+        /// 4x4 column major matrix
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(feature = "py-ref", derive(simple_py_bevy::PyStructRef))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $py_class_name($glam_class_name);
+
+        impl $py_class_name {
+            pub fn new(mat: $glam_class_name) -> Self {
+                Self(mat)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Multiplies this matrix with a scalar, matrix or vector operand
+            ///
+            /// f64 vectors are transformed by this matrix widened to f64
+            fn mul_op(
+                &self,
+                rhs: Mat4OpsEnum<$mat_operand>,
+                allow_scalar: bool,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec4_class_name, vec4::DVec4>>> {
+                let this = self.0;
+                let widened = || glam::DMat4::from_cols_array(&this.to_cols_array().map(|c| c as f64));
+                match rhs {
+                    Mat4OpsEnum::Float(scalar) if allow_scalar => {
+                        return Ok(Either::Left($py_class_name::new(this * scalar as $var_type)));
+                    }
+                    Mat4OpsEnum::Int(i) if allow_scalar => {
+                        return Ok(Either::Left($py_class_name::new(this * i as $var_type)));
+                    }
+                    Mat4OpsEnum::Float(_) | Mat4OpsEnum::Int(_) => {
+                        return Err(PyTypeError::new_err(
+                            "Matrix multiplication with a scalar is not supported, use `*` instead",
+                        ));
+                    }
+                    Mat4OpsEnum::Mat4(mat) => {
+                        return Ok(Either::Left($py_class_name::new(this * Self::narrow(&mat.into())?)));
+                    }
+                    Mat4OpsEnum::DVec4(vec) => {
+                        return Ok(Either::Right(Either::Right(vec4::DVec4::new(widened() * *vec))));
+                    }
+                    #[cfg(feature = "f32")]
+                    Mat4OpsEnum::Vec4(vec) => {
+                        let vec = <$glam_vec4_class_name>::from_array(vec.to_array().map(|c| c as $var_type));
+                        return Ok(Either::Right(Either::Left(<$py_vec4_class_name>::new(this * vec))));
+                    }
+                    #[cfg(feature = "py-ref")]
+                    Mat4OpsEnum::DVec4Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        return Ok(Either::Right(Either::Right(vec4::DVec4::new(widened() * *vec))));
+                    }
+                }
+            }
+            /// Multiplies a scalar or matrix operand on the left with this matrix
+            fn rmul_op(&self, lhs: Mat4OpsEnum<$mat_operand>, allow_scalar: bool) -> PyResult<$py_class_name> {
+                match lhs {
+                    Mat4OpsEnum::Mat4(mat) => Ok($py_class_name::new(Self::narrow(&mat.into())? * self.0)),
+                    Mat4OpsEnum::Float(scalar) if allow_scalar => Ok($py_class_name::new(scalar as $var_type * self.0)),
+                    Mat4OpsEnum::Int(i) if allow_scalar => Ok($py_class_name::new(i as $var_type * self.0)),
+                    Mat4OpsEnum::Float(_) | Mat4OpsEnum::Int(_) => Err(PyTypeError::new_err(
+                        "Matrix multiplication with a scalar is not supported, use `*` instead",
+                    )),
+                    _ => Err(PyTypeError::new_err(
+                        "A vector can't be multiplied by a matrix on the right, use `m * v`",
+                    )),
+                }
+            }
+            /// Converts a matrix operand to this matrix's precision
+            fn narrow(mat: &Mat4MatOpsEnum) -> PyResult<$glam_class_name> {
+                Ok(<$glam_class_name>::from_cols_array(&mat.to_dmat4()?.to_cols_array().map(|c| c as $var_type)))
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            /// Create a new matrix from four column vectors
+            ///
+            /// # Arguments
+            ///
+            /// - `x_axis` (`vec4`) - first column
+            /// - `y_axis` (`vec4`) - second column
+            /// - `z_axis` (`vec4`) - third column
+            /// - `w_axis` (`vec4`) - fourth column
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Matrix with the given columns
+            ///
+            #[new]
+            pub fn py_new(
+                x_axis: &$py_vec4_class_name,
+                y_axis: &$py_vec4_class_name,
+                z_axis: &$py_vec4_class_name,
+                w_axis: &$py_vec4_class_name,
+            ) -> Self {
+                Self::from_cols(x_axis, y_axis, z_axis, w_axis)
+            }
+
+            /// Create a new matrix from four column vectors
+            #[staticmethod]
+            pub fn from_cols(
+                x_axis: &$py_vec4_class_name,
+                y_axis: &$py_vec4_class_name,
+                z_axis: &$py_vec4_class_name,
+                w_axis: &$py_vec4_class_name,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_cols(x_axis.into(), y_axis.into(), z_axis.into(), w_axis.into());
+                $py_class_name(inner)
+            }
+            /// Create a new matrix from four row vectors
+            #[staticmethod]
+            pub fn from_rows(
+                row0: &$py_vec4_class_name,
+                row1: &$py_vec4_class_name,
+                row2: &$py_vec4_class_name,
+                row3: &$py_vec4_class_name,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_cols(row0.into(), row1.into(), row2.into(), row3.into()).transpose();
+                $py_class_name(inner)
+            }
+            /// Create an affine transformation matrix from the given 3D `scale`, `rotation` and `translation`.
+            ///
+            /// The resulting matrix applies scale first, then rotation, then translation.
+            ///
+            /// # Arguments
+            ///
+            /// - `scale` (`Vec3Like`) - Scale along each axis
+            /// - `rotation` (`QuatLike`) - Rotation, must be normalized
+            /// - `translation` (`Vec3Like`) - Translation
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Affine transformation matrix
+            ///
+            #[staticmethod]
+            pub fn from_scale_rotation_translation(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] scale: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] rotation: quat::QuatLike,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] translation: vec3::Vec3Like,
+            ) -> Self {
+                let inner = <$glam_class_name>::from_scale_rotation_translation(scale.into(), rotation.into(), translation.into());
+                $py_class_name(inner)
+            }
+            /// Create a right-handed view matrix using a camera position, a focal point and an up direction.
+            ///
+            /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+            ///
+            /// # Arguments
+            ///
+            /// - `eye` (`Vec3Like`) - Camera position
+            /// - `center` (`Vec3Like`) - Focal point
+            /// - `up` (`Vec3Like`) - Up direction, must be normalized
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - World to view matrix
+            ///
+            #[staticmethod]
+            pub fn look_at_rh(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] eye: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] center: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] up: vec3::Vec3Like,
+            ) -> Self {
+                $py_class_name(<$glam_class_name>::look_at_rh(eye.into(), center.into(), up.into()))
+            }
+            /// Create a left-handed view matrix using a camera position, a focal point and an up direction.
+            ///
+            /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+            ///
+            /// # Arguments
+            ///
+            /// - `eye` (`Vec3Like`) - Camera position
+            /// - `center` (`Vec3Like`) - Focal point
+            /// - `up` (`Vec3Like`) - Up direction, must be normalized
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - World to view matrix
+            ///
+            #[staticmethod]
+            pub fn look_at_lh(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] eye: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] center: vec3::Vec3Like,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] up: vec3::Vec3Like,
+            ) -> Self {
+                $py_class_name(<$glam_class_name>::look_at_lh(eye.into(), center.into(), up.into()))
+            }
+            /// Create a right-handed perspective projection matrix with `[0,1]` depth range.
+            ///
+            /// # Arguments
+            ///
+            /// - `fov_y_radians` (`float`) - Vertical field of view in radians
+            /// - `aspect_ratio` (`float`) - Width divided by height
+            /// - `z_near` (`float`) - Distance to the near plane, must be greater than 0
+            /// - `z_far` (`float`) - Distance to the far plane, must be greater than 0
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Projection matrix
+            ///
+            #[staticmethod]
+            pub fn perspective_rh(
+                fov_y_radians: $var_type,
+                aspect_ratio: $var_type,
+                z_near: $var_type,
+                z_far: $var_type,
+            ) -> PyResult<Self> {
+                if z_near <= 0.0 || z_far <= 0.0 {
+                    return Err(PyValueError::new_err("z_near and z_far must be greater than 0"));
+                }
+                let inner = <$glam_class_name>::perspective_rh(fov_y_radians, aspect_ratio, z_near, z_far);
+                Ok($py_class_name(inner))
+            }
+            /// Create a right-handed orthographic projection matrix with `[0,1]` depth range.
+            ///
+            /// # Arguments
+            ///
+            /// - `left` (`float`) - Left plane
+            /// - `right` (`float`) - Right plane
+            /// - `bottom` (`float`) - Bottom plane
+            /// - `top` (`float`) - Top plane
+            /// - `near` (`float`) - Near plane
+            /// - `far` (`float`) - Far plane
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Projection matrix
+            ///
+            #[staticmethod]
+            pub fn orthographic_rh(
+                left: $var_type,
+                right: $var_type,
+                bottom: $var_type,
+                top: $var_type,
+                near: $var_type,
+                far: $var_type,
+            ) -> Self {
+                $py_class_name(<$glam_class_name>::orthographic_rh(left, right, bottom, top, near, far))
+            }
+
+            #[getter]
+            fn get_x_axis(&self) -> $py_vec4_class_name {
+                return <$py_vec4_class_name>::new(self.0.x_axis);
+            }
+            #[getter]
+            fn get_y_axis(&self) -> $py_vec4_class_name {
+                return <$py_vec4_class_name>::new(self.0.y_axis);
+            }
+            #[getter]
+            fn get_z_axis(&self) -> $py_vec4_class_name {
+                return <$py_vec4_class_name>::new(self.0.z_axis);
+            }
+            #[getter]
+            fn get_w_axis(&self) -> $py_vec4_class_name {
+                return <$py_vec4_class_name>::new(self.0.w_axis);
+            }
+            #[setter]
+            fn set_x_axis(&mut self, x_axis: &$py_vec4_class_name) {
+                self.0.x_axis = x_axis.into();
+            }
+            #[setter]
+            fn set_y_axis(&mut self, y_axis: &$py_vec4_class_name) {
+                self.0.y_axis = y_axis.into();
+            }
+            #[setter]
+            fn set_z_axis(&mut self, z_axis: &$py_vec4_class_name) {
+                self.0.z_axis = z_axis.into();
+            }
+            #[setter]
+            fn set_w_axis(&mut self, w_axis: &$py_vec4_class_name) {
+                self.0.w_axis = w_axis.into();
+            }
+
+            /// Get a column of this matrix
+            fn col(&self, index: usize) -> PyResult<$py_vec4_class_name> {
+                if index > 3 {
+                    return Err(PyValueError::new_err("Column index must be 0, 1, 2 or 3"));
+                }
+                return Ok(<$py_vec4_class_name>::new(self.0.col(index)));
+            }
+            /// Get a row of this matrix
+            fn row(&self, index: usize) -> PyResult<$py_vec4_class_name> {
+                if index > 3 {
+                    return Err(PyValueError::new_err("Row index must be 0, 1, 2 or 3"));
+                }
+                return Ok(<$py_vec4_class_name>::new(self.0.row(index)));
+            }
+
+            /// Convert this matrix to a tuple of 4 column tuples
+            ///
+            /// # Returns
+            ///
+            /// - `((float, float, float, float), ...)` - Column major tuple
+            ///
+            #[allow(clippy::wrong_self_convention, clippy::type_complexity)]
+            fn to_tuple(
+                &self,
+            ) -> (
+                ($var_type, $var_type, $var_type, $var_type),
+                ($var_type, $var_type, $var_type, $var_type),
+                ($var_type, $var_type, $var_type, $var_type),
+                ($var_type, $var_type, $var_type, $var_type),
+            ) {
+                let [x, y, z, w] = self.0.to_cols_array_2d();
+                (
+                    (x[0], x[1], x[2], x[3]),
+                    (y[0], y[1], y[2], y[3]),
+                    (z[0], z[1], z[2], z[3]),
+                    (w[0], w[1], w[2], w[3]),
+                )
+            }
+            /// Decompose this affine matrix into its scale, rotation and translation
+            ///
+            /// The matrix must be an affine transform. A negative determinant, i.e. a reflection, gives a
+            /// negative x scale, only a determinant of exactly 0 raises
+            ///
+            /// # Returns
+            ///
+            /// - `(vec3, quat, vec3)` - scale, rotation and translation, raises SingularMatrixError if the determinant is 0
+            ///
+            #[allow(clippy::wrong_self_convention)]
+            fn to_scale_rotation_translation(&self) -> PyResult<($py_vec3_class_name, $py_quat_class_name, $py_vec3_class_name)> {
+                if self.0.determinant() == 0.0 {
+                    return Err(SingularMatrixError::new_err("Matrix can not be decomposed, determinant is 0"));
+                }
+                let (scale, rotation, translation) = self.0.to_scale_rotation_translation();
+                Ok((
+                    <$py_vec3_class_name>::new(scale),
+                    <$py_quat_class_name>::new(rotation),
+                    <$py_vec3_class_name>::new(translation),
+                ))
+            }
+
+            /// Transform a 3D point, applying translation. The perspective divide is not performed.
+            fn transform_point3(&self, point: &$py_vec3_class_name) -> $py_vec3_class_name {
+                return <$py_vec3_class_name>::new(self.0.transform_point3(point.into()));
+            }
+            /// Transform a 3D direction, ignoring translation
+            fn transform_vector3(&self, vector: &$py_vec3_class_name) -> $py_vec3_class_name {
+                return <$py_vec3_class_name>::new(self.0.transform_vector3(vector.into()));
+            }
+            /// Transform a 3D point, applying the perspective divide. Use this with projection matrices
+            fn project_point3(&self, point: &$py_vec3_class_name) -> $py_vec3_class_name {
+                return <$py_vec3_class_name>::new(self.0.project_point3(point.into()));
+            }
+
+            /// Multiply this matrix with a scalar, another matrix or a vector
+            ///
+            /// Mixing f32 and f64 operands returns f64
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Bound<'_, PyAny>`) - Scalar, mat4 or vec4 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+            ///
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Mat4OpsEnum<$mat_operand>,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec4_class_name, vec4::DVec4>>> {
+                // this * rhs
+                return self.mul_op(rhs, true);
+            }
+            /// Multiply a scalar or another matrix with this matrix, `lhs * self`
+            ///
+            /// A vector on the left raises TypeError, vectors are transformed as `m * v`
+            ///
+            /// # Arguments
+            ///
+            /// - `lhs` (`Bound<'_, PyAny>`) - Scalar or mat4 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - New matrix
+            ///
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Mat4OpsEnum<$mat_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs * this
+                return self.rmul_op(lhs, true);
+            }
+            /// Matrix multiply this matrix with another matrix or a vector
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Bound<'_, PyAny>`) - mat4 or vec4 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Either<, >>` - Either a new matrix or a transformed vector
+            ///
+            fn __matmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Mat4OpsEnum<$mat_operand>,
+            ) -> PyResult<Either<$py_class_name, Either<$py_vec4_class_name, vec4::DVec4>>> {
+                // this @ rhs
+                return self.mul_op(rhs, false);
+            }
+            /// Matrix multiply another matrix with this matrix, `lhs @ self`
+            ///
+            /// # Arguments
+            ///
+            /// - `lhs` (`Bound<'_, PyAny>`) - mat4 to multiply
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - New matrix
+            ///
+            fn __rmatmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Mat4OpsEnum<$mat_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs @ this
+                return self.rmul_op(lhs, false);
+            }
+            fn __add__(&self, rhs: &$py_class_name) -> $py_class_name {
+                return $py_class_name::new(self.0 + rhs.0);
+            }
+            fn __sub__(&self, rhs: &$py_class_name) -> $py_class_name {
+                return $py_class_name::new(self.0 - rhs.0);
+            }
+            fn __neg__(&self) -> $py_class_name {
+                return $py_class_name::new(-self.0);
+            }
+
+            /// Returns the transpose of this matrix
+            fn transpose(&self) -> $py_class_name {
+                return $py_class_name::new(self.0.transpose());
+            }
+            /// Returns the determinant of this matrix
+            fn determinant(&self) -> $var_type {
+                return self.0.determinant();
+            }
+            /// Returns the inverse of this matrix
+            ///
+            /// Only an exactly zero determinant raises, a nearly singular matrix inverts to very large or
+            /// non-finite components
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Inverse matrix, raises SingularMatrixError if this matrix is not invertible
+            ///
+            fn inverse(&self) -> PyResult<$py_class_name> {
+                if self.0.determinant() == 0.0 {
+                    return Err(SingularMatrixError::new_err("Matrix is not invertible, determinant is 0"));
+                }
+                return Ok($py_class_name::new(self.0.inverse()));
+            }
+
+            /// Pickle support, restored from a version tagged list of the column major components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_cols_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_cols_array(&py_value::unpack_state(&state)?)))
+            }
+            /// Copy this matrix
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
+        }
+
+        macro_rules! from_glam {
+            ($a:ty, $b:ty) => {
+                impl From<$a> for $b {
+                    fn from(value: $a) -> Self {
+                        Self(value.clone())
+                    }
+                }
+            };
+        }
+        from_glam!($glam_class_name, $py_class_name);
+        from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = $glam_class_name;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        macro_rules! ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Add<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn add(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 + rhs.0)
+                    }
+                }
+                impl Sub<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn sub(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 - rhs.0)
+                    }
+                }
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs.0)
+                    }
+                }
+            };
+        }
+        ops_with_self!($py_class_name, $py_class_name);
+        ops_with_self!($py_class_name, &$py_class_name);
+        ops_with_self!(&$py_class_name, $py_class_name);
+        ops_with_self!(&$py_class_name, &$py_class_name);
+
+        macro_rules! vec4_ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Mul<$a> for $b {
+                    type Output = $py_vec4_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        <$py_vec4_class_name>::new(self.0 * Into::<$glam_vec4_class_name>::into(rhs))
+                    }
+                }
+            };
+        }
+        vec4_ops_with_self!($py_vec4_class_name, $py_class_name);
+        vec4_ops_with_self!($py_vec4_class_name, &$py_class_name);
+        vec4_ops_with_self!(&$py_vec4_class_name, $py_class_name);
+        vec4_ops_with_self!(&$py_vec4_class_name, &$py_class_name);
+
+        macro_rules! ops_with_glam {
+            ($a:ty, $b:ty) => {
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs)
+                    }
+                }
+            };
+        }
+        ops_with_glam!($glam_class_name, $py_class_name);
+        ops_with_glam!(&$glam_class_name, $py_class_name);
+        ops_with_glam!($glam_class_name, &$py_class_name);
+        ops_with_glam!(&$glam_class_name, &$py_class_name);
+        ops_with_glam!($var_type, $py_class_name);
+        ops_with_glam!($var_type, &$py_class_name);
+    };
+}
+mat4_glam_wrapper!(DMat4, vec3::DVec3, vec4::DVec4, quat::DQuat, glam::DMat4, glam::DVec4, f64, Mat4MatOpsEnum);
+#[cfg(feature = "f32")]
+mat4_glam_wrapper!(
+    Mat4,
+    vec3::Vec3,
+    vec4::Vec4,
+    quat::Quat,
+    glam::Mat4,
+    glam::Vec4,
+    f32,
+    py_value::F32Operand<Mat4MatOpsEnum, DMat4>
+);
+
+/// Creates a 4x4 f64 matrix from four column vectors
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn dmat4(x_axis: vec4::DVec4, y_axis: vec4::DVec4, z_axis: vec4::DVec4, w_axis: vec4::DVec4) -> DMat4 {
+    DMat4::new(glam::dmat4(*x_axis, *y_axis, *z_axis, *w_axis))
+}
+#[cfg(feature = "f32")]
+/// Creates a 4x4 f32 matrix from four column vectors
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn mat4(x_axis: vec4::Vec4, y_axis: vec4::Vec4, z_axis: vec4::Vec4, w_axis: vec4::Vec4) -> Mat4 {
+    Mat4::new(glam::mat4(*x_axis, *y_axis, *z_axis, *w_axis))
+}
+
+#[cfg(test)]
+mod test_mat4 {
+    use super::*;
+
+    mod test_dmat4 {
+        use super::*;
+
+        #[test]
+        fn test_scale_rotation_translation_round_trip() {
+            let rotation = glam::DQuat::from_rotation_z(0.5);
+            let mat = DMat4::new(glam::DMat4::from_scale_rotation_translation(
+                glam::dvec3(1., 2., 3.),
+                rotation,
+                glam::dvec3(4., 5., 6.),
+            ));
+            let (scale, actual_rotation, translation) = mat.0.to_scale_rotation_translation();
+            assert!(scale.abs_diff_eq(glam::dvec3(1., 2., 3.), 1e-12));
+            assert!(actual_rotation.abs_diff_eq(rotation, 1e-12));
+            assert_eq!(translation, glam::dvec3(4., 5., 6.));
+        }
+
+        #[test]
+        fn test_mul_vec() {
+            let mat = DMat4::new(glam::DMat4::from_translation(glam::dvec3(1., 2., 3.)));
+            let actual = mat * vec4::dvec4(0., 0., 0., 1.);
+            assert_eq!(*actual, glam::dvec4(1., 2., 3., 1.));
+        }
+    }
+}
@@ -0,0 +1,390 @@
+//! Helpers for giving the wrapper classes python value semantics
+use either::Either;
+use pyo3::{
+    IntoPyObjectExt, PyTypeInfo,
+    exceptions::{PyIndexError, PyTypeError, PyValueError},
+    prelude::*,
+    types::{PySlice, PyString, PyTuple, PyType},
+};
+use std::fmt::Debug;
+use std::hash::{DefaultHasher, Hash, Hasher};
+#[cfg(feature = "f32")]
+use std::marker::PhantomData;
+
+/// Formats a float so that `eval(repr)` yields the same value
+///
+/// Rust's debug formatting gives the shortest round-tripping representation, but non-finite
+/// values have to be spelled as python expressions
+pub(crate) fn float_repr<T: Debug + Copy + Into<f64>>(value: T) -> String {
+    let as_f64: f64 = value.into();
+    if as_f64.is_nan() {
+        "float('nan')".to_string()
+    } else if as_f64.is_infinite() {
+        if as_f64 > 0.0 {
+            "float('inf')".to_string()
+        } else {
+            "-float('inf')".to_string()
+        }
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+/// Hashes float components consistently with value equality
+///
+/// Components are hashed at f64 precision so that equal f32 and f64 values hash the same,
+/// `-0.0` is hashed as `0.0` since they compare equal, and every NaN hashes to the same value.
+/// NaN never compares equal, so objects containing NaN are hashable but never match on lookup
+pub(crate) fn hash_floats(values: &[f64]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for value in values {
+        let canonical = if value.is_nan() {
+            f64::NAN
+        } else if *value == 0.0 {
+            0.0
+        } else {
+            *value
+        };
+        canonical.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Float types the wrapper classes are generated for
+pub(crate) trait Component {
+    /// Precision of this type, returned by the `dtype` properties
+    const DTYPE: Dtype;
+}
+impl Component for f64 {
+    const DTYPE: Dtype = Dtype::Float64;
+}
+impl Component for f32 {
+    const DTYPE: Dtype = Dtype::Float32;
+}
+
+/// Generates the `dtype` property of a wrapper class with `$var_type` components
+///
+/// The property gets a `#[pymethods]` block of its own, which needs pyo3's `multiple-pymethods`.
+/// Leading attributes are applied to that block, e.g. the py-ref methods attribute
+macro_rules! dtype_getter {
+    ($(#[$attr: meta])* $py_class_name: ty, $var_type: ty) => {
+        $(#[$attr])*
+        #[pyo3_stub_gen::derive::gen_stub_pymethods]
+        #[pymethods]
+        impl $py_class_name {
+            /// Component type, `'float32'` or `'float64'`, usable wherever numpy expects a dtype
+            #[getter]
+            fn dtype(&self) -> &'static str {
+                <$var_type as $crate::py_value::Component>::DTYPE.name()
+            }
+        }
+    };
+}
+pub(crate) use dtype_getter;
+
+/// Component precision requested through a `dtype` argument
+///
+/// Accepts the names `'float32'` and `'float64'`, numpy dtypes, numpy scalar types such as
+/// `numpy.float32` and python's `float`, which is f64
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Dtype {
+    Float32,
+    Float64,
+}
+
+impl Dtype {
+    /// numpy name of this precision
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Dtype::Float32 => "float32",
+            Dtype::Float64 => "float64",
+        }
+    }
+
+    /// Precision of `obj`'s `dtype` attribute, e.g. of a numpy array, if it's a supported float type
+    pub(crate) fn of(obj: &Bound<'_, PyAny>) -> Option<Self> {
+        obj.getattr("dtype").ok()?.extract().ok()
+    }
+}
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Dtype {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let name = if let Ok(name) = obj.cast::<PyString>() {
+            name.to_str()?.to_owned()
+        } else if let Ok(ty) = obj.cast::<PyType>() {
+            ty.name()?.to_string()
+        } else {
+            obj.getattr("name")?.extract::<String>()?
+        };
+        match name.as_str() {
+            "float32" | "f4" | "single" => Ok(Dtype::Float32),
+            "float64" | "f8" | "double" | "float" => Ok(Dtype::Float64),
+            _ => Err(PyValueError::new_err(format!(
+                "unsupported dtype '{}', expected 'float32' or 'float64'",
+                name
+            ))),
+        }
+    }
+}
+
+/// Class attribute descriptor handing out a fresh copy of its value on every access
+///
+/// A plain `#[classattr]` is one instance shared by the whole process, so `v = DVec3.ZERO; v += 1.0`
+/// would change `DVec3.ZERO` itself
+#[pyclass(module = "pyglam", frozen)]
+pub(crate) struct ClassConstant(Py<PyAny>);
+
+#[pymethods]
+impl ClassConstant {
+    fn __get__<'py>(
+        &self,
+        py: Python<'py>,
+        _instance: Option<Bound<'py, PyAny>>,
+        _owner: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.0.bind(py).call_method0("__copy__")
+    }
+}
+
+/// Return type of `#[classattr]` constants, installs `value` behind a `ClassConstant` descriptor
+///
+/// Stubs show the type of the value rather than the descriptor
+pub(crate) struct Constant<T>(pub T);
+
+impl<'py, T> IntoPyObject<'py> for Constant<T>
+where
+    T: IntoPyObject<'py>,
+    PyErr: From<T::Error>,
+{
+    type Target = ClassConstant;
+    type Output = Bound<'py, ClassConstant>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        Bound::new(py, ClassConstant(self.0.into_py_any(py)?))
+    }
+}
+
+impl<T: pyo3_stub_gen::PyStubType> pyo3_stub_gen::PyStubType for Constant<T> {
+    fn type_output() -> pyo3_stub_gen::TypeInfo {
+        T::type_output()
+    }
+}
+
+/// Scalar closeness test with the same semantics as python's `math.isclose`
+pub(crate) fn is_close(a: f64, b: f64, rel_tol: f64, abs_tol: f64) -> bool {
+    if a == b {
+        // also covers equal infinities
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    diff <= (rel_tol * b.abs()).max(rel_tol * a.abs()).max(abs_tol)
+}
+
+/// Validates tolerances the same way python's `math.isclose` does
+pub(crate) fn check_tolerances(rel_tol: f64, abs_tol: f64) -> PyResult<()> {
+    if rel_tol < 0.0 || abs_tol < 0.0 {
+        return Err(PyValueError::new_err("tolerances must be non-negative"));
+    }
+    Ok(())
+}
+
+/// Converts a python style (possibly negative) index into a checked index into `len` components
+pub(crate) fn normalize_index(index: isize, len: usize) -> PyResult<usize> {
+    let normalized = if index < 0 { index + len as isize } else { index };
+    if normalized < 0 || normalized >= len as isize {
+        return Err(PyIndexError::new_err(format!(
+            "index {} is out of range for {} components",
+            index, len
+        )));
+    }
+    Ok(normalized as usize)
+}
+
+/// Implements tuple style `__getitem__` over `components`, an int yields a single component
+/// and a slice yields a tuple of components
+pub(crate) fn get_item<'py, T>(
+    components: &[T],
+    index: &Bound<'py, PyAny>,
+) -> PyResult<Either<T, Bound<'py, PyTuple>>>
+where
+    T: Copy + IntoPyObject<'py>,
+{
+    if let Ok(slice) = index.cast::<PySlice>() {
+        let indices = slice.indices(components.len() as isize)?;
+        let mut selected = Vec::with_capacity(indices.slicelength);
+        let mut i = indices.start;
+        for _ in 0..indices.slicelength {
+            selected.push(components[i as usize]);
+            i += indices.step;
+        }
+        return Ok(Either::Right(PyTuple::new(index.py(), selected)?));
+    }
+    let i = normalize_index(index.extract::<isize>()?, components.len())?;
+    Ok(Either::Left(components[i]))
+}
+
+/// Implements list style `__setitem__` over `components`, an int sets a single component and a
+/// slice assigns a sequence of floats, which must match the slice length since the component count
+/// is fixed
+pub(crate) fn set_item<'py, T>(
+    components: &mut [T],
+    index: &Bound<'py, PyAny>,
+    value: &Bound<'py, PyAny>,
+) -> PyResult<()>
+where
+    T: for<'a> FromPyObject<'a, 'py, Error = PyErr>,
+{
+    if let Ok(slice) = index.cast::<PySlice>() {
+        let indices = slice.indices(components.len() as isize)?;
+        let values = value.extract::<Vec<T>>()?;
+        if values.len() != indices.slicelength {
+            return Err(PyValueError::new_err(format!(
+                "attempt to assign sequence of size {} to slice of size {}",
+                values.len(),
+                indices.slicelength
+            )));
+        }
+        let mut i = indices.start;
+        for value in values {
+            components[i as usize] = value;
+            i += indices.step;
+        }
+        return Ok(());
+    }
+    let i = normalize_index(index.extract::<isize>()?, components.len())?;
+    components[i] = value.extract::<T>()?;
+    Ok(())
+}
+
+/// Extracts `N` float components from a sequence such as a tuple, list or numpy 1-D array,
+/// falling back to objects that can be converted with `__array__`
+///
+/// pyglam's own classes never go through `__array__`, a pyglam type that didn't match the caller's
+/// pyglam arms isn't vec-like and converting it would import numpy for nothing. An `__array__` that
+/// raises, e.g. because numpy isn't installed, also means the object isn't vec-like
+pub(crate) fn extract_components<const N: usize>(obj: &Bound<'_, PyAny>) -> PyResult<[f64; N]> {
+    if let Ok(components) = obj.extract::<[f64; N]>() {
+        return Ok(components);
+    }
+    let foreign = obj.get_type().module().is_ok_and(|module| module != "pyglam");
+    if foreign
+        && obj.hasattr("__array__").unwrap_or(false)
+        && let Ok(array) = obj.call_method0("__array__")
+        && let Ok(components) = array.extract::<[f64; N]>()
+    {
+        return Ok(components);
+    }
+    Err(PyTypeError::new_err(format!(
+        "expected {} floats as a sequence or array, got '{}'",
+        N,
+        obj.get_type().name()?
+    )))
+}
+
+/// Downcasts `obj` to a numpy array
+///
+/// The type check needs numpy's C API, so numpy is only consulted for objects exposing the array
+/// interface once something else has imported it, keeping pyglam usable when numpy isn't installed
+#[cfg(feature = "numpy")]
+pub(crate) fn as_ndarray<'a, 'py>(obj: &'a Bound<'py, PyAny>) -> Option<&'a Bound<'py, numpy::PyUntypedArray>> {
+    let candidate = obj.hasattr("__array_interface__").unwrap_or(false)
+        && obj
+            .py()
+            .import("sys")
+            .and_then(|sys| sys.getattr("modules")?.contains("numpy"))
+            .unwrap_or(false);
+    if !candidate {
+        return None;
+    }
+    obj.cast::<numpy::PyUntypedArray>().ok()
+}
+
+/// Operand of f32 arithmetic that refuses instances of the f64 class `W`
+///
+/// pyo3 returns NotImplemented when an operand fails to extract, so `Vec3 + DVec3` falls back to
+/// `DVec3.__radd__`. Mixed precision arithmetic is therefore promoted to f64 instead of being
+/// truncated to the precision of the left operand
+#[cfg(feature = "f32")]
+pub(crate) struct F32Operand<T, W>(pub T, PhantomData<W>);
+
+#[cfg(feature = "f32")]
+impl<'a, 'py, T, W> FromPyObject<'a, 'py> for F32Operand<T, W>
+where
+    T: FromPyObject<'a, 'py, Error = PyErr>,
+    W: PyTypeInfo,
+{
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if obj.is_instance_of::<W>() {
+            return Err(PyTypeError::new_err(format!(
+                "{} operands are not narrowed to f32",
+                W::NAME
+            )));
+        }
+        Ok(Self(T::extract(obj)?, PhantomData))
+    }
+}
+
+/// Layout version stored with every pickled state, bumped whenever a state layout changes
+pub(crate) const PICKLE_VERSION: u8 = 1;
+
+/// Builds a `__reduce__` value restoring an object of class `T` through `T._from_state(state)`
+///
+/// The state is tagged with `PICKLE_VERSION`, see `unpack_state`
+pub(crate) fn reduce<'py, T: PyTypeInfo>(
+    py: Python<'py>,
+    state: impl IntoPyObject<'py, Error: Into<PyErr>>,
+) -> PyResult<Bound<'py, PyTuple>> {
+    let restore = py.get_type::<T>().getattr("_from_state")?;
+    (restore, ((PICKLE_VERSION, state),)).into_pyobject(py)
+}
+
+/// Unpacks a state built by `reduce`, raising ValueError for a layout this build can't read
+pub(crate) fn unpack_state<'py, T>(state: &Bound<'py, PyAny>) -> PyResult<T>
+where
+    T: for<'a> FromPyObject<'a, 'py, Error = PyErr>,
+{
+    let (version, data): (u8, Bound<'py, PyAny>) = state.extract()?;
+    if version != PICKLE_VERSION {
+        return Err(PyValueError::new_err(format!(
+            "unsupported pyglam pickle version {}, expected {}",
+            version, PICKLE_VERSION
+        )));
+    }
+    data.extract::<T>()
+}
+
+#[cfg(test)]
+mod test_py_value {
+    use super::*;
+
+    #[test]
+    fn test_float_repr() {
+        assert_eq!(float_repr(1.0f64), "1.0");
+        assert_eq!(float_repr(0.1f32), "0.1");
+        assert_eq!(float_repr(f64::NEG_INFINITY), "-float('inf')");
+        assert_eq!(float_repr(f64::NAN), "float('nan')");
+    }
+
+    #[test]
+    fn test_is_close() {
+        assert!(is_close(1.0, 1.0 + 1e-10, 1e-9, 0.0));
+        assert!(!is_close(0.0, 1e-10, 1e-9, 0.0));
+        assert!(is_close(0.0, 1e-10, 1e-9, 1e-9));
+        assert!(is_close(f64::INFINITY, f64::INFINITY, 1e-9, 0.0));
+        assert!(!is_close(f64::NAN, f64::NAN, 1e-9, 0.0));
+    }
+
+    #[test]
+    fn test_hash_signed_zero() {
+        assert_eq!(hash_floats(&[0.0, 1.0]), hash_floats(&[-0.0, 1.0]));
+        assert_eq!(hash_floats(&[f64::NAN]), hash_floats(&[-f64::NAN]));
+    }
+}
@@ -1,32 +1,127 @@
+// pyo3 calls the unsafe buffer protocol methods from its generated wrappers without an unsafe
+// block, which the 2024 edition lints when the methods are defined inside `macro_rules!`
+#![cfg_attr(feature = "numpy", allow(unsafe_op_in_unsafe_fn))]
+#[cfg(feature = "pyo3")]
+use crate::mat3;
+#[cfg(feature = "numpy")]
+use crate::buffer;
+#[cfg(feature = "pyo3")]
+use crate::errors::NormalizationError;
+#[cfg(feature = "pyo3")]
+use crate::euler::EulerRotLike;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::random;
+#[cfg(feature = "pyo3")]
+use crate::quat_array;
 use crate::vec3;
 #[cfg(feature = "pyo3")]
 use either::Either;
 use glam;
+#[cfg(feature = "numpy")]
+use numpy::{AllowTypeChange, PyArrayLike1, PyUntypedArray};
+#[cfg(feature = "numpy")]
+use pyo3::{exceptions::PyValueError, ffi};
 #[cfg(feature = "pyo3")]
-use pyo3::{exceptions::PyNotImplementedError, prelude::*};
+use pyo3::{
+    exceptions::PyTypeError,
+    prelude::*,
+    types::{PyIterator, PyTuple},
+};
+#[cfg(feature = "numpy")]
+use std::ffi::c_int;
 use std::ops::{Deref, DerefMut, Mul};
 
-/// Supported types for vector operations on other vecs where scalars don't make sense
-/// example: dot, cross
+/// Supported types for operations between two quaternions
+/// example: ==, is_close
 #[cfg(feature = "pyo3")]
 #[derive(FromPyObject)]
-enum QuatOpsEnum {
-    DVec3(vec3::DVec3),
-    #[cfg(feature = "f32")]
-    Vec3(vec3::Vec3),
+enum QuatQuatOpsEnum {
     DQuat(DQuat),
     #[cfg(feature = "f32")]
     Quat(Quat),
     #[cfg(feature = "py-ref")]
-    DVec3Ref(vec3::DVec3Ref),
-    #[cfg(feature = "py-ref")]
     DQuatRef(DQuatRef),
 }
 
+/// Supported types for quaternion multiplication
+/// quat * Some
+///
+/// `Q` is the quat operand, f32 quats refuse DQuat operands so that mixed precision promotes to f64.
+/// A DVec3 is always rotated at f64 precision for the same reason
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum QuatOpsEnum<'py, A: pyo3::PyClass, Q> {
+    QuatArray(PyRef<'py, A>),
+    DVec3(vec3::DVec3),
+    Quat(Q),
+    Vec3(vec3::Vec3Like),
+}
+
+/// Supported types for reflected quaternion multiplication
+/// Some * quat
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum QuatRmulOpsEnum {
+    Quat(QuatLike),
+    // only extracted to give vectors on the left a helpful error
+    #[allow(dead_code)]
+    Vec3(vec3::Vec3Like),
+}
+
+/// Any python object that can be used where a quaternion is expected
+///
+/// Accepts pyglam quats of either precision, 4 component xyzw sequences such as tuples and lists,
+/// numpy 1-D arrays of length 4 and objects exposing `__array__`.
+/// Components are held at f64 precision and cast to the precision of the receiving type
+#[cfg(feature = "pyo3")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuatLike(pub glam::DQuat);
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py> FromPyObject<'a, 'py> for QuatLike {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        match obj.extract::<QuatQuatOpsEnum>() {
+            Ok(QuatQuatOpsEnum::DQuat(quat)) => Ok(QuatLike(quat.0)),
+            #[cfg(feature = "f32")]
+            Ok(QuatQuatOpsEnum::Quat(quat)) => Ok(QuatLike(quat.0.as_dquat())),
+            #[cfg(feature = "py-ref")]
+            Ok(QuatQuatOpsEnum::DQuatRef(quat_ref)) => Ok(QuatLike(*quat_ref.get_inner_ref()?)),
+            Err(_) => {
+                let components = py_value::extract_components::<4>(&obj)?;
+                Ok(QuatLike(glam::DQuat::from_array(components)))
+            }
+        }
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<QuatLike> for glam::DQuat {
+    fn from(value: QuatLike) -> Self {
+        value.0
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<QuatLike> for glam::Quat {
+    fn from(value: QuatLike) -> Self {
+        value.0.as_quat()
+    }
+}
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<QuatLike, DQuat>> for glam::Quat {
+    fn from(value: py_value::F32Operand<QuatLike, DQuat>) -> Self {
+        value.0.into()
+    }
+}
+
 macro_rules! vec3_glam_wrapper {
-    ($py_class_name: ident, $py_vec_class_name: ty, $glam_class_name: ty,$glam_vec_class_name: ty, $var_type: ty) => {
+    ($py_class_name: ident, $py_vec_class_name: ty, $py_mat3_class_name: ty, $py_quat_array_class_name: ty, $glam_class_name: ty,$glam_vec_class_name: ty, $var_type: ty, $quat_operand: ty) => {
         /// 4 Component Quaternion wxyz
-        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass)]
+        ///
+        /// Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
         #[cfg_attr(feature = "py-ref", derive(simple_py_bevy::PyStructRef))]
         #[cfg_attr(
             feature = "serde",
@@ -35,15 +130,31 @@ macro_rules! vec3_glam_wrapper {
         )]
         #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
         #[repr(transparent)]
-        #[derive(Clone, Copy, Default, PartialEq)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
         pub struct $py_class_name($glam_class_name);
 
         impl $py_class_name {
             pub fn new(quat: $glam_class_name) -> Self {
                 Self(quat)
             }
+
+            /// Samples a rotation uniformly distributed over all rotations with Shoemake's method
+            pub fn sample_uniform<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                let u1 = rng.random::<$var_type>();
+                let (s2, c2) = (rng.random::<$var_type>() * std::f64::consts::TAU as $var_type).sin_cos();
+                let (s3, c3) = (rng.random::<$var_type>() * std::f64::consts::TAU as $var_type).sin_cos();
+                let (r1, r2) = ((1.0 - u1).sqrt(), u1.sqrt());
+                Self::new(<$glam_class_name>::from_xyzw(r1 * s2, r1 * c2, r2 * s3, r2 * c3))
+            }
         }
 
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
         #[cfg(feature = "pyo3")]
         #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
         #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
@@ -68,11 +179,24 @@ macro_rules! vec3_glam_wrapper {
                 $py_class_name(inner)
             }
 
+            /// The identity rotation
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn IDENTITY() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::IDENTITY))
+            }
+
+            /// Generates a random rotation, uniformly distributed over all rotations
+            #[staticmethod]
+            pub fn rand_uniform() -> Self {
+                random::with_default_rng(|rng| Self::sample_uniform(rng))
+            }
+
             /// Create a new quaternion from an axis and angle
             ///
             /// # Arguments
             ///
-            /// - `axis` (`vec3`) - Axis, should be normalized
+            /// - `axis` (`Vec3Like`) - Axis, should be normalized
             /// - `angle` (`float`) - Angle in radians
             ///
             /// # Returns
@@ -80,9 +204,48 @@ macro_rules! vec3_glam_wrapper {
             /// - `PyResult<Self>` - Quaternion describing this axis/angle rotation
             ///
             #[staticmethod]
-            pub fn from_axis_angle(axis: &$py_vec_class_name, angle: $var_type) -> Self {
-                let inner = <$glam_class_name>::from_axis_angle(axis.into(), angle);
-                $py_class_name(inner)
+            pub fn from_axis_angle(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] axis: Bound<'_, PyAny>,
+                angle: $var_type,
+            ) -> PyResult<Self> {
+                let axis = <$glam_vec_class_name>::from(axis.extract::<vec3::Vec3Like>()?);
+                let inner = <$glam_class_name>::from_axis_angle(axis, angle);
+                Ok($py_class_name(inner))
+            }
+
+            /// Create a quaternion from a rotation vector, rotating `v.length()` radians about `v.normalize()`
+            ///
+            /// A zero vector gives the identity rotation
+            ///
+            /// # Arguments
+            ///
+            /// - `v` (`Vec3Like`) - Rotation vector, also known as scaled axis
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Quaternion describing this rotation
+            ///
+            #[staticmethod]
+            pub fn from_scaled_axis(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] v: Bound<'_, PyAny>,
+            ) -> PyResult<Self> {
+                let v = <$glam_vec_class_name>::from(v.extract::<vec3::Vec3Like>()?);
+                Ok($py_class_name(<$glam_class_name>::from_scaled_axis(v)))
+            }
+            /// Create a quaternion rotating `angle` radians about the x axis
+            #[staticmethod]
+            pub fn from_rotation_x(angle: $var_type) -> Self {
+                $py_class_name(<$glam_class_name>::from_rotation_x(angle))
+            }
+            /// Create a quaternion rotating `angle` radians about the y axis
+            #[staticmethod]
+            pub fn from_rotation_y(angle: $var_type) -> Self {
+                $py_class_name(<$glam_class_name>::from_rotation_y(angle))
+            }
+            /// Create a quaternion rotating `angle` radians about the z axis
+            #[staticmethod]
+            pub fn from_rotation_z(angle: $var_type) -> Self {
+                $py_class_name(<$glam_class_name>::from_rotation_z(angle))
             }
 
             /// Gets the minimal rotation for transforming `from` to `to`.  The rotation is in the
@@ -95,18 +258,124 @@ macro_rules! vec3_glam_wrapper {
             ///
             /// # Arguments
             ///
-            /// - `from_` (`vec3`) - starting vector. Must be a unit vector
-            /// - `to` (`vec3`) - ending vector. Must be a unit vector
+            /// - `from_` (`Vec3Like`) - starting vector. Must be a unit vector
+            /// - `to` (`Vec3Like`) - ending vector. Must be a unit vector
             ///
             /// # Returns
             ///
             /// - `PyResult<Self>` - Returns a quaternion that would rotate vector from onto to
             ///
             #[staticmethod]
-            pub fn from_rotation_arc(from_: &$py_vec_class_name, to: &$py_vec_class_name) -> Self {
-                let inner = <$glam_class_name>::from_rotation_arc(from_.into(), to.into());
+            pub fn from_rotation_arc(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] from_: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] to: Bound<'_, PyAny>,
+            ) -> PyResult<Self> {
+                let from_ = <$glam_vec_class_name>::from(from_.extract::<vec3::Vec3Like>()?);
+                let to = <$glam_vec_class_name>::from(to.extract::<vec3::Vec3Like>()?);
+                let inner = <$glam_class_name>::from_rotation_arc(from_, to);
+                Ok($py_class_name(inner))
+            }
+
+            /// Create a quaternion from a 3x3 rotation matrix
+            ///
+            /// # Arguments
+            ///
+            /// - `mat` (`mat3`) - Rotation matrix, must not contain scale or shear
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Quaternion describing the same rotation as `mat`
+            ///
+            #[staticmethod]
+            pub fn from_mat3(mat: &$py_mat3_class_name) -> Self {
+                let inner = <$glam_class_name>::from_mat3(&mat.into());
                 $py_class_name(inner)
             }
+            /// Convert this quaternion into a 3x3 rotation matrix
+            #[allow(clippy::wrong_self_convention)] // pymethods can't take self by value
+            fn to_mat3(&self) -> $py_mat3_class_name {
+                <$py_mat3_class_name>::from_quat(QuatLike(DQuat::from(*self).0))
+            }
+            /// Decompose this quaternion into a rotation axis and angle
+            ///
+            /// # Returns
+            ///
+            /// - `(vec3, float)` - Normalized axis and angle in radians
+            ///
+            #[allow(clippy::wrong_self_convention)]
+            fn to_axis_angle(&self) -> ($py_vec_class_name, $var_type) {
+                let (axis, angle) = self.0.to_axis_angle();
+                (<$py_vec_class_name>::new(axis), angle)
+            }
+            /// Convert this quaternion into a rotation vector, the axis scaled by the angle in radians
+            #[allow(clippy::wrong_self_convention)]
+            fn to_scaled_axis(&self) -> $py_vec_class_name {
+                <$py_vec_class_name>::new(self.0.to_scaled_axis())
+            }
+
+            /// Create a quaternion from euler angles applied in the given order
+            ///
+            /// # Arguments
+            ///
+            /// - `order` (`EulerRot | str`) - Rotation sequence, e.g. `EulerRot.ZYX` or `"ZYX"` for yaw-pitch-roll
+            /// - `a` (`float`) - Angle about the first axis of `order`
+            /// - `b` (`float`) - Angle about the second axis of `order`
+            /// - `c` (`float`) - Angle about the third axis of `order`
+            /// - `degrees` (`bool`) - Angles are in degrees instead of radians
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Quaternion describing the combined rotation
+            ///
+            #[staticmethod]
+            #[pyo3(signature = (order, a, b, c, degrees=false))]
+            pub fn from_euler(
+                #[gen_stub(override_type(type_repr = "typing.Union[EulerRot, builtins.str]", imports = ("typing", "builtins")))] order: Bound<'_, PyAny>,
+                a: $var_type,
+                b: $var_type,
+                c: $var_type,
+                degrees: bool,
+            ) -> PyResult<Self> {
+                let order = glam::EulerRot::from(order.extract::<EulerRotLike>()?);
+                let inner = if degrees {
+                    <$glam_class_name>::from_euler(order, a.to_radians(), b.to_radians(), c.to_radians())
+                } else {
+                    <$glam_class_name>::from_euler(order, a, b, c)
+                };
+                Ok($py_class_name(inner))
+            }
+
+            /// Decompose this quaternion into euler angles in the given order
+            ///
+            /// Near gimbal lock the decomposition isn't unique. This happens when the middle angle
+            /// is ±90 degrees for three-axis orders, or 0 or 180 degrees for two-axis orders such as `ZYZ`.
+            /// One of the outer angles is then set to 0 and the other carries the whole rotation about
+            /// the aligned axes, so `from_euler(order, *to_euler(order))` still gives back the same rotation
+            ///
+            /// # Arguments
+            ///
+            /// - `order` (`EulerRot | str`) - Rotation sequence, e.g. `EulerRot.ZYX` or `"ZYX"` for yaw-pitch-roll
+            /// - `degrees` (`bool`) - Return the angles in degrees instead of radians
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<(float, float, float)>` - Angles about the first, second and third axis of `order`
+            ///
+            #[pyo3(signature = (order, degrees=false))]
+            #[allow(clippy::wrong_self_convention)] // pymethods can't take self by value
+            fn to_euler(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[EulerRot, builtins.str]", imports = ("typing", "builtins")))] order: Bound<'_, PyAny>,
+                degrees: bool,
+            ) -> PyResult<($var_type, $var_type, $var_type)> {
+                let order = glam::EulerRot::from(order.extract::<EulerRotLike>()?);
+                let (a, b, c) = self.0.to_euler(order);
+                if degrees {
+                    Ok((a.to_degrees(), b.to_degrees(), c.to_degrees()))
+                } else {
+                    Ok((a, b, c))
+                }
+            }
 
             /// Convert this quat to a 4 component tuple
             ///
@@ -117,137 +386,571 @@ macro_rules! vec3_glam_wrapper {
             fn to_tuple(&self) -> ($var_type, $var_type, $var_type, $var_type) {
                 (self.x, self.y, self.z, self.w)
             }
+            /// Convert this quat to f64 precision
+            ///
+            /// # Returns
+            ///
+            /// - `DQuat` - Copy with every component widened to f64
+            ///
+            fn as_dquat(&self) -> DQuat {
+                DQuat::from(*self)
+            }
+            /// Convert this quat to f32 precision
+            ///
+            /// # Returns
+            ///
+            /// - `Quat` - Copy with every component rounded to the nearest f32
+            ///
+            #[cfg(feature = "f32")]
+            fn as_quat(&self) -> Quat {
+                Quat::from(*self)
+            }
+
+            /// Create a quaternion from a numpy array of 4 xyzw components
+            ///
+            /// # Arguments
+            ///
+            /// - `array` (`numpy.typing.ArrayLike`) - 1-D array of length 4, other dtypes are cast to this type's precision
+            ///
+            /// # Returns
+            ///
+            /// - `quat` - New quaternion holding a copy of the components
+            ///
+            #[cfg(feature = "numpy")]
+            #[staticmethod]
+            fn from_numpy(
+                #[gen_stub(override_type(type_repr = "numpy.typing.ArrayLike", imports = ("numpy.typing")))] array: PyArrayLike1<'_, $var_type, AllowTypeChange>,
+            ) -> PyResult<Self> {
+                let components = array.as_array();
+                if components.len() != 4 {
+                    return Err(PyValueError::new_err(format!(
+                        "expected an array of 4 components, got {}",
+                        components.len()
+                    )));
+                }
+                Ok($py_class_name(<$glam_class_name>::from_xyzw(components[0], components[1], components[2], components[3])))
+            }
+            /// View this quaternion as a numpy array, see `numpy.asarray`
+            ///
+            /// Without a dtype conversion or `copy=True` the array is a read-only view of this quaternion
+            ///
+            /// # Arguments
+            ///
+            /// - `dtype` (`numpy.dtype | None`) - Requested dtype, defaults to this type's precision
+            /// - `copy` (`bool | None`) - True to always copy, False to raise ValueError instead of copying
+            ///
+            /// # Returns
+            ///
+            /// - `numpy.ndarray` - 1-D array of the xyzw components
+            ///
+            #[cfg(feature = "numpy")]
+            #[pyo3(signature = (dtype=None, copy=None))]
+            fn __array__<'py>(
+                slf: Bound<'py, Self>,
+                dtype: Option<Bound<'py, PyAny>>,
+                copy: Option<bool>,
+            ) -> PyResult<Bound<'py, PyUntypedArray>> {
+                buffer::to_numpy(slf.as_any(), dtype, copy)
+            }
+            /// Exposes the xyzw components as a read-only, contiguous float buffer
+            #[cfg(feature = "numpy")]
+            #[gen_stub(skip)]
+            unsafe fn __getbuffer__(
+                slf: Bound<'_, Self>,
+                view: *mut ffi::Py_buffer,
+                flags: c_int,
+            ) -> PyResult<()> {
+                let data = &slf.try_borrow()?.0 as *const $glam_class_name as *const $var_type;
+                unsafe { buffer::fill_view(view, flags, data, 4, slf.into_any()) }
+            }
+            #[cfg(feature = "numpy")]
+            #[gen_stub(skip)]
+            unsafe fn __releasebuffer__(&self, view: *mut ffi::Py_buffer) {
+                unsafe { buffer::release_view(view) }
+            }
 
-            /// Multiply this quaternion with either another quaternion or a vector
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}({}, {}, {}, {})",
+                    stringify!($py_class_name),
+                    py_value::float_repr(self.0.x),
+                    py_value::float_repr(self.0.y),
+                    py_value::float_repr(self.0.z),
+                    py_value::float_repr(self.0.w),
+                )
+            }
+            fn __str__(&self) -> String {
+                format!("{}", self.0)
+            }
+            /// Component-wise value equality with any quat, regardless of precision
+            ///
+            /// `q` and `-q` describe the same rotation but are not equal.
+            /// Follows float semantics, so a quat containing NaN is never equal to anything.
+            /// Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+            fn __eq__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] other: QuatQuatOpsEnum,
+            ) -> PyResult<bool> {
+                let this = glam::DQuat::from_xyzw(self.0.x as f64, self.0.y as f64, self.0.z as f64, self.0.w as f64);
+                match other {
+                    QuatQuatOpsEnum::DQuat(dquat) => {
+                        return Ok(this == *dquat);
+                    }
+                    #[cfg(feature = "f32")]
+                    QuatQuatOpsEnum::Quat(quat) => {
+                        return Ok(this == quat.0.as_dquat());
+                    }
+                    #[cfg(feature = "py-ref")]
+                    QuatQuatOpsEnum::DQuatRef(quat_ref) => {
+                        let dquat = quat_ref.get_inner_ref()?;
+                        return Ok(this == *dquat);
+                    }
+                }
+            }
+            /// Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+            fn __hash__(&self) -> u64 {
+                py_value::hash_floats(&[self.0.x as f64, self.0.y as f64, self.0.z as f64, self.0.w as f64])
+            }
+            /// Determine whether this quat describes a rotation close to `other`, following `math.isclose`
+            ///
+            /// `q` and `-q` describe the same rotation, so `other` is also compared with its sign flipped
+            ///
+            /// # Arguments
+            ///
+            /// - `other` (`QuatLike`) - Quaternion to compare against, any quat or xyzw sequence
+            /// - `rel_tol` (`float`) - Maximum difference relative to the larger absolute component
+            /// - `abs_tol` (`float`) - Minimum absolute tolerance, useful for comparisons near zero
+            ///
+            /// # Returns
+            ///
+            /// - `bool` - True if every component pair of `other` or `-other` is close
+            ///
+            #[pyo3(signature = (other, rel_tol=1e-9, abs_tol=0.0))]
+            fn is_close(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] other: Bound<'_, PyAny>,
+                rel_tol: f64,
+                abs_tol: f64,
+            ) -> PyResult<bool> {
+                py_value::check_tolerances(rel_tol, abs_tol)?;
+                let other: glam::DQuat = other.extract::<QuatLike>()?.into();
+                let this = [self.0.x as f64, self.0.y as f64, self.0.z as f64, self.0.w as f64];
+                let all_close = |other: glam::DQuat| {
+                    this.iter()
+                        .zip(other.to_array())
+                        .all(|(a, b)| py_value::is_close(*a, b, rel_tol, abs_tol))
+                };
+                Ok(all_close(other) || all_close(-other))
+            }
+
+            fn __len__(&self) -> usize {
+                4
+            }
+            /// Get a component by index, or a tuple of components by slice
+            fn __getitem__<'py>(
+                &self,
+                index: Bound<'py, PyAny>,
+            ) -> PyResult<Either<$var_type, Bound<'py, PyTuple>>> {
+                py_value::get_item(&[self.0.x, self.0.y, self.0.z, self.0.w], &index)
+            }
+            /// Set a component by index, or a slice of components from a sequence of the same length
+            fn __setitem__(&mut self, index: Bound<'_, PyAny>, value: Bound<'_, PyAny>) -> PyResult<()> {
+                let mut components = self.0.to_array();
+                py_value::set_item(&mut components, &index, &value)?;
+                self.0 = <$glam_class_name>::from_array(components);
+                Ok(())
+            }
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                PyTuple::new(py, [self.0.x, self.0.y, self.0.z, self.0.w])?.try_iter()
+            }
+            /// Whether any component equals `value`, False for values that aren't numbers
+            fn __contains__(&self, value: Bound<'_, PyAny>) -> bool {
+                value
+                    .extract::<$var_type>()
+                    .is_ok_and(|value| [self.0.x, self.0.y, self.0.z, self.0.w].contains(&value))
+            }
+
+            /// Multiply this quaternion with another quaternion, a vector or a quaternion array
             ///
             /// A quaternion multiplication means combining two rotations into a single rotation
             /// A vector multiplication yields a rotated vec3
+            /// A quaternion array multiplication combines this rotation with every element
             ///
             /// # Arguments
             ///
-            /// - `rhs` (`Bound<'_, PyAny>`) - Quat or Vec to multiply
+            /// - `rhs` (`typing.Union[QuatLike, Vec3Like, quat array]`) - Quat, Vec or quat array to multiply, 4 component sequences are treated as xyzw quats
             ///
             /// # Returns
             ///
-            /// - `PyResult<Either<, >>` - Either a new rotation or a rotated vector
+            /// - `PyResult<Either<Either<, >, >>` - Either a new rotation, a rotated vector or an array of combined rotations
             ///
             fn __mul__(
-                &mut self,
-                rhs: Bound<'_, PyAny>,
-            ) -> PyResult<Either<$py_class_name, $py_vec_class_name>> {
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[QuatLike, Vec3Like]", imports = ("typing")))] rhs: QuatOpsEnum<'_, $py_quat_array_class_name, $quat_operand>,
+            ) -> PyResult<Either<Either<$py_class_name, $py_vec_class_name>, Either<$py_quat_array_class_name, vec3::DVec3>>> {
                 // this * rhs
                 let this = self.0;
-                match rhs.extract::<QuatOpsEnum>() {
-                    Ok(QuatOpsEnum::DQuat(dquat)) => {
-                        return Ok(Either::Left($py_class_name::new(
-                            this * <$glam_class_name>::from_xyzw(
-                                dquat.x as $var_type,
-                                dquat.y as $var_type,
-                                dquat.z as $var_type,
-                                dquat.w as $var_type,
-                            ),
-                        )));
+                match rhs {
+                    QuatOpsEnum::QuatArray(array) => {
+                        return Ok(Either::Right(Either::Left(array.iter().map(|quat| this * *quat).collect())));
                     }
-                    #[cfg(feature = "f32")]
-                    Ok(QuatOpsEnum::Quat(quat)) => {
-                        return Ok(Either::Left($py_class_name::new(
-                            this * <$glam_class_name>::from_xyzw(
-                                quat.x as $var_type,
-                                quat.y as $var_type,
-                                quat.z as $var_type,
-                                quat.w as $var_type,
-                            ),
-                        )));
+                    QuatOpsEnum::DVec3(vec) => {
+                        let this = glam::DQuat::from_array(this.to_array().map(|c| c as f64));
+                        return Ok(Either::Right(Either::Right(vec3::DVec3::new(this * *vec))));
                     }
-                    Ok(QuatOpsEnum::DVec3(vec)) => {
-                        return Ok(Either::Right(<$py_vec_class_name>::new(
-                            this * <$glam_vec_class_name>::new(
-                                vec.x as $var_type,
-                                vec.y as $var_type,
-                                vec.z as $var_type,
-                            ),
-                        )));
+                    QuatOpsEnum::Quat(quat) => {
+                        return Ok(Either::Left(Either::Left($py_class_name::new(
+                            this * <$glam_class_name>::from(quat),
+                        ))));
                     }
-                    #[cfg(feature = "f32")]
-                    Ok(QuatOpsEnum::Vec3(vec)) => {
-                        return Ok(Either::Right(<$py_vec_class_name>::new(
-                            this * <$glam_vec_class_name>::new(
-                                vec.x as $var_type,
-                                vec.y as $var_type,
-                                vec.z as $var_type,
-                            ),
-                        )));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(QuatOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok(Either::Right(<$py_vec_class_name>::new(
-                            this * <$glam_vec_class_name>::new(
-                                vec.x as $var_type,
-                                vec.y as $var_type,
-                                vec.z as $var_type,
-                            ),
-                        )));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(QuatOpsEnum::DQuatRef(quat_ref)) => {
-                        let dquat = quat_ref.get_inner_ref()?;
-                        return Ok(Either::Left($py_class_name::new(
-                            this * <$glam_class_name>::from_xyzw(
-                                dquat.x as $var_type,
-                                dquat.y as $var_type,
-                                dquat.z as $var_type,
-                                dquat.w as $var_type,
-                            ),
-                        )));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    QuatOpsEnum::Vec3(vec) => {
+                        return Ok(Either::Left(Either::Right(<$py_vec_class_name>::new(
+                            this * <$glam_vec_class_name>::from(vec),
+                        ))));
                     }
                 }
             }
-            /// Multiply this quaternion with either another quaternion or a vec3
+            /// Multiply a quaternion on the left with this quaternion, `lhs * self`
             ///
-            /// Order doesn't matter for multiplying a vector and a quat, but order matters for multiplying two quats
+            /// Combines the rotations, so the result rotates by this quaternion first and then by `lhs`.
+            /// A vector on the left, `v * q`, raises TypeError since it's ambiguous whether it should
+            /// rotate or inverse rotate, use `q * v`, `q.rotate(v)` or `q.inverse_rotate(v)` instead
             ///
             /// # Arguments
             ///
-            /// - `lhs` (`Bound<'_, PyAny>`) - left hand side multiplicand
+            /// - `lhs` (`QuatLike`) - Left hand side rotation, 4 component sequences are treated as xyzw quats
             ///
             /// # Returns
             ///
-            /// - `PyResult<Either<, >>` - Either a quat equivalent to the combined rotation or a rotated vector
+            /// - `PyResult<Self>` - Combined rotation
             ///
             fn __rmul__(
-                &mut self,
-                lhs: Bound<'_, PyAny>,
-            ) -> PyResult<Either<$py_class_name, $py_vec_class_name>> {
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] lhs: QuatRmulOpsEnum,
+            ) -> PyResult<$py_class_name> {
                 // lhs * this
-                return self.__mul__(lhs);
+                match lhs {
+                    QuatRmulOpsEnum::Quat(quat) => {
+                        return Ok($py_class_name::new(<$glam_class_name>::from(quat) * self.0));
+                    }
+                    QuatRmulOpsEnum::Vec3(_) => {
+                        return Err(PyTypeError::new_err(
+                            "A vector can't be multiplied by a quaternion on the right, use `q * v`, `q.rotate(v)` or `q.inverse_rotate(v)`",
+                        ));
+                    }
+                }
+            }
+            /// Rotate a vector by this quaternion, same as `self * v`
+            ///
+            /// # Arguments
+            ///
+            /// - `v` (`Vec3Like`) - Vector to rotate
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<vec3>` - Rotated vector
+            ///
+            fn rotate(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] v: Bound<'_, PyAny>,
+            ) -> PyResult<$py_vec_class_name> {
+                let v = <$glam_vec_class_name>::from(v.extract::<vec3::Vec3Like>()?);
+                Ok(<$py_vec_class_name>::new(self.0 * v))
+            }
+            /// Rotate a vector by the inverse of this quaternion, undoing `rotate`
+            ///
+            /// This quaternion must be normalized
+            ///
+            /// # Arguments
+            ///
+            /// - `v` (`Vec3Like`) - Vector to rotate
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<vec3>` - Inverse rotated vector
+            ///
+            fn inverse_rotate(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] v: Bound<'_, PyAny>,
+            ) -> PyResult<$py_vec_class_name> {
+                let v = <$glam_vec_class_name>::from(v.extract::<vec3::Vec3Like>()?);
+                Ok(<$py_vec_class_name>::new(self.0.inverse() * v))
+            }
+            /// Combine this rotation with a rotation applied after it, same as `other * self`
+            ///
+            /// `a.then(b).then(c)` reads in the order the rotations are applied
+            ///
+            /// # Arguments
+            ///
+            /// - `other` (`QuatLike`) - Rotation applied after this one
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Combined rotation
+            ///
+            fn then(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] other: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let other = <$glam_class_name>::from(other.extract::<QuatLike>()?);
+                Ok($py_class_name::new(other * self.0))
             }
 
             /// Normalize this quaternion into a unit quat
-            fn normalize(&self) -> $py_class_name {
-                $py_class_name::new(self.0.normalize())
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Unit quat, raises NormalizationError if the length is zero, infinite or NaN
+            ///
+            fn normalize(&self) -> PyResult<$py_class_name> {
+                let rcp = self.0.length_recip();
+                if !(rcp.is_finite() && rcp > 0.0) {
+                    return Err(NormalizationError::new_err(
+                        "Can not normalize a quaternion with zero, infinite or NaN length",
+                    ));
+                }
+                Ok($py_class_name::new(self.0 * rcp))
             }
             /// Compute the conjugate of this quat.
             /// If this is a unit quat, the conjugate is equal to the inverse of the rotation
             fn conjugate(&self) -> $py_class_name {
                 $py_class_name::new(self.0.conjugate())
             }
+            /// Compute the inverse rotation, this quat must be normalized
+            fn inverse(&self) -> $py_class_name {
+                $py_class_name::new(self.0.inverse())
+            }
+            /// Dot product of this quat and `rhs`, the cosine of half the angle between unit rotations
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`QuatLike`) - Other quaternion, any quat or xyzw sequence
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Dot product of the xyzw components
+            ///
+            fn dot(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<QuatLike>()?);
+                Ok(self.0.dot(rhs))
+            }
+            /// Length of this quaternion, 1 for a unit quat
+            fn length(&self) -> $var_type {
+                self.0.length()
+            }
+            /// Whether this quaternion has a length of 1, i.e. its squared length is within `2e-4` of 1
+            fn is_normalized(&self) -> bool {
+                self.0.is_normalized()
+            }
+            /// Whether this quaternion rotates by less than roughly 0.0028 radians, treating `q` and `-q` alike
+            fn is_near_identity(&self) -> bool {
+                self.0.is_near_identity()
+            }
+            /// Angle in radians of the shortest rotation from this quat to `rhs`, both must be normalized
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`QuatLike`) - Other rotation
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Angle between the two rotations in `[0, pi]`
+            ///
+            fn angle_between(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<QuatLike>()?);
+                Ok(self.0.angle_between(rhs))
+            }
+
+            /// Spherical linear interpolation between this quat and `end`
+            ///
+            /// Follows the shortest path, `end` is negated when the quats are more than 180 degrees apart
+            ///
+            /// # Arguments
+            ///
+            /// - `end` (`QuatLike`) - Rotation at `t = 1`, both quats should be normalized
+            /// - `t` (`float`) - Interpolation factor, 0 returns this quat and 1 returns `end`
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Interpolated rotation
+            ///
+            fn slerp(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] end: Bound<'_, PyAny>,
+                t: $var_type,
+            ) -> PyResult<$py_class_name> {
+                let end = <$glam_class_name>::from(end.extract::<QuatLike>()?);
+                Ok($py_class_name::new(self.0.slerp(end, t)))
+            }
+            /// Normalized linear interpolation between this quat and `end`
+            ///
+            /// Cheaper than `slerp` but the angular velocity isn't constant.
+            /// Follows the shortest path, `end` is negated when the quats are more than 180 degrees apart
+            ///
+            /// # Arguments
+            ///
+            /// - `end` (`QuatLike`) - Rotation at `t = 1`, both quats should be normalized
+            /// - `t` (`float`) - Interpolation factor, 0 returns this quat and 1 returns `end`
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Interpolated unit rotation
+            ///
+            fn lerp(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] end: Bound<'_, PyAny>,
+                t: $var_type,
+            ) -> PyResult<$py_class_name> {
+                let end = <$glam_class_name>::from(end.extract::<QuatLike>()?);
+                Ok($py_class_name::new(self.0.lerp(end, t)))
+            }
+            /// Alias of `lerp`, the result is always normalized
+            fn nlerp(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] end: Bound<'_, PyAny>,
+                t: $var_type,
+            ) -> PyResult<$py_class_name> {
+                self.lerp(end, t)
+            }
+            /// Spherical cubic interpolation between `q0` and `q1` through the control quats `a` and `b`
+            ///
+            /// Computed as `slerp(slerp(q0, q1, t), slerp(a, b, t), 2t(1 - t))`, so every slerp follows the shortest path
+            ///
+            /// # Arguments
+            ///
+            /// - `q0` (`QuatLike`) - Rotation at `t = 0`
+            /// - `a` (`QuatLike`) - Control rotation for `q0`
+            /// - `b` (`QuatLike`) - Control rotation for `q1`
+            /// - `q1` (`QuatLike`) - Rotation at `t = 1`
+            /// - `t` (`float`) - Interpolation factor
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Interpolated rotation
+            ///
+            #[staticmethod]
+            fn squad(
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] q0: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] a: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] b: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] q1: Bound<'_, PyAny>,
+                t: $var_type,
+            ) -> PyResult<$py_class_name> {
+                let [q0, a, b, q1] = Self::extract_squad_quats([q0, a, b, q1])?;
+                Ok($py_class_name::new(Self::squad_impl(q0, a, b, q1, t)))
+            }
+
+            /// Spherical linear interpolation at every value of `ts`, see `slerp`
+            ///
+            /// # Arguments
+            ///
+            /// - `end` (`QuatLike`) - Rotation at `t = 1`
+            /// - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<quat array>` - One interpolated rotation per factor
+            ///
+            fn slerp_many(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] end: Bound<'_, PyAny>,
+                ts: Vec<$var_type>,
+            ) -> PyResult<$py_quat_array_class_name> {
+                let end = <$glam_class_name>::from(end.extract::<QuatLike>()?);
+                Ok(ts.into_iter().map(|t| self.0.slerp(end, t)).collect())
+            }
+            /// Normalized linear interpolation at every value of `ts`, see `lerp`
+            ///
+            /// # Arguments
+            ///
+            /// - `end` (`QuatLike`) - Rotation at `t = 1`
+            /// - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<quat array>` - One interpolated rotation per factor
+            ///
+            fn nlerp_many(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] end: Bound<'_, PyAny>,
+                ts: Vec<$var_type>,
+            ) -> PyResult<$py_quat_array_class_name> {
+                let end = <$glam_class_name>::from(end.extract::<QuatLike>()?);
+                Ok(ts.into_iter().map(|t| self.0.lerp(end, t)).collect())
+            }
+            /// Spherical cubic interpolation at every value of `ts`, see `squad`
+            ///
+            /// # Arguments
+            ///
+            /// - `q0` (`QuatLike`) - Rotation at `t = 0`
+            /// - `a` (`QuatLike`) - Control rotation for `q0`
+            /// - `b` (`QuatLike`) - Control rotation for `q1`
+            /// - `q1` (`QuatLike`) - Rotation at `t = 1`
+            /// - `ts` (`Sequence[float]`) - Interpolation factors, any float sequence or 1-D array
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<quat array>` - One interpolated rotation per factor
+            ///
+            #[staticmethod]
+            fn squad_many(
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] q0: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] a: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] b: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] q1: Bound<'_, PyAny>,
+                ts: Vec<$var_type>,
+            ) -> PyResult<$py_quat_array_class_name> {
+                let [q0, a, b, q1] = Self::extract_squad_quats([q0, a, b, q1])?;
+                Ok(ts.into_iter().map(|t| Self::squad_impl(q0, a, b, q1, t)).collect())
+            }
+
+            /// Pickle support, restored from a version tagged list of the xyzw components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_array(py_value::unpack_state(&state)?)))
+            }
+            /// Copy this quaternion
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
         }
 
-        macro_rules! into_glam {
-            ($a:ty, $b:ty) => {
-                impl Into<$a> for $b {
-                    fn into(self) -> $a {
-                        self.0
-                    }
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Extracts the four quats of a `squad` call, raising TypeError if any isn't quat-like
+            fn extract_squad_quats(quats: [Bound<'_, PyAny>; 4]) -> PyResult<[$glam_class_name; 4]> {
+                let mut out = [<$glam_class_name>::IDENTITY; 4];
+                for (out, quat) in out.iter_mut().zip(quats) {
+                    *out = <$glam_class_name>::from(quat.extract::<QuatLike>()?);
                 }
-            };
+                Ok(out)
+            }
+            /// Spherical cubic interpolation between `q0` and `q1` through the control quats `a` and `b`
+            fn squad_impl(
+                q0: $glam_class_name,
+                a: $glam_class_name,
+                b: $glam_class_name,
+                q1: $glam_class_name,
+                t: $var_type,
+            ) -> $glam_class_name {
+                q0.slerp(q1, t).slerp(a.slerp(b, t), 2.0 * t * (1.0 - t))
+            }
         }
-        into_glam!($glam_class_name, $py_class_name);
-        into_glam!($glam_class_name, &$py_class_name);
+
         macro_rules! from_glam {
             ($a:ty, $b:ty) => {
                 impl From<$a> for $b {
@@ -259,6 +962,16 @@ macro_rules! vec3_glam_wrapper {
         }
         from_glam!($glam_class_name, $py_class_name);
         from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
 
         impl Deref for $py_class_name {
             type Target = $glam_class_name;
@@ -344,9 +1057,24 @@ macro_rules! vec3_glam_wrapper {
         vec3_ops_with_glam!(&$glam_vec_class_name, &$py_class_name);
     };
 }
-vec3_glam_wrapper!(DQuat, vec3::DVec3, glam::DQuat, glam::DVec3, f64);
+vec3_glam_wrapper!(DQuat, vec3::DVec3, mat3::DMat3, quat_array::DQuatArray, glam::DQuat, glam::DVec3, f64, QuatLike);
 #[cfg(feature = "f32")]
-vec3_glam_wrapper!(Quat, vec3::Vec3, glam::Quat, glam::Vec3, f32);
+vec3_glam_wrapper!(Quat, vec3::Vec3, mat3::Mat3, quat_array::QuatArray, glam::Quat, glam::Vec3, f32, py_value::F32Operand<QuatLike, DQuat>);
+
+/// Widens every component to f64
+#[cfg(feature = "f32")]
+impl From<Quat> for DQuat {
+    fn from(value: Quat) -> Self {
+        Self(value.0.as_dquat())
+    }
+}
+/// Rounds every component to the nearest f32
+#[cfg(feature = "f32")]
+impl From<DQuat> for Quat {
+    fn from(value: DQuat) -> Self {
+        Self(value.0.as_quat())
+    }
+}
 
 /// Creates a 4-dimensional f64 quaternion
 #[inline(always)]
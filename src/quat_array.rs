@@ -0,0 +1,485 @@
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::quat;
+#[cfg(feature = "pyo3")]
+use crate::vec3;
+#[cfg(feature = "pyo3")]
+use crate::vec3_array;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "numpy")]
+use numpy::{AllowTypeChange, IntoPyArray, PyArrayLike2, PyArrayMethods, PyUntypedArray};
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBytes, PySlice, PyTuple},
+};
+use std::ops::{Deref, DerefMut};
+
+/// Supported operands for multiplying quaternion arrays
+/// array * Some
+///
+/// `A` and `V` are the quat and vec3 array classes of the same precision, arrays of the other precision
+/// don't extract. `Q` and `U` are the quat and vector operands, f32 arrays refuse DQuat and DVec3 operands
+/// instead of truncating them
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum QuatArrayOpsEnum<'py, A: pyo3::PyClass, V: pyo3::PyClass, Q, U> {
+    Array(PyRef<'py, A>),
+    Vec3Array(PyRef<'py, V>),
+    Quat(Q),
+    Vec3(U),
+}
+
+macro_rules! quat_array_wrapper {
+    ($py_class_name: ident, $py_quat_class_name: ty, $py_vec_array_class_name: ty, $glam_quat_class_name: ty, $glam_vec_class_name: ty, $var_type: ty, $quat_operand: ty, $vec_operand: ty) => {
+        /// Contiguous array of quaternions for rotating and composing many rotations at once
+        ///
+        /// Arithmetic doesn't mix precisions, f32 arrays refuse DQuat and DVec3 operands and arrays only
+        /// combine with arrays of the same precision. Convert with `quat_array(array, dtype=...)` first
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct $py_class_name(Vec<$glam_quat_class_name>);
+
+        impl $py_class_name {
+            pub fn new(quats: Vec<$glam_quat_class_name>) -> Self {
+                Self(quats)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Checks that `other` has one element per element of this array
+            fn check_len(&self, other: usize) -> PyResult<()> {
+                if other != self.0.len() {
+                    return Err(PyValueError::new_err(format!(
+                        "Array lengths don't match: {} and {}",
+                        self.0.len(),
+                        other
+                    )));
+                }
+                Ok(())
+            }
+
+            /// Copies the xyzw rows of an array with shape `(N, 4)`
+            #[cfg(feature = "numpy")]
+            fn from_array(array: PyArrayLike2<'_, $var_type, AllowTypeChange>) -> PyResult<Self> {
+                let rows = array.as_array();
+                if rows.ncols() != 4 {
+                    return Err(PyValueError::new_err(format!(
+                        "expected an array with shape (N, 4), got {:?}",
+                        rows.shape()
+                    )));
+                }
+                Ok(Self(
+                    rows.rows()
+                        .into_iter()
+                        .map(|row| <$glam_quat_class_name>::from_xyzw(row[0], row[1], row[2], row[3]))
+                        .collect(),
+                ))
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!($py_class_name, $var_type);
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            /// Create an array from an iterable of quaternions
+            ///
+            /// # Arguments
+            ///
+            /// - `quats` (`Iterable[QuatLike] | None`) - Quaternions or xyzw sequences, or an array with shape `(N, 4)`, empty if not given
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Array holding a copy of each quaternion
+            ///
+            #[new]
+            #[pyo3(signature = (quats=None))]
+            pub fn py_new(quats: Option<Bound<'_, PyAny>>) -> PyResult<Self> {
+                let Some(quats) = quats else {
+                    return Ok(Self::default());
+                };
+                #[cfg(feature = "numpy")]
+                if let Some(array) = py_value::as_ndarray(&quats) {
+                    return Self::from_array(array.extract()?);
+                }
+                let mut inner = Vec::with_capacity(quats.len().unwrap_or(0));
+                for quat in quats.try_iter()? {
+                    inner.push(<$glam_quat_class_name>::from(quat?.extract::<quat::QuatLike>()?));
+                }
+                Ok(Self(inner))
+            }
+            /// Create an array of `len` identity rotations
+            #[staticmethod]
+            pub fn identity(len: usize) -> Self {
+                Self(vec![<$glam_quat_class_name>::IDENTITY; len])
+            }
+
+            /// Create an array from a numpy array with shape `(N, 4)` in xyzw order
+            ///
+            /// # Arguments
+            ///
+            /// - `array` (`numpy.typing.ArrayLike`) - 2-D array of any float or integer dtype
+            /// - `dtype` (`str | numpy.dtype | None`) - Precision of the new array, `'float32'` or `'float64'`, defaults to this type's precision
+            ///
+            /// # Returns
+            ///
+            /// - `DQuatArray | QuatArray` - Array holding a copy of each row
+            ///
+            #[cfg(feature = "numpy")]
+            #[staticmethod]
+            #[pyo3(signature = (array, dtype=None))]
+            #[gen_stub(override_return_type(type_repr = "typing.Union[DQuatArray, QuatArray]", imports = ("typing")))]
+            fn from_numpy<'py>(
+                #[gen_stub(override_type(type_repr = "numpy.typing.ArrayLike", imports = ("numpy.typing")))] array: &Bound<'py, PyAny>,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                from_numpy_as(array, dtype.unwrap_or(<$var_type as py_value::Component>::DTYPE))
+            }
+            /// Copy this array into a numpy array with shape `(N, 4)` in xyzw order
+            ///
+            /// # Arguments
+            ///
+            /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to this type's precision
+            ///
+            /// # Returns
+            ///
+            /// - `numpy.ndarray` - New array holding the components of every quaternion
+            ///
+            #[cfg(feature = "numpy")]
+            #[pyo3(signature = (dtype=None))]
+            fn to_numpy<'py>(
+                &self,
+                py: Python<'py>,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+            ) -> PyResult<Bound<'py, PyUntypedArray>> {
+                let components = self.0.iter().flat_map(|quat| quat.to_array());
+                let shape = [self.0.len(), 4];
+                Ok(match dtype.unwrap_or(<$var_type as py_value::Component>::DTYPE) {
+                    py_value::Dtype::Float64 => {
+                        let flat: Vec<f64> = components.map(|c| c as f64).collect();
+                        flat.into_pyarray(py).reshape(shape)?.as_untyped().clone()
+                    }
+                    py_value::Dtype::Float32 => {
+                        let flat: Vec<f32> = components.map(|c| c as f32).collect();
+                        flat.into_pyarray(py).reshape(shape)?.as_untyped().clone()
+                    }
+                })
+            }
+            /// Copy this array into a numpy array, numpy's `__array__` protocol
+            ///
+            /// The storage can't be shared, so `copy=False` raises ValueError
+            #[cfg(feature = "numpy")]
+            #[pyo3(signature = (dtype=None, copy=None))]
+            fn __array__<'py>(
+                &self,
+                py: Python<'py>,
+                dtype: Option<Bound<'py, PyAny>>,
+                copy: Option<bool>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                if copy == Some(false) {
+                    return Err(PyValueError::new_err(
+                        "Unable to avoid a copy while creating an array from a quat array",
+                    ));
+                }
+                let array = self.to_numpy(py, None)?.into_any();
+                match dtype {
+                    Some(dtype) => array.call_method1("astype", (dtype,)),
+                    None => Ok(array),
+                }
+            }
+            /// Copy this array into a list of quaternions
+            fn to_list(&self) -> Vec<$py_quat_class_name> {
+                self.0.iter().map(|quat| <$py_quat_class_name>::new(*quat)).collect()
+            }
+
+            fn __repr__(&self) -> String {
+                let quats: Vec<String> = self
+                    .0
+                    .iter()
+                    .map(|quat| {
+                        format!(
+                            "({}, {}, {}, {})",
+                            py_value::float_repr(quat.x),
+                            py_value::float_repr(quat.y),
+                            py_value::float_repr(quat.z),
+                            py_value::float_repr(quat.w)
+                        )
+                    })
+                    .collect();
+                format!("{}([{}])", stringify!($py_class_name), quats.join(", "))
+            }
+            fn __len__(&self) -> usize {
+                self.0.len()
+            }
+            /// Get a quaternion by index, or a new array by slice
+            fn __getitem__(&self, index: Bound<'_, PyAny>) -> PyResult<Either<$py_quat_class_name, $py_class_name>> {
+                if let Ok(slice) = index.cast::<PySlice>() {
+                    let indices = slice.indices(self.0.len() as isize)?;
+                    let mut selected = Vec::with_capacity(indices.slicelength);
+                    let mut i = indices.start;
+                    for _ in 0..indices.slicelength {
+                        selected.push(self.0[i as usize]);
+                        i += indices.step;
+                    }
+                    return Ok(Either::Right(Self(selected)));
+                }
+                let i = py_value::normalize_index(index.extract::<isize>()?, self.0.len())?;
+                Ok(Either::Left(<$py_quat_class_name>::new(self.0[i])))
+            }
+            /// Set a quaternion by index, raises IndexError if out of range
+            fn __setitem__(
+                &mut self,
+                index: isize,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] value: Bound<'_, PyAny>,
+            ) -> PyResult<()> {
+                let i = py_value::normalize_index(index, self.0.len())?;
+                self.0[i] = <$glam_quat_class_name>::from(value.extract::<quat::QuatLike>()?);
+                Ok(())
+            }
+
+            /// Elementwise multiplication with quaternions or vectors
+            ///
+            /// Quaternion operands compose rotations, `self[i] * rhs` or `self[i] * rhs[i]` for an array.
+            /// Vector operands are rotated, a single vector by every rotation or a vec3 array elementwise
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`QuatLike | Vec3Like | quat array | vec3 array`) - Right hand side multiplicand
+            ///
+            /// # Returns
+            ///
+            /// - `quat array | vec3 array` - Composed rotations or rotated vectors
+            ///
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: QuatArrayOpsEnum<'_, $py_class_name, $py_vec_array_class_name, $quat_operand, $vec_operand>,
+            ) -> PyResult<Either<$py_class_name, $py_vec_array_class_name>> {
+                // this * rhs
+                match rhs {
+                    QuatArrayOpsEnum::Array(other) => {
+                        self.check_len(other.0.len())?;
+                        return Ok(Either::Left(Self(
+                            self.0.iter().zip(other.0.iter()).map(|(a, b)| *a * *b).collect(),
+                        )));
+                    }
+                    QuatArrayOpsEnum::Vec3Array(vecs) => {
+                        self.check_len(vecs.len())?;
+                        return Ok(Either::Right(
+                            self.0.iter().zip(vecs.iter()).map(|(quat, vec)| *quat * *vec).collect(),
+                        ));
+                    }
+                    QuatArrayOpsEnum::Quat(quat) => {
+                        let quat = <$glam_quat_class_name>::from(quat);
+                        return Ok(Either::Left(Self(self.0.iter().map(|a| *a * quat).collect())));
+                    }
+                    QuatArrayOpsEnum::Vec3(vec) => {
+                        let vec = <$glam_vec_class_name>::from(vec);
+                        return Ok(Either::Right(self.0.iter().map(|quat| *quat * vec).collect()));
+                    }
+                }
+            }
+            /// Compose a quaternion with every rotation in this array, `lhs * self[i]`
+            ///
+            /// # Arguments
+            ///
+            /// - `lhs` (`QuatLike`) - Left hand side rotation
+            ///
+            /// # Returns
+            ///
+            /// - `quat array` - Composed rotations
+            ///
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "QuatLike", imports = ()))] lhs: $quat_operand,
+            ) -> $py_class_name {
+                // lhs * this
+                let quat = <$glam_quat_class_name>::from(lhs);
+                Self(self.0.iter().map(|a| quat * *a).collect())
+            }
+
+            /// Normalize every quaternion into a unit quat
+            fn normalize(&self) -> $py_class_name {
+                Self(self.0.iter().map(|quat| quat.normalize()).collect())
+            }
+            /// Conjugate of every quaternion, equal to the inverse for unit quats
+            fn conjugate(&self) -> $py_class_name {
+                Self(self.0.iter().map(|quat| quat.conjugate()).collect())
+            }
+            /// Inverse of every quaternion, the quaternions must be normalized
+            fn inverse(&self) -> $py_class_name {
+                Self(self.0.iter().map(|quat| quat.inverse()).collect())
+            }
+
+            /// Pickle support, restored from a version tagged buffer of little endian xyzw components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                let data: Vec<u8> = self
+                    .0
+                    .iter()
+                    .flat_map(|quat| quat.to_array())
+                    .flat_map(<$var_type>::to_le_bytes)
+                    .collect();
+                py_value::reduce::<$py_class_name>(py, PyBytes::new(py, &data))
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                let data: Vec<u8> = py_value::unpack_state(&state)?;
+                let size = std::mem::size_of::<$var_type>();
+                if data.len() % (4 * size) != 0 {
+                    return Err(PyValueError::new_err(format!(
+                        "pickled quat array data must be a multiple of {} bytes, got {}",
+                        4 * size,
+                        data.len()
+                    )));
+                }
+                let values: Vec<$var_type> = data
+                    .chunks_exact(size)
+                    .map(|bytes| <$var_type>::from_le_bytes(bytes.try_into().unwrap()))
+                    .collect();
+                Ok(values
+                    .chunks_exact(4)
+                    .map(|c| <$glam_quat_class_name>::from_slice(c))
+                    .collect())
+            }
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                self.clone()
+            }
+        }
+
+        impl From<Vec<$glam_quat_class_name>> for $py_class_name {
+            fn from(value: Vec<$glam_quat_class_name>) -> Self {
+                Self(value)
+            }
+        }
+        impl From<$py_class_name> for Vec<$glam_quat_class_name> {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl FromIterator<$glam_quat_class_name> for $py_class_name {
+            fn from_iter<I: IntoIterator<Item = $glam_quat_class_name>>(iter: I) -> Self {
+                Self(iter.into_iter().collect())
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = Vec<$glam_quat_class_name>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+    };
+}
+quat_array_wrapper!(
+    DQuatArray,
+    quat::DQuat,
+    vec3_array::DVec3Array,
+    glam::DQuat,
+    glam::DVec3,
+    f64,
+    quat::QuatLike,
+    vec3::Vec3Like
+);
+#[cfg(feature = "f32")]
+quat_array_wrapper!(
+    QuatArray,
+    quat::Quat,
+    vec3_array::Vec3Array,
+    glam::Quat,
+    glam::Vec3,
+    f32,
+    py_value::F32Operand<quat::QuatLike, quat::DQuat>,
+    py_value::F32Operand<vec3::Vec3Like, vec3::DVec3>
+);
+
+/// Creates a quat array of the requested precision
+///
+/// # Arguments
+///
+/// - `quats` (`Iterable[QuatLike] | None`) - Quaternions or xyzw sequences, or an array with shape `(N, 4)`, empty if not given
+/// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to the dtype of `quats` if it has one, e.g. a numpy array, and f64 otherwise
+///
+/// # Returns
+///
+/// - `DQuatArray | QuatArray` - Array holding a copy of each quaternion
+///
+#[cfg(feature = "pyo3")]
+#[pyo3_stub_gen::derive::gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(signature = (quats=None, dtype=None))]
+#[gen_stub(override_return_type(type_repr = "typing.Union[DQuatArray, QuatArray]", imports = ("typing")))]
+pub(crate) fn quat_array<'py>(
+    py: Python<'py>,
+    quats: Option<Bound<'py, PyAny>>,
+    #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let dtype = dtype
+        .or_else(|| quats.as_ref().and_then(py_value::Dtype::of))
+        .unwrap_or(py_value::Dtype::Float64);
+    match dtype {
+        py_value::Dtype::Float64 => Ok(DQuatArray::py_new(quats)?.into_pyobject(py)?.into_any()),
+        #[cfg(feature = "f32")]
+        py_value::Dtype::Float32 => Ok(QuatArray::py_new(quats)?.into_pyobject(py)?.into_any()),
+        #[cfg(not(feature = "f32"))]
+        py_value::Dtype::Float32 => Err(PyValueError::new_err("pyglam was built without the f32 feature")),
+    }
+}
+
+/// Reads an array with shape `(N, 4)` into a quat array of precision `dtype`
+#[cfg(feature = "numpy")]
+fn from_numpy_as<'py>(array: &Bound<'py, PyAny>, dtype: py_value::Dtype) -> PyResult<Bound<'py, PyAny>> {
+    let py = array.py();
+    match dtype {
+        py_value::Dtype::Float64 => Ok(DQuatArray::from_array(array.extract()?)?.into_pyobject(py)?.into_any()),
+        #[cfg(feature = "f32")]
+        py_value::Dtype::Float32 => Ok(QuatArray::from_array(array.extract()?)?.into_pyobject(py)?.into_any()),
+        #[cfg(not(feature = "f32"))]
+        py_value::Dtype::Float32 => Err(PyValueError::new_err("pyglam was built without the f32 feature")),
+    }
+}
+
+#[cfg(test)]
+mod test_quat_array {
+    use super::*;
+
+    mod test_dquat_array {
+        use super::*;
+
+        #[test]
+        fn test_from_iter() {
+            let actual: DQuatArray = (0..3).map(|i| glam::DQuat::from_rotation_z(i as f64)).collect();
+            assert_eq!(actual.len(), 3);
+            assert_eq!(actual[0], glam::DQuat::IDENTITY);
+        }
+
+        #[test]
+        fn test_into_vec() {
+            let array = DQuatArray::new(vec![glam::DQuat::IDENTITY]);
+            let actual: Vec<glam::DQuat> = array.into();
+            assert_eq!(actual, vec![glam::DQuat::IDENTITY]);
+        }
+    }
+}
@@ -0,0 +1,339 @@
+//! Seedable random number generation for the random vector and rotation samplers
+//!
+//! Samplers such as `DVec3.rand_uv` draw from a process wide default generator. It uses
+//! thread local OS seeded randomness until `seed` makes it deterministic
+#[cfg(feature = "pyo3")]
+use crate::errors::NormalizationError;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::quat::DQuat;
+#[cfg(feature = "pyo3")]
+use crate::vec3::DVec3;
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+use crate::quat::Quat;
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+use crate::vec3::Vec3;
+#[cfg(feature = "pyo3")]
+use crate::vec3::Vec3Like;
+#[cfg(feature = "pyo3")]
+use pyo3::{exceptions::PyValueError, prelude::*, types::{PyBytes, PyTuple}};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::sync::{Mutex, PoisonError};
+
+/// Generator used by the samplers once `seed` was called, `None` falls back to `rand::rng()`
+static DEFAULT_RNG: Mutex<Option<ChaCha8Rng>> = Mutex::new(None);
+
+/// Runs `f` with the default generator used by the random samplers
+pub fn with_default_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    let mut seeded = DEFAULT_RNG.lock().unwrap_or_else(PoisonError::into_inner);
+    match seeded.as_mut() {
+        Some(rng) => f(rng),
+        None => f(&mut rand::rng()),
+    }
+}
+
+/// Seeds the default generator used by the random samplers
+///
+/// # Arguments
+///
+/// - `seed` (`int | None`) - Seed making every following sample deterministic, `None` restores OS seeded randomness
+///
+#[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyfunction, pyfunction)]
+#[cfg_attr(feature = "pyo3", pyo3(signature = (seed=None)))]
+pub fn seed(seed: Option<u64>) {
+    let mut seeded = DEFAULT_RNG.lock().unwrap_or_else(PoisonError::into_inner);
+    *seeded = seed.map(ChaCha8Rng::seed_from_u64);
+}
+
+/// Seedable random number generator for reproducible sampling
+///
+/// Uses ChaCha8, whose output for a given seed is stable across platforms and releases
+#[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng(ChaCha8Rng);
+
+impl Rng {
+    pub fn new(rng: ChaCha8Rng) -> Self {
+        Self(rng)
+    }
+    /// Creates a generator seeded from `seed`
+    pub fn seed_from_u64(seed: u64) -> Self {
+        Self(ChaCha8Rng::seed_from_u64(seed))
+    }
+    /// Creates a generator seeded from OS randomness
+    pub fn from_entropy() -> Self {
+        Self(ChaCha8Rng::from_rng(&mut rand::rng()))
+    }
+}
+
+/// f64 samples that `Rng` hands out at the precision requested through a `dtype` argument
+#[cfg(feature = "pyo3")]
+trait Sample {
+    /// Converts this sample to `dtype`, f64 if not given
+    fn into_dtype<'py>(self, py: Python<'py>, dtype: Option<py_value::Dtype>) -> PyResult<Bound<'py, PyAny>>;
+}
+
+#[cfg(feature = "pyo3")]
+macro_rules! impl_sample {
+    ($f64_class: ty, $f32_class: ty) => {
+        impl Sample for $f64_class {
+            fn into_dtype<'py>(self, py: Python<'py>, dtype: Option<py_value::Dtype>) -> PyResult<Bound<'py, PyAny>> {
+                match dtype.unwrap_or(py_value::Dtype::Float64) {
+                    py_value::Dtype::Float64 => Ok(self.into_pyobject(py)?.into_any()),
+                    #[cfg(feature = "f32")]
+                    py_value::Dtype::Float32 => Ok(<$f32_class>::from(self).into_pyobject(py)?.into_any()),
+                    #[cfg(not(feature = "f32"))]
+                    py_value::Dtype::Float32 => Err(PyValueError::new_err("pyglam was built without the f32 feature")),
+                }
+            }
+        }
+    };
+}
+#[cfg(feature = "pyo3")]
+impl_sample!(DVec3, Vec3);
+#[cfg(feature = "pyo3")]
+impl_sample!(DQuat, Quat);
+
+#[cfg(feature = "pyo3")]
+#[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+impl Rng {
+    /// Create a generator
+    ///
+    /// # Arguments
+    ///
+    /// - `seed` (`int | None`) - Seed for a reproducible sequence, seeded from OS randomness if not given
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - New generator
+    ///
+    #[new]
+    #[pyo3(signature = (seed=None))]
+    pub fn py_new(seed: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => Self::seed_from_u64(seed),
+            None => Self::from_entropy(),
+        }
+    }
+
+    /// Reseed this generator, restarting its sequence
+    ///
+    /// # Arguments
+    ///
+    /// - `seed` (`int | None`) - Seed for a reproducible sequence, seeded from OS randomness if not given
+    ///
+    #[pyo3(name = "seed", signature = (seed=None))]
+    fn py_seed(&mut self, seed: Option<u64>) {
+        *self = Self::py_new(seed);
+    }
+
+    /// Capture the state of this generator, see `setstate`
+    ///
+    /// # Returns
+    ///
+    /// - `(bytes, int, int)` - 32 byte seed, stream and word position
+    ///
+    fn getstate<'py>(&self, py: Python<'py>) -> (Bound<'py, PyBytes>, u64, u128) {
+        (
+            PyBytes::new(py, &self.0.get_seed()),
+            self.0.get_stream(),
+            self.0.get_word_pos(),
+        )
+    }
+
+    /// Restore a state captured by `getstate`, continuing that sequence
+    ///
+    /// # Arguments
+    ///
+    /// - `state` (`(bytes, int, int)`) - 32 byte seed, stream and word position
+    ///
+    fn setstate(&mut self, state: (Vec<u8>, u64, u128)) -> PyResult<()> {
+        let (seed, stream, word_pos) = state;
+        let seed: [u8; 32] = seed
+            .try_into()
+            .map_err(|_| PyValueError::new_err("Rng state seed must be 32 bytes"))?;
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        rng.set_stream(stream);
+        rng.set_word_pos(word_pos);
+        self.0 = rng;
+        Ok(())
+    }
+
+    /// Pickle support, restored from a version tagged `getstate` tuple
+    fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        py_value::reduce::<Rng>(py, self.getstate(py))
+    }
+    /// Restore a state built by `__reduce__`
+    #[staticmethod]
+    #[gen_stub(skip)]
+    fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+        let mut rng = Self::seed_from_u64(0);
+        rng.setstate(py_value::unpack_state(&state)?)?;
+        Ok(rng)
+    }
+    /// Copy this generator, the copy continues the same sequence independently
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+    fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+        self.clone()
+    }
+
+    /// Generate a random float uniformly distributed in `[0, 1)`
+    fn random(&mut self) -> f64 {
+        rand::Rng::random(&mut self.0)
+    }
+
+    /// Generate a random unit vector, uniformly distributed over the sphere
+    ///
+    /// # Arguments
+    ///
+    /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+    ///
+    /// # Returns
+    ///
+    /// - `DVec3 | Vec3` - Random unit vector
+    ///
+    #[pyo3(signature = (dtype=None))]
+    #[gen_stub(override_return_type(type_repr = "typing.Union[DVec3, Vec3]", imports = ("typing")))]
+    fn rand_uv<'py>(
+        &mut self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        DVec3::sample_uv(&mut self.0).into_dtype(py, dtype)
+    }
+    /// Generate a random vector, uniformly distributed inside the unit ball
+    ///
+    /// # Arguments
+    ///
+    /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+    ///
+    /// # Returns
+    ///
+    /// - `DVec3 | Vec3` - Random vector with a length of at most 1
+    ///
+    #[pyo3(signature = (dtype=None))]
+    #[gen_stub(override_return_type(type_repr = "typing.Union[DVec3, Vec3]", imports = ("typing")))]
+    fn rand_in_ball<'py>(
+        &mut self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        DVec3::sample_in_ball(&mut self.0).into_dtype(py, dtype)
+    }
+    /// Generate a random vector, uniformly distributed inside the cube spanning -1 to 1 on every axis
+    ///
+    /// # Arguments
+    ///
+    /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+    ///
+    /// # Returns
+    ///
+    /// - `DVec3 | Vec3` - Random vector with every component in `[-1, 1]`
+    ///
+    #[pyo3(signature = (dtype=None))]
+    #[gen_stub(override_return_type(type_repr = "typing.Union[DVec3, Vec3]", imports = ("typing")))]
+    fn rand_in_cube<'py>(
+        &mut self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        DVec3::sample_in_cube(&mut self.0).into_dtype(py, dtype)
+    }
+    /// Generate a random vector with independent normally distributed components
+    ///
+    /// # Arguments
+    ///
+    /// - `std_dev` (`float`) - Standard deviation of each component, the mean is 0
+    /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+    ///
+    /// # Returns
+    ///
+    /// - `DVec3 | Vec3` - Random vector
+    ///
+    #[pyo3(signature = (std_dev=1.0, dtype=None))]
+    #[gen_stub(override_return_type(type_repr = "typing.Union[DVec3, Vec3]", imports = ("typing")))]
+    fn rand_gaussian<'py>(
+        &mut self,
+        py: Python<'py>,
+        std_dev: f64,
+        #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        DVec3::sample_gaussian(&mut self.0, std_dev).into_dtype(py, dtype)
+    }
+    /// Generate a random unit vector, uniformly distributed within `half_angle` of `axis`
+    ///
+    /// # Arguments
+    ///
+    /// - `axis` (`Vec3Like`) - Center of the cone, normalized before sampling
+    /// - `half_angle` (`float`) - Maximum angle from `axis` in radians, `pi` covers the whole sphere
+    /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+    ///
+    /// # Returns
+    ///
+    /// - `DVec3 | Vec3` - Random unit vector, raises NormalizationError if `axis` is zero or not finite
+    ///
+    #[pyo3(signature = (axis, half_angle, dtype=None))]
+    #[gen_stub(override_return_type(type_repr = "typing.Union[DVec3, Vec3]", imports = ("typing")))]
+    fn rand_in_cone<'py>(
+        &mut self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] axis: Bound<'py, PyAny>,
+        half_angle: f64,
+        #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let axis = glam::DVec3::from(axis.extract::<Vec3Like>()?)
+            .try_normalize()
+            .ok_or_else(|| NormalizationError::new_err("Cone axis must be non-zero and finite"))?;
+        DVec3::sample_in_cone(&mut self.0, axis, half_angle).into_dtype(py, dtype)
+    }
+    /// Generate a random rotation, uniformly distributed over all rotations
+    ///
+    /// # Arguments
+    ///
+    /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to f64
+    ///
+    /// # Returns
+    ///
+    /// - `DQuat | Quat` - Random unit quaternion
+    ///
+    #[pyo3(signature = (dtype=None))]
+    #[gen_stub(override_return_type(type_repr = "typing.Union[DQuat, Quat]", imports = ("typing")))]
+    fn rand_uniform<'py>(
+        &mut self,
+        py: Python<'py>,
+        #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        DQuat::sample_uniform(&mut self.0).into_dtype(py, dtype)
+    }
+}
+
+impl RngCore for Rng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quat::DQuat;
+    use crate::vec3::DVec3;
+
+    #[test]
+    fn test_seed_is_reproducible() {
+        let mut a = Rng::seed_from_u64(42);
+        let mut b = Rng::seed_from_u64(42);
+        assert_eq!(DVec3::sample_uv(&mut a), DVec3::sample_uv(&mut b));
+        assert_eq!(DQuat::sample_uniform(&mut a), DQuat::sample_uniform(&mut b));
+    }
+}
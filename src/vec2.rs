@@ -0,0 +1,611 @@
+#[cfg(feature = "pyo3")]
+use crate::errors::NormalizationError;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    prelude::*,
+    types::{PyIterator, PyTuple},
+};
+use std::ops::{Add, Deref, DerefMut, Div, Mul, Sub};
+
+/// Supported types for arithmetic operations on vecs
+/// vec2 * Some
+///
+/// `V` is the vector operand, f32 vecs refuse DVec2 operands so that mixed precision promotes to f64
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Vec2ScaleOpsEnum<V> {
+    Float(f64),
+    Int(i64),
+    Vec2(V),
+}
+
+/// Supported types for vector operations on other vecs where scalars don't make sense
+/// example: dot, perp_dot
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Vec2VecOpsEnum {
+    DVec2(DVec2),
+    #[cfg(feature = "f32")]
+    Vec2(Vec2),
+    #[cfg(feature = "py-ref")]
+    DVec2Ref(DVec2Ref),
+}
+
+/// Any python object that can be used where a vec2 is expected
+///
+/// Accepts pyglam vec2s of either precision, 2 component sequences such as tuples and lists,
+/// numpy 1-D arrays of length 2 and objects exposing `__array__`.
+/// Components are held at f64 precision and cast to the precision of the receiving type
+#[cfg(feature = "pyo3")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec2Like(pub glam::DVec2);
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Vec2Like {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        match obj.extract::<Vec2VecOpsEnum>() {
+            Ok(Vec2VecOpsEnum::DVec2(vec)) => Ok(Vec2Like(vec.0)),
+            #[cfg(feature = "f32")]
+            Ok(Vec2VecOpsEnum::Vec2(vec)) => Ok(Vec2Like(vec.0.as_dvec2())),
+            #[cfg(feature = "py-ref")]
+            Ok(Vec2VecOpsEnum::DVec2Ref(vec_ref)) => Ok(Vec2Like(*vec_ref.get_inner_ref()?)),
+            Err(_) => {
+                let components = py_value::extract_components::<2>(&obj)?;
+                Ok(Vec2Like(glam::DVec2::from_array(components)))
+            }
+        }
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<Vec2Like> for glam::DVec2 {
+    fn from(value: Vec2Like) -> Self {
+        value.0
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<Vec2Like> for glam::Vec2 {
+    fn from(value: Vec2Like) -> Self {
+        value.0.as_vec2()
+    }
+}
+
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<Vec2Like, DVec2>> for glam::Vec2 {
+    fn from(value: py_value::F32Operand<Vec2Like, DVec2>) -> Self {
+        value.0.into()
+    }
+}
+
+macro_rules! vec2_glam_wrapper {
+    ($py_class_name: ident, $glam_class_name: ty, $var_type: ty, $vec_operand: ty) => {
+        /// 2 Component vector xy
+        ///
+        /// Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(feature = "py-ref", derive(simple_py_bevy::PyStructRef))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $py_class_name($glam_class_name);
+
+        impl $py_class_name {
+            pub fn new(vec: $glam_class_name) -> Self {
+                Self(vec)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Converts a scalar or vec operand into this glam vector type
+            fn scale_op(rhs: Vec2ScaleOpsEnum<$vec_operand>) -> PyResult<$glam_class_name> {
+                match rhs {
+                    Vec2ScaleOpsEnum::Float(scalar) => Ok(<$glam_class_name>::splat(scalar as $var_type)),
+                    Vec2ScaleOpsEnum::Int(i) => Ok(<$glam_class_name>::splat(i as $var_type)),
+                    Vec2ScaleOpsEnum::Vec2(vec) => Ok(<$glam_class_name>::from(vec)),
+                }
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            #[new]
+            #[pyo3(signature = (x, y=None))]
+            pub fn py_new(x: $var_type, y: Option<$var_type>) -> PyResult<Self> {
+                let inner = <$glam_class_name>::new(x, y.unwrap_or(x));
+                Ok($py_class_name(inner))
+            }
+
+            #[getter]
+            fn get_x(&self) -> $var_type {
+                return self.0.x;
+            }
+            #[getter]
+            fn get_y(&self) -> $var_type {
+                return self.0.y;
+            }
+            #[setter]
+            fn set_x(&mut self, x: $var_type) {
+                self.0.x = x;
+            }
+            #[setter]
+            fn set_y(&mut self, y: $var_type) {
+                self.0.y = y;
+            }
+
+            /// Convert this vector to a 2 component tuple
+            ///
+            /// # Returns
+            ///
+            /// - `(float, float)` - XY tuple
+            ///
+            #[allow(clippy::wrong_self_convention)]
+            fn to_tuple(&self) -> ($var_type, $var_type) {
+                (self.x, self.y)
+            }
+            /// Convert this vector to f64 precision
+            ///
+            /// # Returns
+            ///
+            /// - `DVec2` - Copy with every component widened to f64
+            ///
+            fn as_dvec2(&self) -> DVec2 {
+                DVec2::from(*self)
+            }
+            /// Convert this vector to f32 precision
+            ///
+            /// # Returns
+            ///
+            /// - `Vec2` - Copy with every component rounded to the nearest f32
+            ///
+            #[cfg(feature = "f32")]
+            fn as_vec2(&self) -> Vec2 {
+                Vec2::from(*self)
+            }
+
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}({}, {})",
+                    stringify!($py_class_name),
+                    py_value::float_repr(self.0.x),
+                    py_value::float_repr(self.0.y),
+                )
+            }
+            fn __str__(&self) -> String {
+                format!("{}", self.0)
+            }
+            /// Component-wise value equality with any vec2, regardless of precision
+            ///
+            /// Follows float semantics, so a vector containing NaN is never equal to anything.
+            /// Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+            fn __eq__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] other: Vec2VecOpsEnum,
+            ) -> PyResult<bool> {
+                let this = glam::DVec2::new(self.0.x as f64, self.0.y as f64);
+                match other {
+                    Vec2VecOpsEnum::DVec2(vec) => {
+                        return Ok(this == *vec);
+                    }
+                    #[cfg(feature = "f32")]
+                    Vec2VecOpsEnum::Vec2(vec) => {
+                        return Ok(this == vec.0.as_dvec2());
+                    }
+                    #[cfg(feature = "py-ref")]
+                    Vec2VecOpsEnum::DVec2Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        return Ok(this == *vec);
+                    }
+                }
+            }
+            /// Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+            fn __hash__(&self) -> u64 {
+                py_value::hash_floats(&[self.0.x as f64, self.0.y as f64])
+            }
+
+            fn __len__(&self) -> usize {
+                2
+            }
+            /// Get a component by index, or a tuple of components by slice
+            fn __getitem__<'py>(
+                &self,
+                index: Bound<'py, PyAny>,
+            ) -> PyResult<Either<$var_type, Bound<'py, PyTuple>>> {
+                py_value::get_item(&[self.0.x, self.0.y], &index)
+            }
+            /// Set a component by index, or a slice of components from a sequence of the same length
+            fn __setitem__(&mut self, index: Bound<'_, PyAny>, value: Bound<'_, PyAny>) -> PyResult<()> {
+                let mut components = self.0.to_array();
+                py_value::set_item(&mut components, &index, &value)?;
+                self.0 = <$glam_class_name>::from_array(components);
+                Ok(())
+            }
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                PyTuple::new(py, [self.0.x, self.0.y])?.try_iter()
+            }
+            /// Whether any component equals `value`, False for values that aren't numbers
+            fn __contains__(&self, value: Bound<'_, PyAny>) -> bool {
+                value
+                    .extract::<$var_type>()
+                    .is_ok_and(|value| [self.0.x, self.0.y].contains(&value))
+            }
+
+            fn __add__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this + rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 + rhs));
+            }
+            fn __radd__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs + this
+                return self.__add__(lhs);
+            }
+            fn __sub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this - rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 - rhs));
+            }
+            fn __rsub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs - this
+                let lhs = Self::scale_op(lhs)?;
+                return Ok($py_class_name::new(lhs - self.0));
+            }
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this * rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 * rhs));
+            }
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs * this
+                return self.__mul__(lhs);
+            }
+            fn __truediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this / rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 / rhs));
+            }
+            fn __rtruediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs / this
+                let lhs = Self::scale_op(lhs)?;
+                return Ok($py_class_name::new(lhs / self.0));
+            }
+
+            fn __iadd__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this += rhs
+                self.0 += Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __isub__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this -= rhs
+                self.0 -= Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __imul__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this *= rhs
+                self.0 *= Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __itruediv__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec2Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec2ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this /= rhs
+                self.0 /= Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __neg__(&self) -> $py_class_name {
+                // -this
+                return $py_class_name::new(-self.0);
+            }
+
+            /// Normalize this vector to unit length
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+            ///
+            fn normalize(&self) -> PyResult<$py_class_name> {
+                self.0
+                    .try_normalize()
+                    .map($py_class_name::new)
+                    .ok_or_else(|| NormalizationError::new_err("Can not normalize a vector with zero, infinite or NaN length"))
+            }
+            fn length(&self) -> $var_type {
+                return self.0.length();
+            }
+            /// Computes the dot product of this vector and `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec2Like`) - Other vector
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Dot product
+            ///
+            fn dot(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec2Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec2Like>()?);
+                return Ok(self.0.dot(rhs));
+            }
+            /// The perpendicular dot product of this vector and `rhs`.
+            /// Also known as the wedge product, 2D cross product, and determinant.
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec2Like`) - Other vector
+            ///
+            /// # Returns
+            ///
+            /// - `float` - `x * rhs.y - y * rhs.x`
+            ///
+            fn perp_dot(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec2Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec2Like>()?);
+                return Ok(self.0.perp_dot(rhs));
+            }
+
+            /// Pickle support, restored from a version tagged list of the components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_array(py_value::unpack_state(&state)?)))
+            }
+            /// Copy this vector
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
+        }
+
+        macro_rules! from_glam {
+            ($a:ty, $b:ty) => {
+                impl From<$a> for $b {
+                    fn from(value: $a) -> Self {
+                        Self(value.clone())
+                    }
+                }
+            };
+        }
+        from_glam!($glam_class_name, $py_class_name);
+        from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = $glam_class_name;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        macro_rules! ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Add<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn add(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 + rhs.0)
+                    }
+                }
+                impl Sub<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn sub(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 - rhs.0)
+                    }
+                }
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs.0)
+                    }
+                }
+                impl Div<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn div(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 / rhs.0)
+                    }
+                }
+            };
+        }
+        ops_with_self!($py_class_name, $py_class_name);
+        ops_with_self!($py_class_name, &$py_class_name);
+        ops_with_self!(&$py_class_name, $py_class_name);
+        ops_with_self!(&$py_class_name, &$py_class_name);
+
+        macro_rules! ops_with_glam {
+            ($a:ty, $b:ty) => {
+                impl Add<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn add(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 + rhs)
+                    }
+                }
+                impl Sub<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn sub(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 - rhs)
+                    }
+                }
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs)
+                    }
+                }
+                impl Div<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn div(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 / rhs)
+                    }
+                }
+            };
+        }
+        ops_with_glam!($glam_class_name, $py_class_name);
+        ops_with_glam!(&$glam_class_name, $py_class_name);
+        ops_with_glam!($glam_class_name, &$py_class_name);
+        ops_with_glam!(&$glam_class_name, &$py_class_name);
+        ops_with_glam!($var_type, $py_class_name);
+        ops_with_glam!(&$var_type, $py_class_name);
+        ops_with_glam!($var_type, &$py_class_name);
+        ops_with_glam!(&$var_type, &$py_class_name);
+    };
+}
+vec2_glam_wrapper!(DVec2, glam::DVec2, f64, Vec2Like);
+#[cfg(feature = "f32")]
+vec2_glam_wrapper!(Vec2, glam::Vec2, f32, py_value::F32Operand<Vec2Like, DVec2>);
+
+/// Widens every component to f64
+#[cfg(feature = "f32")]
+impl From<Vec2> for DVec2 {
+    fn from(value: Vec2) -> Self {
+        Self(value.0.as_dvec2())
+    }
+}
+/// Rounds every component to the nearest f32
+#[cfg(feature = "f32")]
+impl From<DVec2> for Vec2 {
+    fn from(value: DVec2) -> Self {
+        Self(value.0.as_vec2())
+    }
+}
+
+/// Creates a 2-dimensional f64 vector
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn dvec2(x: f64, y: f64) -> DVec2 {
+    DVec2::new(glam::dvec2(x, y))
+}
+#[cfg(feature = "f32")]
+/// Creates a 2-dimensional f32 vector
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn vec2(x: f32, y: f32) -> Vec2 {
+    Vec2::new(glam::vec2(x, y))
+}
+
+#[cfg(test)]
+mod test_vec2 {
+    use super::*;
+
+    mod test_dvec2 {
+        use super::*;
+
+        #[test]
+        fn test_deref() {
+            let actual = DVec2(glam::DVec2::splat(0.));
+            assert_eq!(actual.x, 0.);
+        }
+
+        #[test]
+        fn test_simple_dvec2_api() {
+            let actual = dvec2(10., 10.);
+            assert_eq!(actual.y, 10.);
+        }
+        #[cfg(feature = "f32")]
+        #[test]
+        fn test_simple_vec2_api() {
+            let actual = vec2(10., 10.);
+            assert_eq!(actual.y, 10.);
+        }
+
+        #[test]
+        fn test_add() {
+            let actual = dvec2(10., 10.) + glam::dvec2(10., 10.);
+            assert_eq!(actual.x, 20.);
+        }
+        #[test]
+        fn test_div() {
+            let actual = dvec2(10., 10.) / 10.;
+            assert_eq!(actual.x, 1.);
+        }
+        #[test]
+        fn test_perp_dot() {
+            let actual = dvec2(1., 0.).0.perp_dot(glam::dvec2(0., 1.));
+            assert_eq!(actual, 1.);
+        }
+    }
+}
@@ -1,22 +1,47 @@
+// pyo3 calls the unsafe buffer protocol methods from its generated wrappers without an unsafe
+// block, which the 2024 edition lints when the methods are defined inside `macro_rules!`
+#![cfg_attr(feature = "numpy", allow(unsafe_op_in_unsafe_fn))]
+#[cfg(feature = "numpy")]
+use crate::buffer;
+#[cfg(feature = "pyo3")]
+use crate::errors::NormalizationError;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::random;
+#[cfg(feature = "pyo3")]
+use crate::vec2;
+#[cfg(feature = "pyo3")]
+use crate::vec4;
+#[cfg(feature = "pyo3")]
+use either::Either;
 use glam;
 #[cfg(feature = "pyo3")]
+use glam::Vec3Swizzles;
+#[cfg(feature = "numpy")]
+use numpy::{AllowTypeChange, PyArrayLike1, PyUntypedArray};
+#[cfg(feature = "numpy")]
+use pyo3::ffi;
+#[cfg(feature = "pyo3")]
 use pyo3::{
-    exceptions::{PyNotImplementedError, PyValueError},
+    exceptions::PyValueError,
     prelude::*,
+    types::{PyIterator, PyTuple},
 };
+#[cfg(feature = "numpy")]
+use std::ffi::c_int;
 use std::ops::{Add, Deref, DerefMut, Div, Mul, Sub};
 
 /// Supported types for arithmetic operations on vecs
 /// vec3 * Some
+///
+/// `V` is the vector operand, f32 vecs refuse DVec3 operands so that mixed precision promotes to f64
 #[cfg(feature = "pyo3")]
 #[derive(FromPyObject)]
-enum Vec3ScaleOpsEnum {
+enum Vec3ScaleOpsEnum<V> {
     Float(f64),
     Int(i64),
-    DVec3(DVec3),
-    Vec3(DVec3),
-    #[cfg(feature = "py-ref")]
-    DVec3Ref(DVec3Ref),
+    Vec3(V),
 }
 
 /// Supported types for vector operations on other vecs where scalars don't make sense
@@ -31,10 +56,104 @@ enum Vec3VecOpsEnum {
     DVec3Ref(DVec3Ref),
 }
 
+/// Any python object that can be used where a vec3 is expected
+///
+/// Accepts pyglam vec3s of either precision, 3 component sequences such as tuples and lists,
+/// numpy 1-D arrays of length 3 and objects exposing `__array__`.
+/// Components are held at f64 precision and cast to the precision of the receiving type
+#[cfg(feature = "pyo3")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3Like(pub glam::DVec3);
+
+#[cfg(feature = "pyo3")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Vec3Like {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        match obj.extract::<Vec3VecOpsEnum>() {
+            Ok(Vec3VecOpsEnum::DVec3(vec)) => Ok(Vec3Like(vec.0)),
+            #[cfg(feature = "f32")]
+            Ok(Vec3VecOpsEnum::Vec3(vec)) => Ok(Vec3Like(vec.0.as_dvec3())),
+            #[cfg(feature = "py-ref")]
+            Ok(Vec3VecOpsEnum::DVec3Ref(vec_ref)) => Ok(Vec3Like(*vec_ref.get_inner_ref()?)),
+            Err(_) => {
+                let components = py_value::extract_components::<3>(&obj)?;
+                Ok(Vec3Like(glam::DVec3::from_array(components)))
+            }
+        }
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<Vec3Like> for glam::DVec3 {
+    fn from(value: Vec3Like) -> Self {
+        value.0
+    }
+}
+#[cfg(feature = "pyo3")]
+impl From<Vec3Like> for glam::Vec3 {
+    fn from(value: Vec3Like) -> Self {
+        value.0.as_vec3()
+    }
+}
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<Vec3Like, DVec3>> for glam::Vec3 {
+    fn from(value: py_value::F32Operand<Vec3Like, DVec3>) -> Self {
+        value.0.into()
+    }
+}
+
+/// Invokes a wrapper macro with every vec3 swizzle appended to its arguments
+///
+/// The swizzles are handed to the wrapper as name lists so they're generated alongside the rest of
+/// the class's methods. The lists are the 2, 3 and 4 component reads followed by the 2 and 3
+/// component writes, which skip repeated components
+macro_rules! with_vec3_swizzles {
+    ($wrapper: ident!($($args: tt)*)) => {
+        $wrapper!(
+            $($args)*;
+            [
+                xx, xy, xz, yx, yy, yz, zx, zy, zz
+            ];
+            [
+                xxx, xxy, xxz, xyx, xyy, xyz, xzx, xzy, xzz, yxx, yxy, yxz, yyx, yyy, yyz, yzx,
+                yzy, yzz, zxx, zxy, zxz, zyx, zyy, zyz, zzx, zzy, zzz
+            ];
+            [
+                xxxx, xxxy, xxxz, xxyx, xxyy, xxyz, xxzx, xxzy, xxzz, xyxx, xyxy, xyxz, xyyx, xyyy,
+                xyyz, xyzx, xyzy, xyzz, xzxx, xzxy, xzxz, xzyx, xzyy, xzyz, xzzx, xzzy, xzzz, yxxx,
+                yxxy, yxxz, yxyx, yxyy, yxyz, yxzx, yxzy, yxzz, yyxx, yyxy, yyxz, yyyx, yyyy, yyyz,
+                yyzx, yyzy, yyzz, yzxx, yzxy, yzxz, yzyx, yzyy, yzyz, yzzx, yzzy, yzzz, zxxx, zxxy,
+                zxxz, zxyx, zxyy, zxyz, zxzx, zxzy, zxzz, zyxx, zyxy, zyxz, zyyx, zyyy, zyyz, zyzx,
+                zyzy, zyzz, zzxx, zzxy, zzxz, zzyx, zzyy, zzyz, zzzx, zzzy, zzzz
+            ];
+            [
+                (xy, set_xy, x, y), (xz, set_xz, x, z), (yx, set_yx, y, x), (yz, set_yz, y, z),
+                (zx, set_zx, z, x), (zy, set_zy, z, y)
+            ];
+            [
+                (xyz, set_xyz, x, y, z), (xzy, set_xzy, x, z, y), (yxz, set_yxz, y, x, z),
+                (yzx, set_yzx, y, z, x), (zxy, set_zxy, z, x, y), (zyx, set_zyx, z, y, x)
+            ]
+        );
+    };
+}
+
 macro_rules! vec3_glam_wrapper {
-    ($py_class_name: ident, $glam_class_name: ty, $glam_quat_class_name: ty, $var_type: ty) => {
+    (
+        $py_class_name: ident, $py_vec2_class_name: ty, $py_vec4_class_name: ty, $glam_class_name: ty, $glam_quat_class_name: ty, $var_type: ty, $vec_operand: ty;
+        [$($swizzle2: ident),*];
+        [$($swizzle3: ident),*];
+        [$($swizzle4: ident),*];
+        [$(($set_swizzle2: ident, $set_swizzle2_fn: ident, $a2: ident, $b2: ident)),*];
+        [$(($set_swizzle3: ident, $set_swizzle3_fn: ident, $a3: ident, $b3: ident, $c3: ident)),*]
+    ) => {
         /// 3 Component vector xyz
-        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass)]
+        ///
+        /// Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+        ///
+        /// Swizzles such as `v.zyx` or `v.xy` read components in any order and return a vec of that length,
+        /// the ones without repeated components can also be assigned, e.g. `v.xy = (1.0, 2.0)`
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
         #[cfg_attr(
             feature = "py-ref",
             derive(
@@ -53,15 +172,71 @@ macro_rules! vec3_glam_wrapper {
             reflect(Clone)
         )]
         #[repr(transparent)]
-        #[derive(Clone, Copy, Default, PartialEq)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
         pub struct $py_class_name($glam_class_name);
 
         impl $py_class_name {
             pub fn new(vec: $glam_class_name) -> Self {
                 Self(vec)
             }
+
+            /// Samples a unit vector uniformly distributed over the sphere
+            pub fn sample_uv<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                // uniform z and azimuth give a uniform distribution by Archimedes' hat-box theorem
+                let z: $var_type = rng.random_range(-1.0..=1.0);
+                let phi = rng.random::<$var_type>() * std::f64::consts::TAU as $var_type;
+                let r = (1.0 - z * z).max(0.0).sqrt();
+                Self::new(<$glam_class_name>::new(r * phi.cos(), r * phi.sin(), z))
+            }
+            /// Samples a vector uniformly distributed inside the unit ball
+            pub fn sample_in_ball<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                let radius = rng.random::<$var_type>().cbrt();
+                Self::new(Self::sample_uv(rng).0 * radius)
+            }
+            /// Samples a vector uniformly distributed inside the cube spanning -1 to 1 on every axis
+            pub fn sample_in_cube<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::new(<$glam_class_name>::new(
+                    rng.random_range(-1.0..=1.0),
+                    rng.random_range(-1.0..=1.0),
+                    rng.random_range(-1.0..=1.0),
+                ))
+            }
+            /// Samples a vector whose components are independent zero mean normal distributions
+            pub fn sample_gaussian<R: rand::Rng + ?Sized>(rng: &mut R, std_dev: $var_type) -> Self {
+                // Box-Muller transform, the unused fourth normal is discarded
+                let mut normal_pair = || {
+                    // 1 - [0, 1) avoids ln(0)
+                    let radius = (-2.0 * (1.0 - rng.random::<$var_type>()).ln()).sqrt();
+                    let theta = rng.random::<$var_type>() * std::f64::consts::TAU as $var_type;
+                    (radius * theta.cos(), radius * theta.sin())
+                };
+                let (x, y) = normal_pair();
+                let (z, _) = normal_pair();
+                Self::new(<$glam_class_name>::new(x, y, z) * std_dev)
+            }
+            /// Samples a unit vector uniformly distributed over the spherical cap within `half_angle` radians of `axis`
+            ///
+            /// `axis` must be normalized
+            pub fn sample_in_cone<R: rand::Rng + ?Sized>(
+                rng: &mut R,
+                axis: $glam_class_name,
+                half_angle: $var_type,
+            ) -> Self {
+                let cos_theta: $var_type = 1.0 - rng.random::<$var_type>() * (1.0 - half_angle.cos());
+                let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+                let phi = rng.random::<$var_type>() * std::f64::consts::TAU as $var_type;
+                let (b1, b2) = axis.any_orthonormal_pair();
+                Self::new(b1 * (sin_theta * phi.cos()) + b2 * (sin_theta * phi.sin()) + axis * cos_theta)
+            }
         }
 
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
         #[cfg(feature = "pyo3")]
         #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
         #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
@@ -78,16 +253,129 @@ macro_rules! vec3_glam_wrapper {
                 let inner = <$glam_class_name>::new(x, y.unwrap_or(x), z.unwrap_or(x));
                 Ok($py_class_name(inner))
             }
-            /// Generates a random unit vector, normalized to 1.
+            /// All zeros
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn ZERO() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::ZERO))
+            }
+            /// All ones
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn ONE() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::ONE))
+            }
+            /// All negative ones
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn NEG_ONE() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::NEG_ONE))
+            }
+            /// Unit vector along the positive x axis
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn X() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::X))
+            }
+            /// Unit vector along the positive y axis
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn Y() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::Y))
+            }
+            /// Unit vector along the positive z axis
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn Z() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::Z))
+            }
+            /// Unit vector along the negative x axis
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn NEG_X() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::NEG_X))
+            }
+            /// Unit vector along the negative y axis
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn NEG_Y() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::NEG_Y))
+            }
+            /// Unit vector along the negative z axis
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn NEG_Z() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::NEG_Z))
+            }
+            /// All NaN
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn NAN() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::NAN))
+            }
+            /// All positive infinity
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn INFINITY() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::INFINITY))
+            }
+            /// All negative infinity
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn NEG_INFINITY() -> py_value::Constant<Self> {
+                py_value::Constant(Self(<$glam_class_name>::NEG_INFINITY))
+            }
+
+            /// Generates a random unit vector, uniformly distributed over the sphere
             #[staticmethod]
-            pub fn rand_uv() ->  Self {
-                Self::new(
-                    <$glam_class_name>::new(
-                        rand::random(),
-                        rand::random(),
-                        rand::random(),
-                    ).normalize()
-                )
+            pub fn rand_uv() -> Self {
+                random::with_default_rng(|rng| Self::sample_uv(rng))
+            }
+            /// Generates a random vector, uniformly distributed inside the unit ball
+            #[staticmethod]
+            pub fn rand_in_ball() -> Self {
+                random::with_default_rng(|rng| Self::sample_in_ball(rng))
+            }
+            /// Generates a random vector, uniformly distributed inside the cube spanning -1 to 1 on every axis
+            #[staticmethod]
+            pub fn rand_in_cube() -> Self {
+                random::with_default_rng(|rng| Self::sample_in_cube(rng))
+            }
+            /// Generates a random vector with independent normally distributed components
+            ///
+            /// # Arguments
+            ///
+            /// - `std_dev` (`float`) - Standard deviation of each component, the mean is 0
+            ///
+            /// # Returns
+            ///
+            /// - `Self` - Random vector
+            ///
+            #[staticmethod]
+            #[pyo3(signature = (std_dev=1.0))]
+            pub fn rand_gaussian(std_dev: $var_type) -> Self {
+                random::with_default_rng(|rng| Self::sample_gaussian(rng, std_dev))
+            }
+            /// Generates a random unit vector, uniformly distributed within `half_angle` of `axis`
+            ///
+            /// # Arguments
+            ///
+            /// - `axis` (`Vec3Like`) - Center of the cone, normalized before sampling
+            /// - `half_angle` (`float`) - Maximum angle from `axis` in radians, `pi` covers the whole sphere
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Random unit vector, raises NormalizationError if `axis` is zero or not finite
+            ///
+            #[staticmethod]
+            pub fn rand_in_cone(
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] axis: Bound<'_, PyAny>,
+                half_angle: $var_type,
+            ) -> PyResult<Self> {
+                let axis = <$glam_class_name>::from(axis.extract::<Vec3Like>()?)
+                    .try_normalize()
+                    .ok_or_else(|| NormalizationError::new_err("Cone axis must be non-zero and finite"))?;
+                Ok(random::with_default_rng(|rng| Self::sample_in_cone(rng, axis, half_angle)))
             }
 
             #[getter]
@@ -114,6 +402,45 @@ macro_rules! vec3_glam_wrapper {
             fn set_z(&mut self, z: $var_type) {
                 self.0.z = z;
             }
+            $(
+                #[getter]
+                fn $swizzle2(&self) -> $py_vec2_class_name {
+                    self.0.$swizzle2().into()
+                }
+            )*
+            $(
+                #[getter]
+                fn $swizzle3(&self) -> Self {
+                    Self(self.0.$swizzle3())
+                }
+            )*
+            $(
+                #[getter]
+                fn $swizzle4(&self) -> $py_vec4_class_name {
+                    self.0.$swizzle4().into()
+                }
+            )*
+            $(
+                #[setter($set_swizzle2)]
+                fn $set_swizzle2_fn(
+                    &mut self,
+                    #[gen_stub(override_type(type_repr = "Vec2Like", imports = ()))] value: vec2::Vec2Like,
+                ) {
+                    self.0.$a2 = value.0.x as $var_type;
+                    self.0.$b2 = value.0.y as $var_type;
+                }
+            )*
+            $(
+                #[setter($set_swizzle3)]
+                fn $set_swizzle3_fn(
+                    &mut self,
+                    #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] value: Vec3Like,
+                ) {
+                    self.0.$a3 = value.0.x as $var_type;
+                    self.0.$b3 = value.0.y as $var_type;
+                    self.0.$c3 = value.0.z as $var_type;
+                }
+            )*
 
             /// Convert this vector to a 3 component tuple
             ///
@@ -124,354 +451,733 @@ macro_rules! vec3_glam_wrapper {
             fn to_tuple(&self) -> ($var_type, $var_type, $var_type) {
                 (self.x, self.y, self.z)
             }
+            /// Convert this vector to f64 precision
+            ///
+            /// # Returns
+            ///
+            /// - `DVec3` - Copy with every component widened to f64
+            ///
+            fn as_dvec3(&self) -> DVec3 {
+                DVec3::from(*self)
+            }
+            /// Convert this vector to f32 precision
+            ///
+            /// # Returns
+            ///
+            /// - `Vec3` - Copy with every component rounded to the nearest f32
+            ///
+            #[cfg(feature = "f32")]
+            fn as_vec3(&self) -> Vec3 {
+                Vec3::from(*self)
+            }
+
+            /// Create a vector from a numpy array of 3 xyz components
+            ///
+            /// # Arguments
+            ///
+            /// - `array` (`numpy.typing.ArrayLike`) - 1-D array of length 3, other dtypes are cast to this type's precision
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - New vector holding a copy of the components
+            ///
+            #[cfg(feature = "numpy")]
+            #[staticmethod]
+            fn from_numpy(
+                #[gen_stub(override_type(type_repr = "numpy.typing.ArrayLike", imports = ("numpy.typing")))] array: PyArrayLike1<'_, $var_type, AllowTypeChange>,
+            ) -> PyResult<Self> {
+                let components = array.as_array();
+                if components.len() != 3 {
+                    return Err(PyValueError::new_err(format!(
+                        "expected an array of 3 components, got {}",
+                        components.len()
+                    )));
+                }
+                Ok($py_class_name(<$glam_class_name>::new(components[0], components[1], components[2])))
+            }
+            /// View this vector as a numpy array, see `numpy.asarray`
+            ///
+            /// Without a dtype conversion or `copy=True` the array is a read-only view of this vector
+            ///
+            /// # Arguments
+            ///
+            /// - `dtype` (`numpy.dtype | None`) - Requested dtype, defaults to this type's precision
+            /// - `copy` (`bool | None`) - True to always copy, False to raise ValueError instead of copying
+            ///
+            /// # Returns
+            ///
+            /// - `numpy.ndarray` - 1-D array of the xyz components
+            ///
+            #[cfg(feature = "numpy")]
+            #[pyo3(signature = (dtype=None, copy=None))]
+            fn __array__<'py>(
+                slf: Bound<'py, Self>,
+                dtype: Option<Bound<'py, PyAny>>,
+                copy: Option<bool>,
+            ) -> PyResult<Bound<'py, PyUntypedArray>> {
+                buffer::to_numpy(slf.as_any(), dtype, copy)
+            }
+            /// Exposes the xyz components as a read-only, contiguous float buffer
+            #[cfg(feature = "numpy")]
+            #[gen_stub(skip)]
+            unsafe fn __getbuffer__(
+                slf: Bound<'_, Self>,
+                view: *mut ffi::Py_buffer,
+                flags: c_int,
+            ) -> PyResult<()> {
+                let data = &slf.try_borrow()?.0 as *const $glam_class_name as *const $var_type;
+                unsafe { buffer::fill_view(view, flags, data, 3, slf.into_any()) }
+            }
+            #[cfg(feature = "numpy")]
+            #[gen_stub(skip)]
+            unsafe fn __releasebuffer__(&self, view: *mut ffi::Py_buffer) {
+                unsafe { buffer::release_view(view) }
+            }
+
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}({}, {}, {})",
+                    stringify!($py_class_name),
+                    py_value::float_repr(self.0.x),
+                    py_value::float_repr(self.0.y),
+                    py_value::float_repr(self.0.z),
+                )
+            }
+            fn __str__(&self) -> String {
+                format!("{}", self.0)
+            }
+            /// Component-wise value equality with any vec3, regardless of precision
+            ///
+            /// Follows float semantics, so a vector containing NaN is never equal to anything.
+            /// Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+            fn __eq__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] other: Vec3VecOpsEnum,
+            ) -> PyResult<bool> {
+                let this = glam::DVec3::new(self.0.x as f64, self.0.y as f64, self.0.z as f64);
+                match other {
+                    Vec3VecOpsEnum::DVec3(vec) => {
+                        return Ok(this == *vec);
+                    }
+                    #[cfg(feature = "f32")]
+                    Vec3VecOpsEnum::Vec3(vec) => {
+                        return Ok(this == vec.0.as_dvec3());
+                    }
+                    #[cfg(feature = "py-ref")]
+                    Vec3VecOpsEnum::DVec3Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        return Ok(this == *vec);
+                    }
+                }
+            }
+            /// Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+            fn __hash__(&self) -> u64 {
+                py_value::hash_floats(&[self.0.x as f64, self.0.y as f64, self.0.z as f64])
+            }
+            /// Determine whether each component of this vector is close to `other`, following `math.isclose`
+            ///
+            /// # Arguments
+            ///
+            /// - `other` (`Vec3Like`) - Vector to compare against, any vec3 or 3 component sequence
+            /// - `rel_tol` (`float`) - Maximum difference relative to the larger absolute component
+            /// - `abs_tol` (`float`) - Minimum absolute tolerance, useful for comparisons near zero
+            ///
+            /// # Returns
+            ///
+            /// - `bool` - True if every component pair is close
+            ///
+            #[pyo3(signature = (other, rel_tol=1e-9, abs_tol=0.0))]
+            fn is_close(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] other: Bound<'_, PyAny>,
+                rel_tol: f64,
+                abs_tol: f64,
+            ) -> PyResult<bool> {
+                py_value::check_tolerances(rel_tol, abs_tol)?;
+                let other: glam::DVec3 = other.extract::<Vec3Like>()?.into();
+                let this = [self.0.x as f64, self.0.y as f64, self.0.z as f64];
+                Ok(this
+                    .iter()
+                    .zip(other.to_array())
+                    .all(|(a, b)| py_value::is_close(*a, b, rel_tol, abs_tol)))
+            }
+
+            fn __len__(&self) -> usize {
+                3
+            }
+            /// Get a component by index, or a tuple of components by slice
+            fn __getitem__<'py>(
+                &self,
+                index: Bound<'py, PyAny>,
+            ) -> PyResult<Either<$var_type, Bound<'py, PyTuple>>> {
+                py_value::get_item(&[self.0.x, self.0.y, self.0.z], &index)
+            }
+            /// Set a component by index, or a slice of components from a sequence of the same length
+            fn __setitem__(&mut self, index: Bound<'_, PyAny>, value: Bound<'_, PyAny>) -> PyResult<()> {
+                let mut components = self.0.to_array();
+                py_value::set_item(&mut components, &index, &value)?;
+                self.0 = <$glam_class_name>::from_array(components);
+                Ok(())
+            }
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                PyTuple::new(py, [self.0.x, self.0.y, self.0.z])?.try_iter()
+            }
+            /// Whether any component equals `value`, False for values that aren't numbers
+            fn __contains__(&self, value: Bound<'_, PyAny>) -> bool {
+                value
+                    .extract::<$var_type>()
+                    .is_ok_and(|value| [self.0.x, self.0.y, self.0.z].contains(&value))
+            }
 
-            pub fn __add__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __add__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // this + rhs
                 let this = self.0;
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         return Ok($py_class_name::new(this + scalar as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         return Ok($py_class_name::new(this + i as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(this + <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(this + <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(this + <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        return Ok($py_class_name::new(this + <$glam_class_name>::from(vec)));
                     }
                 }
             }
-            pub fn __radd__(&mut self, lhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __radd__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // lhs + this
                 return self.__add__(lhs);
             }
-            pub fn __sub__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __sub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // this - rhs
                 let this = self.0;
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         return Ok($py_class_name::new(this - scalar as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         return Ok($py_class_name::new(this - i as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(this - <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(this - <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(this - <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        return Ok($py_class_name::new(this - <$glam_class_name>::from(vec)));
                     }
                 }
             }
-            pub fn __rsub__(&mut self, lhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __rsub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // lhs - this
                 let this = self.0;
-                match lhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match lhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         return Ok($py_class_name::new(scalar as $var_type - this));
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         return Ok($py_class_name::new(i as $var_type - this));
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type) - this));
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type) - this));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type) - this));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        return Ok($py_class_name::new(<$glam_class_name>::from(vec) - this));
                     }
                 }
             }
-            pub fn __mul__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // this * rhs
                 let this = self.0;
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         return Ok($py_class_name::new(this * scalar as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         return Ok($py_class_name::new(this * i as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(this * <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(this * <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(this * <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        return Ok($py_class_name::new(this * <$glam_class_name>::from(vec)));
                     }
                 }
             }
-            pub fn __rmul__(&mut self, lhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // lhs * this
                 return self.__mul__(lhs);
             }
-            pub fn __truediv__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __truediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // this / rhs
                 let this = self.0;
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         return Ok($py_class_name::new(this / scalar as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         return Ok($py_class_name::new(this / i as f64 as $var_type));
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(this / <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(this / <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(this / <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        return Ok($py_class_name::new(this / <$glam_class_name>::from(vec)));
                     }
                 }
             }
-            pub fn __rtruediv__(&mut self, lhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
+            fn __rtruediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
                 // lhs / this
                 let this = self.0;
-                match lhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match lhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         return Ok($py_class_name::new(scalar as $var_type / this));
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         return Ok($py_class_name::new(i as $var_type / this));
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type) / this));
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type) / this));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type) / this));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        return Ok($py_class_name::new(<$glam_class_name>::from(vec) / this));
                     }
                 }
             }
 
-            pub fn __iadd__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<()> {
+            fn __iadd__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
                 // this += rhs
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         self.0 += scalar as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         self.0 += i as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        self.0 += <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        self.0 += <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        self.0 += <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        self.0 += <$glam_class_name>::from(vec);
                         return Ok(());
                     }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
-                    }
                 }
             }
-            pub fn __isub__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<()> {
+            fn __isub__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
                 // this -= rhs
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         self.0 -= scalar as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         self.0 -= i as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        self.0 -= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        self.0 -= <$glam_class_name>::from(vec);
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        self.0 -= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        self.0 -= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
-                    }
                 }
             }
-            pub fn __imul__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<()> {
+            fn __imul__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
                 // this *= rhs
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         self.0 *= scalar as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         self.0 *= i as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        self.0 *= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        self.0 *= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        self.0 *= <$glam_class_name>::from(vec);
                         return Ok(());
                     }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        self.0 *= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
-                    }
                 }
             }
-            pub fn __itruediv__(&mut self, rhs: Bound<'_, PyAny>) -> PyResult<()> {
+            fn __itruediv__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[Vec3Like, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec3ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
                 // this *= rhs
-                match rhs.extract::<Vec3ScaleOpsEnum>() {
-                    Ok(Vec3ScaleOpsEnum::Float(scalar)) => {
+                match rhs {
+                    Vec3ScaleOpsEnum::Float(scalar) => {
                         self.0 /= scalar as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::Int(i)) => {
+                    Vec3ScaleOpsEnum::Int(i) => {
                         self.0 /= i as $var_type;
                         return Ok(());
                     }
-                    Ok(Vec3ScaleOpsEnum::DVec3(vec)) => {
-                        self.0 /= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    Ok(Vec3ScaleOpsEnum::Vec3(vec)) => {
-                        self.0 /= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
+                    Vec3ScaleOpsEnum::Vec3(vec) => {
+                        self.0 /= <$glam_class_name>::from(vec);
                         return Ok(());
                     }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3ScaleOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        self.0 /= <$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type);
-                        return Ok(());
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
-                    }
                 }
             }
-            pub fn __neg__(&mut self) -> $py_class_name {
+            fn __neg__(&self) -> $py_class_name {
                 // -this
                 return $py_class_name::new(-self.0);
             }
 
-            fn normalize(&self) -> $py_class_name {
-                return $py_class_name::new(self.0.normalize());
+            /// Normalize this vector to unit length
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+            ///
+            fn normalize(&self) -> PyResult<$py_class_name> {
+                self.0
+                    .try_normalize()
+                    .map($py_class_name::new)
+                    .ok_or_else(|| NormalizationError::new_err("Can not normalize a vector with zero, infinite or NaN length"))
             }
             fn length(&self) -> $var_type {
                 return self.0.length();
             }
-            fn dot(&self, rhs: Bound<'_, PyAny>) -> PyResult<$var_type> {
-                match rhs.extract::<Vec3VecOpsEnum>() {
-                    Ok(Vec3VecOpsEnum::DVec3(vec)) => {
-                        return Ok(self.0.dot(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    #[cfg(feature = "f32")]
-                    Ok(Vec3VecOpsEnum::Vec3(vec)) => {
-                        return Ok(self.0.dot(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3VecOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok(self.0.dot(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type)));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
-                    }
+            /// Creates a vec4 from the `x`, `y` and `z` elements of this vector and `w`
+            ///
+            /// # Arguments
+            ///
+            /// - `w` (`float`) - w component, use 1.0 for points and 0.0 for directions
+            ///
+            /// # Returns
+            ///
+            /// - `vec4` - Homogeneous xyzw vector
+            ///
+            fn extend(&self, w: $var_type) -> $py_vec4_class_name {
+                return <$py_vec4_class_name>::new(self.0.extend(w));
+            }
+            /// Computes the dot product of this vector and `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Dot product
+            ///
+            fn dot(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                return Ok(self.0.dot(rhs));
+            }
+            /// Computes the cross product of this vector and `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - Vector perpendicular to both inputs
+            ///
+            fn cross(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                return Ok($py_class_name::new(self.0.cross(rhs)));
+            }
+
+            /// Squared length of this vector, cheaper than `length` for comparisons
+            fn length_squared(&self) -> $var_type {
+                self.0.length_squared()
+            }
+            /// Euclidean distance between this point and `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Other point, any vec3 or 3 component sequence
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Distance between the points
+            ///
+            fn distance(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok(self.0.distance(rhs))
+            }
+            /// Squared euclidean distance between this point and `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Other point, any vec3 or 3 component sequence
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Squared distance between the points
+            ///
+            fn distance_squared(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok(self.0.distance_squared(rhs))
+            }
+            /// Linear interpolation between this vector and `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Vector at `s = 1`
+            /// - `s` (`float`) - Interpolation factor, values outside `[0, 1]` extrapolate
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - `self + (rhs - self) * s`
+            ///
+            fn lerp(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+                s: $var_type,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.lerp(rhs, s)))
+            }
+            /// Point halfway between this point and `rhs`
+            fn midpoint(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.midpoint(rhs)))
+            }
+            /// Vector projection of this vector onto `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Vector to project onto, the result is NaN if it is zero
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - Component of this vector parallel to `rhs`
+            ///
+            fn project_onto(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.project_onto(rhs)))
+            }
+            /// Vector rejection of this vector from `rhs`
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Vector to reject from, the result is NaN if it is zero
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - Component of this vector perpendicular to `rhs`
+            ///
+            fn reject_from(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.reject_from(rhs)))
+            }
+            /// Reflects this direction off a surface
+            ///
+            /// # Arguments
+            ///
+            /// - `normal` (`Vec3Like`) - Surface normal, must be normalized
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - Reflected direction
+            ///
+            fn reflect(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] normal: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let normal = <$glam_class_name>::from(normal.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.reflect(normal)))
+            }
+            /// Refracts this direction through a surface, this vector must be normalized
+            ///
+            /// # Arguments
+            ///
+            /// - `normal` (`Vec3Like`) - Surface normal, must be normalized
+            /// - `eta` (`float`) - Ratio of the refractive indices, incident over transmitted
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - Refracted direction, zero on total internal reflection
+            ///
+            fn refract(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] normal: Bound<'_, PyAny>,
+                eta: $var_type,
+            ) -> PyResult<$py_class_name> {
+                let normal = <$glam_class_name>::from(normal.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.refract(normal, eta)))
+            }
+            /// Angle in radians between this vector and `rhs`, neither needs to be normalized
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like`) - Other vector, any vec3 or 3 component sequence
+            ///
+            /// # Returns
+            ///
+            /// - `float` - Angle in `[0, pi]`
+            ///
+            fn angle_between(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$var_type> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok(self.0.angle_between(rhs))
+            }
+            /// Scales this vector so its length lies within `[min, max]`
+            ///
+            /// # Arguments
+            ///
+            /// - `min` (`float`) - Minimum length
+            /// - `max` (`float`) - Maximum length
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<vec3>` - Clamped vector, raises ValueError if `min > max`
+            ///
+            fn clamp_length(&self, min: $var_type, max: $var_type) -> PyResult<$py_class_name> {
+                if min > max {
+                    return Err(PyValueError::new_err("min must be less than or equal to max"));
                 }
+                Ok($py_class_name::new(self.0.clamp_length(min, max)))
             }
-            fn cross(&self, rhs: Bound<'_, PyAny>) -> PyResult<$py_class_name> {
-                match rhs.extract::<Vec3VecOpsEnum>() {
-                    Ok(Vec3VecOpsEnum::DVec3(vec)) => {
-                        return Ok($py_class_name::new(self.0.cross(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type))));
-                    }
-                    #[cfg(feature = "f32")]
-                    Ok(Vec3VecOpsEnum::Vec3(vec)) => {
-                        return Ok($py_class_name::new(self.0.cross(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type))));
-                    }
-                    #[cfg(feature = "py-ref")]
-                    Ok(Vec3VecOpsEnum::DVec3Ref(vec_ref)) => {
-                        let vec = vec_ref.get_inner_ref()?;
-                        return Ok($py_class_name::new(self.0.cross(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type))));
-                    }
-                    Err(e) => {
-                        return Err(PyNotImplementedError::new_err(e));
-                    }
+            /// Normalize this vector, or return zero if it can't be normalized
+            fn normalize_or_zero(&self) -> $py_class_name {
+                $py_class_name::new(self.0.normalize_or_zero())
+            }
+            /// Normalize this vector, or return None if its length is zero, infinite or NaN
+            fn try_normalize(&self) -> Option<$py_class_name> {
+                self.0.try_normalize().map($py_class_name::new)
+            }
+            /// Absolute value of each component
+            fn abs(&self) -> $py_class_name {
+                $py_class_name::new(self.0.abs())
+            }
+            /// Sign of each component, 1.0 for positive values including `+0.0` and -1.0 for negative values including `-0.0`
+            fn signum(&self) -> $py_class_name {
+                $py_class_name::new(self.0.signum())
+            }
+            /// Componentwise minimum of this vector and `rhs`
+            fn min(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.min(rhs)))
+            }
+            /// Componentwise maximum of this vector and `rhs`
+            fn max(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] rhs: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let rhs = <$glam_class_name>::from(rhs.extract::<Vec3Like>()?);
+                Ok($py_class_name::new(self.0.max(rhs)))
+            }
+            /// Clamps each component between the matching components of `min` and `max`
+            ///
+            /// # Arguments
+            ///
+            /// - `min` (`Vec3Like`) - Lower bound per component
+            /// - `max` (`Vec3Like`) - Upper bound per component
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<vec3>` - Clamped vector, raises ValueError if any `min` component exceeds `max`
+            ///
+            fn clamp(
+                &self,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] min: Bound<'_, PyAny>,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] max: Bound<'_, PyAny>,
+            ) -> PyResult<$py_class_name> {
+                let min = <$glam_class_name>::from(min.extract::<Vec3Like>()?);
+                let max = <$glam_class_name>::from(max.extract::<Vec3Like>()?);
+                if min.cmpgt(max).any() {
+                    return Err(PyValueError::new_err("min must be less than or equal to max"));
                 }
+                Ok($py_class_name::new(self.0.clamp(min, max)))
+            }
+            /// Sum of the components
+            fn element_sum(&self) -> $var_type {
+                self.0.element_sum()
+            }
+            /// Product of the components
+            fn element_product(&self) -> $var_type {
+                self.0.element_product()
+            }
+            /// Largest component
+            fn max_element(&self) -> $var_type {
+                self.0.max_element()
+            }
+            /// Smallest component
+            fn min_element(&self) -> $var_type {
+                self.0.min_element()
+            }
+            /// Whether every component is neither infinite nor NaN
+            fn is_finite(&self) -> bool {
+                self.0.is_finite()
+            }
+            /// Whether any component is NaN
+            fn is_nan(&self) -> bool {
+                self.0.is_nan()
+            }
+            /// Two unit vectors perpendicular to this vector and to each other, this vector must be normalized
+            ///
+            /// # Returns
+            ///
+            /// - `(vec3, vec3)` - Orthonormal pair completing a right handed basis with this vector
+            ///
+            fn any_orthonormal_pair(&self) -> ($py_class_name, $py_class_name) {
+                let (a, b) = self.0.any_orthonormal_pair();
+                ($py_class_name::new(a), $py_class_name::new(b))
             }
-        }
 
-        macro_rules! into_glam {
-            ($a:ty, $b:ty) => {
-                impl Into<$a> for $b {
-                    fn into(self) -> $a {
-                        self.0
-                    }
-                }
-            };
+            /// Pickle support, restored from a version tagged list of the components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_array(py_value::unpack_state(&state)?)))
+            }
+            /// Copy this vector
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
         }
-        into_glam!($glam_class_name, $py_class_name);
-        into_glam!($glam_class_name, &$py_class_name);
+
         macro_rules! from_glam {
             ($a:ty, $b:ty) => {
                 impl From<$a> for $b {
@@ -483,6 +1189,16 @@ macro_rules! vec3_glam_wrapper {
         }
         from_glam!($glam_class_name, $py_class_name);
         from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
 
         impl Deref for $py_class_name {
             type Target = $glam_class_name;
@@ -592,9 +1308,24 @@ macro_rules! vec3_glam_wrapper {
         quat_ops_with_glam!(&$py_class_name, &$glam_quat_class_name);
     }
 }
-vec3_glam_wrapper!(DVec3, glam::DVec3, glam::DQuat, f64);
+with_vec3_swizzles!(vec3_glam_wrapper!(DVec3, vec2::DVec2, vec4::DVec4, glam::DVec3, glam::DQuat, f64, Vec3Like));
+#[cfg(feature = "f32")]
+with_vec3_swizzles!(vec3_glam_wrapper!(Vec3, vec2::Vec2, vec4::Vec4, glam::Vec3, glam::Quat, f32, py_value::F32Operand<Vec3Like, DVec3>));
+
+/// Widens every component to f64
 #[cfg(feature = "f32")]
-vec3_glam_wrapper!(Vec3, glam::Vec3, glam::Quat, f32);
+impl From<Vec3> for DVec3 {
+    fn from(value: Vec3) -> Self {
+        Self(value.0.as_dvec3())
+    }
+}
+/// Rounds every component to the nearest f32
+#[cfg(feature = "f32")]
+impl From<DVec3> for Vec3 {
+    fn from(value: DVec3) -> Self {
+        Self(value.0.as_vec3())
+    }
+}
 
 /// Creates a 3-dimensional f64 vector
 #[inline(always)]
@@ -613,6 +1344,7 @@ pub fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
 #[cfg(test)]
 mod test_vec3 {
     use super::*;
+    use rand::{SeedableRng, rngs::StdRng};
 
     mod test_dvec3 {
         use super::*;
@@ -643,6 +1375,14 @@ mod test_vec3 {
             assert_eq!(actual.x, 10.);
         }
 
+        #[cfg(feature = "f32")]
+        #[test]
+        fn test_precision_conversion() {
+            let actual = DVec3::from(vec3(0.5, 1., 2.));
+            assert_eq!(actual.0, glam::dvec3(0.5, 1., 2.));
+            assert_eq!(Vec3::from(dvec3(0.1, 0., 0.)).x, 0.1f32);
+        }
+
         #[test]
         fn test_add() {
             let actual = dvec3(10., 10., 10.) + glam::dvec3(10., 10., 10.);
@@ -663,5 +1403,23 @@ mod test_vec3 {
             let actual = dvec3(10., 10., 10.) / glam::dvec3(10., 10., 10.);
             assert_eq!(actual.x, 1.);
         }
+
+        #[test]
+        fn test_sample_uv_covers_sphere() {
+            let mut rng = StdRng::seed_from_u64(7);
+            let samples: Vec<DVec3> = (0..4000).map(|_| DVec3::sample_uv(&mut rng)).collect();
+            assert!(samples.iter().all(|v| (v.length() - 1.).abs() < 1e-12));
+            let mean = samples.iter().fold(glam::DVec3::ZERO, |acc, v| acc + v.0) / samples.len() as f64;
+            assert!(mean.length() < 0.05);
+        }
+        #[test]
+        fn test_sample_in_cone() {
+            let mut rng = StdRng::seed_from_u64(7);
+            let axis = glam::DVec3::new(1., 2., 3.).normalize();
+            for _ in 0..1000 {
+                let actual = DVec3::sample_in_cone(&mut rng, axis, 0.1);
+                assert!(actual.0.angle_between(axis) <= 0.1 + 1e-9);
+            }
+        }
     }
 }
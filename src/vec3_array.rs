@@ -0,0 +1,545 @@
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::vec3;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "numpy")]
+use numpy::{AllowTypeChange, IntoPyArray, PyArrayLike2, PyArrayMethods, PyUntypedArray};
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBytes, PySlice, PyTuple},
+};
+use std::ops::{Deref, DerefMut};
+
+/// Supported operands for elementwise operations on vec3 arrays
+/// array + Some
+///
+/// `A` is the array class itself, arrays of the other precision don't extract.
+/// `V` is the vector operand, f32 arrays refuse DVec3 operands instead of truncating them
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Vec3ArrayOpsEnum<'py, A: pyo3::PyClass, V> {
+    Float(f64),
+    Int(i64),
+    Array(PyRef<'py, A>),
+    Vec3(V),
+}
+
+macro_rules! vec3_array_wrapper {
+    ($py_class_name: ident, $py_vec_class_name: ty, $glam_vec_class_name: ty, $var_type: ty, $vec_operand: ty) => {
+        /// Contiguous array of 3 component vectors for vectorised operations
+        ///
+        /// Arithmetic doesn't mix precisions, f32 arrays refuse DVec3 operands and arrays only combine with
+        /// arrays of the same precision. Convert with `vec3_array(array, dtype=...)` first
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct $py_class_name(Vec<$glam_vec_class_name>);
+
+        impl $py_class_name {
+            pub fn new(vecs: Vec<$glam_vec_class_name>) -> Self {
+                Self(vecs)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Applies `op` between each element and a scalar, vector or same length array operand
+            ///
+            /// Scalars are splatted to all components, which only makes sense for arithmetic
+            fn zip_op<T>(
+                &self,
+                rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+                allow_scalar: bool,
+                op: impl Fn($glam_vec_class_name, $glam_vec_class_name) -> T,
+            ) -> PyResult<Vec<T>> {
+                let splat = match rhs {
+                    Vec3ArrayOpsEnum::Float(scalar) if allow_scalar => {
+                        <$glam_vec_class_name>::splat(scalar as $var_type)
+                    }
+                    Vec3ArrayOpsEnum::Int(i) if allow_scalar => <$glam_vec_class_name>::splat(i as $var_type),
+                    Vec3ArrayOpsEnum::Float(_) | Vec3ArrayOpsEnum::Int(_) => {
+                        return Err(PyTypeError::new_err("Expected a vec3 or vec3 array operand, got a scalar"));
+                    }
+                    Vec3ArrayOpsEnum::Vec3(vec) => <$glam_vec_class_name>::from(vec),
+                    Vec3ArrayOpsEnum::Array(other) => {
+                        if other.0.len() != self.0.len() {
+                            return Err(PyValueError::new_err(format!(
+                                "Array lengths don't match: {} and {}",
+                                self.0.len(),
+                                other.0.len()
+                            )));
+                        }
+                        return Ok(self.0.iter().zip(other.0.iter()).map(|(a, b)| op(*a, *b)).collect());
+                    }
+                };
+                Ok(self.0.iter().map(|a| op(*a, splat)).collect())
+            }
+
+            /// Folds the elements with `op`, raising ValueError for an empty array
+            fn reduce(
+                &self,
+                op: impl Fn($glam_vec_class_name, $glam_vec_class_name) -> $glam_vec_class_name,
+            ) -> PyResult<$glam_vec_class_name> {
+                self.0
+                    .iter()
+                    .copied()
+                    .reduce(op)
+                    .ok_or_else(|| PyValueError::new_err("Reduction of an empty array"))
+            }
+
+            /// Copies the rows of an array with shape `(N, 3)`
+            #[cfg(feature = "numpy")]
+            fn from_array(array: PyArrayLike2<'_, $var_type, AllowTypeChange>) -> PyResult<Self> {
+                let rows = array.as_array();
+                if rows.ncols() != 3 {
+                    return Err(PyValueError::new_err(format!(
+                        "expected an array with shape (N, 3), got {:?}",
+                        rows.shape()
+                    )));
+                }
+                Ok(Self(
+                    rows.rows()
+                        .into_iter()
+                        .map(|row| <$glam_vec_class_name>::new(row[0], row[1], row[2]))
+                        .collect(),
+                ))
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!($py_class_name, $var_type);
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            /// Create an array from an iterable of vectors
+            ///
+            /// # Arguments
+            ///
+            /// - `vecs` (`Iterable[Vec3Like] | None`) - Vectors or 3 component sequences, or an array with shape `(N, 3)`, empty if not given
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Array holding a copy of each vector
+            ///
+            #[new]
+            #[pyo3(signature = (vecs=None))]
+            pub fn py_new(vecs: Option<Bound<'_, PyAny>>) -> PyResult<Self> {
+                let Some(vecs) = vecs else {
+                    return Ok(Self::default());
+                };
+                #[cfg(feature = "numpy")]
+                if let Some(array) = py_value::as_ndarray(&vecs) {
+                    return Self::from_array(array.extract()?);
+                }
+                let mut inner = Vec::with_capacity(vecs.len().unwrap_or(0));
+                for vec in vecs.try_iter()? {
+                    inner.push(<$glam_vec_class_name>::from(vec?.extract::<vec3::Vec3Like>()?));
+                }
+                Ok(Self(inner))
+            }
+            /// Create an array of `len` zero vectors
+            #[staticmethod]
+            pub fn zeros(len: usize) -> Self {
+                Self(vec![<$glam_vec_class_name>::ZERO; len])
+            }
+
+            /// Create an array from a numpy array with shape `(N, 3)`
+            ///
+            /// # Arguments
+            ///
+            /// - `array` (`numpy.typing.ArrayLike`) - 2-D array of any float or integer dtype
+            /// - `dtype` (`str | numpy.dtype | None`) - Precision of the new array, `'float32'` or `'float64'`, defaults to this type's precision
+            ///
+            /// # Returns
+            ///
+            /// - `DVec3Array | Vec3Array` - Array holding a copy of each row
+            ///
+            #[cfg(feature = "numpy")]
+            #[staticmethod]
+            #[pyo3(signature = (array, dtype=None))]
+            #[gen_stub(override_return_type(type_repr = "typing.Union[DVec3Array, Vec3Array]", imports = ("typing")))]
+            fn from_numpy<'py>(
+                #[gen_stub(override_type(type_repr = "numpy.typing.ArrayLike", imports = ("numpy.typing")))] array: &Bound<'py, PyAny>,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                from_numpy_as(array, dtype.unwrap_or(<$var_type as py_value::Component>::DTYPE))
+            }
+            /// Copy this array into a numpy array with shape `(N, 3)`
+            ///
+            /// # Arguments
+            ///
+            /// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to this type's precision
+            ///
+            /// # Returns
+            ///
+            /// - `numpy.ndarray` - New array holding the components of every vector
+            ///
+            #[cfg(feature = "numpy")]
+            #[pyo3(signature = (dtype=None))]
+            fn to_numpy<'py>(
+                &self,
+                py: Python<'py>,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+            ) -> PyResult<Bound<'py, PyUntypedArray>> {
+                let components = self.0.iter().flat_map(|vec| vec.to_array());
+                let shape = [self.0.len(), 3];
+                Ok(match dtype.unwrap_or(<$var_type as py_value::Component>::DTYPE) {
+                    py_value::Dtype::Float64 => {
+                        let flat: Vec<f64> = components.map(|c| c as f64).collect();
+                        flat.into_pyarray(py).reshape(shape)?.as_untyped().clone()
+                    }
+                    py_value::Dtype::Float32 => {
+                        let flat: Vec<f32> = components.map(|c| c as f32).collect();
+                        flat.into_pyarray(py).reshape(shape)?.as_untyped().clone()
+                    }
+                })
+            }
+            /// Copy this array into a numpy array, numpy's `__array__` protocol
+            ///
+            /// The storage can't be shared, so `copy=False` raises ValueError
+            #[cfg(feature = "numpy")]
+            #[pyo3(signature = (dtype=None, copy=None))]
+            fn __array__<'py>(
+                &self,
+                py: Python<'py>,
+                dtype: Option<Bound<'py, PyAny>>,
+                copy: Option<bool>,
+            ) -> PyResult<Bound<'py, PyAny>> {
+                if copy == Some(false) {
+                    return Err(PyValueError::new_err(
+                        "Unable to avoid a copy while creating an array from a vec3 array",
+                    ));
+                }
+                let array = self.to_numpy(py, None)?.into_any();
+                match dtype {
+                    Some(dtype) => array.call_method1("astype", (dtype,)),
+                    None => Ok(array),
+                }
+            }
+            /// Copy this array into a list of vectors
+            fn to_list(&self) -> Vec<$py_vec_class_name> {
+                self.0.iter().map(|vec| <$py_vec_class_name>::new(*vec)).collect()
+            }
+
+            fn __repr__(&self) -> String {
+                let vecs: Vec<String> = self
+                    .0
+                    .iter()
+                    .map(|vec| {
+                        format!(
+                            "({}, {}, {})",
+                            py_value::float_repr(vec.x),
+                            py_value::float_repr(vec.y),
+                            py_value::float_repr(vec.z)
+                        )
+                    })
+                    .collect();
+                format!("{}([{}])", stringify!($py_class_name), vecs.join(", "))
+            }
+            fn __len__(&self) -> usize {
+                self.0.len()
+            }
+            /// Get a vector by index, or a new array by slice
+            fn __getitem__(&self, index: Bound<'_, PyAny>) -> PyResult<Either<$py_vec_class_name, $py_class_name>> {
+                if let Ok(slice) = index.cast::<PySlice>() {
+                    let indices = slice.indices(self.0.len() as isize)?;
+                    let mut selected = Vec::with_capacity(indices.slicelength);
+                    let mut i = indices.start;
+                    for _ in 0..indices.slicelength {
+                        selected.push(self.0[i as usize]);
+                        i += indices.step;
+                    }
+                    return Ok(Either::Right(Self(selected)));
+                }
+                let i = py_value::normalize_index(index.extract::<isize>()?, self.0.len())?;
+                Ok(Either::Left(<$py_vec_class_name>::new(self.0[i])))
+            }
+            /// Set a vector by index, raises IndexError if out of range
+            fn __setitem__(
+                &mut self,
+                index: isize,
+                #[gen_stub(override_type(type_repr = "Vec3Like", imports = ()))] value: Bound<'_, PyAny>,
+            ) -> PyResult<()> {
+                let i = py_value::normalize_index(index, self.0.len())?;
+                self.0[i] = <$glam_vec_class_name>::from(value.extract::<vec3::Vec3Like>()?);
+                Ok(())
+            }
+
+            fn __add__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this + rhs
+                Ok(Self(self.zip_op(rhs, true, |a, b| a + b)?))
+            }
+            fn __radd__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs + this
+                Ok(Self(self.zip_op(lhs, true, |a, b| b + a)?))
+            }
+            fn __sub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this - rhs
+                Ok(Self(self.zip_op(rhs, true, |a, b| a - b)?))
+            }
+            fn __rsub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs - this
+                Ok(Self(self.zip_op(lhs, true, |a, b| b - a)?))
+            }
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this * rhs
+                Ok(Self(self.zip_op(rhs, true, |a, b| a * b)?))
+            }
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs * this
+                Ok(Self(self.zip_op(lhs, true, |a, b| b * a)?))
+            }
+            fn __truediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this / rhs
+                Ok(Self(self.zip_op(rhs, true, |a, b| a / b)?))
+            }
+            fn __rtruediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] lhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs / this
+                Ok(Self(self.zip_op(lhs, true, |a, b| b / a)?))
+            }
+            fn __neg__(&self) -> $py_class_name {
+                Self(self.0.iter().map(|vec| -*vec).collect())
+            }
+
+            /// Elementwise dot product with a vector or a same length array
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like | vec3 array`) - Vector applied to every element, or one vector per element
+            ///
+            /// # Returns
+            ///
+            /// - `list[float]` - Dot product per element
+            ///
+            fn dot(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<Vec<$var_type>> {
+                self.zip_op(rhs, false, |a, b| a.dot(b))
+            }
+            /// Elementwise cross product with a vector or a same length array
+            ///
+            /// # Arguments
+            ///
+            /// - `rhs` (`Vec3Like | vec3 array`) - Vector applied to every element, or one vector per element
+            ///
+            /// # Returns
+            ///
+            /// - `vec3 array` - Cross product per element
+            ///
+            fn cross(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] rhs: Vec3ArrayOpsEnum<'_, $py_class_name, $vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                Ok(Self(self.zip_op(rhs, false, |a, b| a.cross(b))?))
+            }
+            /// Length of each vector
+            fn length(&self) -> Vec<$var_type> {
+                self.0.iter().map(|vec| vec.length()).collect()
+            }
+            /// Normalize each vector to unit length
+            fn normalize(&self) -> $py_class_name {
+                Self(self.0.iter().map(|vec| vec.normalize()).collect())
+            }
+
+            /// Sum of all vectors, zero for an empty array
+            fn sum(&self) -> $py_vec_class_name {
+                <$py_vec_class_name>::new(self.0.iter().sum())
+            }
+            /// Mean of all vectors, raises ValueError for an empty array
+            fn mean(&self) -> PyResult<$py_vec_class_name> {
+                let sum = self.reduce(|a, b| a + b)?;
+                Ok(<$py_vec_class_name>::new(sum / self.0.len() as $var_type))
+            }
+            /// Componentwise minimum of all vectors, raises ValueError for an empty array
+            fn min(&self) -> PyResult<$py_vec_class_name> {
+                Ok(<$py_vec_class_name>::new(self.reduce(|a, b| a.min(b))?))
+            }
+            /// Componentwise maximum of all vectors, raises ValueError for an empty array
+            fn max(&self) -> PyResult<$py_vec_class_name> {
+                Ok(<$py_vec_class_name>::new(self.reduce(|a, b| a.max(b))?))
+            }
+
+            /// Pickle support, restored from a version tagged buffer of little endian xyz components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                let data: Vec<u8> = self
+                    .0
+                    .iter()
+                    .flat_map(|vec| vec.to_array())
+                    .flat_map(<$var_type>::to_le_bytes)
+                    .collect();
+                py_value::reduce::<$py_class_name>(py, PyBytes::new(py, &data))
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                let data: Vec<u8> = py_value::unpack_state(&state)?;
+                let size = std::mem::size_of::<$var_type>();
+                if data.len() % (3 * size) != 0 {
+                    return Err(PyValueError::new_err(format!(
+                        "pickled vec3 array data must be a multiple of {} bytes, got {}",
+                        3 * size,
+                        data.len()
+                    )));
+                }
+                let values: Vec<$var_type> = data
+                    .chunks_exact(size)
+                    .map(|bytes| <$var_type>::from_le_bytes(bytes.try_into().unwrap()))
+                    .collect();
+                Ok(values
+                    .chunks_exact(3)
+                    .map(|c| <$glam_vec_class_name>::from_slice(c))
+                    .collect())
+            }
+            fn __copy__(&self) -> Self {
+                self.clone()
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                self.clone()
+            }
+        }
+
+        impl From<Vec<$glam_vec_class_name>> for $py_class_name {
+            fn from(value: Vec<$glam_vec_class_name>) -> Self {
+                Self(value)
+            }
+        }
+        impl From<$py_class_name> for Vec<$glam_vec_class_name> {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl FromIterator<$glam_vec_class_name> for $py_class_name {
+            fn from_iter<I: IntoIterator<Item = $glam_vec_class_name>>(iter: I) -> Self {
+                Self(iter.into_iter().collect())
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = Vec<$glam_vec_class_name>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+    };
+}
+vec3_array_wrapper!(DVec3Array, vec3::DVec3, glam::DVec3, f64, vec3::Vec3Like);
+#[cfg(feature = "f32")]
+vec3_array_wrapper!(
+    Vec3Array,
+    vec3::Vec3,
+    glam::Vec3,
+    f32,
+    py_value::F32Operand<vec3::Vec3Like, vec3::DVec3>
+);
+
+/// Creates a vec3 array of the requested precision
+///
+/// # Arguments
+///
+/// - `vecs` (`Iterable[Vec3Like] | None`) - Vectors or 3 component sequences, or an array with shape `(N, 3)`, empty if not given
+/// - `dtype` (`str | numpy.dtype | None`) - `'float32'` or `'float64'`, defaults to the dtype of `vecs` if it has one, e.g. a numpy array, and f64 otherwise
+///
+/// # Returns
+///
+/// - `DVec3Array | Vec3Array` - Array holding a copy of each vector
+///
+#[cfg(feature = "pyo3")]
+#[pyo3_stub_gen::derive::gen_stub_pyfunction]
+#[pyfunction]
+#[pyo3(signature = (vecs=None, dtype=None))]
+#[gen_stub(override_return_type(type_repr = "typing.Union[DVec3Array, Vec3Array]", imports = ("typing")))]
+pub(crate) fn vec3_array<'py>(
+    py: Python<'py>,
+    vecs: Option<Bound<'py, PyAny>>,
+    #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] dtype: Option<py_value::Dtype>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let dtype = dtype
+        .or_else(|| vecs.as_ref().and_then(py_value::Dtype::of))
+        .unwrap_or(py_value::Dtype::Float64);
+    match dtype {
+        py_value::Dtype::Float64 => Ok(DVec3Array::py_new(vecs)?.into_pyobject(py)?.into_any()),
+        #[cfg(feature = "f32")]
+        py_value::Dtype::Float32 => Ok(Vec3Array::py_new(vecs)?.into_pyobject(py)?.into_any()),
+        #[cfg(not(feature = "f32"))]
+        py_value::Dtype::Float32 => Err(PyValueError::new_err("pyglam was built without the f32 feature")),
+    }
+}
+
+/// Reads an array with shape `(N, 3)` into a vec3 array of precision `dtype`
+#[cfg(feature = "numpy")]
+fn from_numpy_as<'py>(array: &Bound<'py, PyAny>, dtype: py_value::Dtype) -> PyResult<Bound<'py, PyAny>> {
+    let py = array.py();
+    match dtype {
+        py_value::Dtype::Float64 => Ok(DVec3Array::from_array(array.extract()?)?.into_pyobject(py)?.into_any()),
+        #[cfg(feature = "f32")]
+        py_value::Dtype::Float32 => Ok(Vec3Array::from_array(array.extract()?)?.into_pyobject(py)?.into_any()),
+        #[cfg(not(feature = "f32"))]
+        py_value::Dtype::Float32 => Err(PyValueError::new_err("pyglam was built without the f32 feature")),
+    }
+}
+
+#[cfg(test)]
+mod test_vec3_array {
+    use super::*;
+
+    mod test_dvec3_array {
+        use super::*;
+
+        #[test]
+        fn test_from_iter() {
+            let actual: DVec3Array = (0..3).map(|i| glam::DVec3::splat(i as f64)).collect();
+            assert_eq!(actual.len(), 3);
+            assert_eq!(actual[2], glam::DVec3::splat(2.));
+        }
+
+        #[test]
+        fn test_into_vec() {
+            let array = DVec3Array::new(vec![glam::DVec3::X, glam::DVec3::Y]);
+            let actual: Vec<glam::DVec3> = array.into();
+            assert_eq!(actual, vec![glam::DVec3::X, glam::DVec3::Y]);
+        }
+    }
+}
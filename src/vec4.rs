@@ -0,0 +1,592 @@
+#[cfg(feature = "pyo3")]
+use crate::errors::NormalizationError;
+#[cfg(feature = "pyo3")]
+use crate::py_value;
+#[cfg(feature = "pyo3")]
+use crate::vec3;
+#[cfg(feature = "pyo3")]
+use either::Either;
+use glam;
+#[cfg(feature = "pyo3")]
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyIterator, PyTuple},
+};
+use std::ops::{Add, Deref, DerefMut, Div, Mul, Sub};
+
+/// Supported types for arithmetic operations on vecs
+/// vec4 * Some
+///
+/// `V` is the vector operand, f32 vecs refuse DVec4 operands so that mixed precision promotes to f64
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Vec4ScaleOpsEnum<V> {
+    Float(f64),
+    Int(i64),
+    Vec4(V),
+}
+
+/// Supported types for vector operations on other vecs where scalars don't make sense
+/// example: dot
+#[cfg(feature = "pyo3")]
+#[derive(FromPyObject)]
+enum Vec4VecOpsEnum {
+    DVec4(DVec4),
+    #[cfg(feature = "f32")]
+    Vec4(Vec4),
+    #[cfg(feature = "py-ref")]
+    DVec4Ref(DVec4Ref),
+}
+
+#[cfg(all(feature = "pyo3", feature = "f32"))]
+impl From<py_value::F32Operand<Vec4VecOpsEnum, DVec4>> for Vec4VecOpsEnum {
+    fn from(value: py_value::F32Operand<Vec4VecOpsEnum, DVec4>) -> Self {
+        value.0
+    }
+}
+
+macro_rules! vec4_glam_wrapper {
+    ($py_class_name: ident, $py_vec3_class_name: ty, $glam_class_name: ty, $var_type: ty, $vec_operand: ty) => {
+        /// 4 Component vector xyzw
+        ///
+        /// Arithmetic mixing f32 and f64 operands returns f64, plain numbers and sequences take this type's precision
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pyclass, pyclass(module = "pyglam"))]
+        #[cfg_attr(feature = "py-ref", derive(simple_py_bevy::PyStructRef))]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Deserialize, serde::Serialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(feature = "bevy", derive(bevy::reflect::Reflect), reflect(Clone))]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        pub struct $py_class_name($glam_class_name);
+
+        impl $py_class_name {
+            pub fn new(vec: $glam_class_name) -> Self {
+                Self(vec)
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        impl $py_class_name {
+            /// Converts a scalar or vec operand into this glam vector type
+            fn scale_op(rhs: Vec4ScaleOpsEnum<$vec_operand>) -> PyResult<$glam_class_name> {
+                match rhs {
+                    Vec4ScaleOpsEnum::Float(scalar) => Ok(<$glam_class_name>::splat(scalar as $var_type)),
+                    Vec4ScaleOpsEnum::Int(i) => Ok(<$glam_class_name>::splat(i as $var_type)),
+                    Vec4ScaleOpsEnum::Vec4(vec) => Self::vec_op(vec.into()),
+                }
+            }
+            /// Converts a vec operand into this glam vector type
+            fn vec_op(rhs: Vec4VecOpsEnum) -> PyResult<$glam_class_name> {
+                match rhs {
+                    Vec4VecOpsEnum::DVec4(vec) => Ok(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type, vec.w as $var_type)),
+                    #[cfg(feature = "f32")]
+                    Vec4VecOpsEnum::Vec4(vec) => Ok(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type, vec.w as $var_type)),
+                    #[cfg(feature = "py-ref")]
+                    Vec4VecOpsEnum::DVec4Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        Ok(<$glam_class_name>::new(vec.x as $var_type, vec.y as $var_type, vec.z as $var_type, vec.w as $var_type))
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "pyo3")]
+        py_value::dtype_getter!(
+            #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+            $py_class_name,
+            $var_type
+        );
+
+        #[cfg(feature = "pyo3")]
+        #[cfg_attr(feature = "py-ref", simple_py_bevy::py_ref_methods)]
+        #[cfg_attr(feature = "pyo3", pyo3_stub_gen::derive::gen_stub_pymethods, pymethods)]
+        impl $py_class_name {
+            #[new]
+            #[pyo3(signature = (x, y=None, z=None, w=None))]
+            pub fn py_new(
+                x: $var_type,
+                y: Option<$var_type>,
+                z: Option<$var_type>,
+                w: Option<$var_type>,
+            ) -> PyResult<Self> {
+                let num_set = [y, z, w].iter().filter(|v| v.is_some()).count();
+                if num_set != 0 && num_set != 3 {
+                    return Err(PyValueError::new_err(
+                        "Either set all values DVec4(1.0, 1.0, 1.0, 1.0) or only first value to set xyzw to same value: DVec4(1.0)",
+                    ));
+                }
+
+                let inner = <$glam_class_name>::new(x, y.unwrap_or(x), z.unwrap_or(x), w.unwrap_or(x));
+                Ok($py_class_name(inner))
+            }
+
+            #[getter]
+            fn get_x(&self) -> $var_type {
+                return self.0.x;
+            }
+            #[getter]
+            fn get_y(&self) -> $var_type {
+                return self.0.y;
+            }
+            #[getter]
+            fn get_z(&self) -> $var_type {
+                return self.0.z;
+            }
+            #[getter]
+            fn get_w(&self) -> $var_type {
+                return self.0.w;
+            }
+            #[setter]
+            fn set_x(&mut self, x: $var_type) {
+                self.0.x = x;
+            }
+            #[setter]
+            fn set_y(&mut self, y: $var_type) {
+                self.0.y = y;
+            }
+            #[setter]
+            fn set_z(&mut self, z: $var_type) {
+                self.0.z = z;
+            }
+            #[setter]
+            fn set_w(&mut self, w: $var_type) {
+                self.0.w = w;
+            }
+
+            /// Convert this vector to a 4 component tuple
+            ///
+            /// # Returns
+            ///
+            /// - `(float, float, float, float)` - XYZW tuple
+            ///
+            #[allow(clippy::wrong_self_convention)]
+            fn to_tuple(&self) -> ($var_type, $var_type, $var_type, $var_type) {
+                (self.x, self.y, self.z, self.w)
+            }
+            /// Convert this vector to f64 precision
+            ///
+            /// # Returns
+            ///
+            /// - `DVec4` - Copy with every component widened to f64
+            ///
+            fn as_dvec4(&self) -> DVec4 {
+                DVec4::from(*self)
+            }
+            /// Convert this vector to f32 precision
+            ///
+            /// # Returns
+            ///
+            /// - `Vec4` - Copy with every component rounded to the nearest f32
+            ///
+            #[cfg(feature = "f32")]
+            fn as_vec4(&self) -> Vec4 {
+                Vec4::from(*self)
+            }
+
+            fn __repr__(&self) -> String {
+                format!(
+                    "{}({}, {}, {}, {})",
+                    stringify!($py_class_name),
+                    py_value::float_repr(self.0.x),
+                    py_value::float_repr(self.0.y),
+                    py_value::float_repr(self.0.z),
+                    py_value::float_repr(self.0.w),
+                )
+            }
+            fn __str__(&self) -> String {
+                format!("{}", self.0)
+            }
+            /// Component-wise value equality with any vec4, regardless of precision
+            ///
+            /// Follows float semantics, so a vector containing NaN is never equal to anything.
+            /// Other types, including plain sequences, return NotImplemented so `__hash__` stays consistent
+            fn __eq__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Any", imports = ("typing")))] other: Vec4VecOpsEnum,
+            ) -> PyResult<bool> {
+                let this = glam::DVec4::new(self.0.x as f64, self.0.y as f64, self.0.z as f64, self.0.w as f64);
+                match other {
+                    Vec4VecOpsEnum::DVec4(vec) => {
+                        return Ok(this == *vec);
+                    }
+                    #[cfg(feature = "f32")]
+                    Vec4VecOpsEnum::Vec4(vec) => {
+                        return Ok(this == vec.0.as_dvec4());
+                    }
+                    #[cfg(feature = "py-ref")]
+                    Vec4VecOpsEnum::DVec4Ref(vec_ref) => {
+                        let vec = vec_ref.get_inner_ref()?;
+                        return Ok(this == *vec);
+                    }
+                }
+            }
+            /// Hash consistent with `__eq__`, `-0.0` and `0.0` hash the same
+            fn __hash__(&self) -> u64 {
+                py_value::hash_floats(&[self.0.x as f64, self.0.y as f64, self.0.z as f64, self.0.w as f64])
+            }
+
+            fn __len__(&self) -> usize {
+                4
+            }
+            /// Get a component by index, or a tuple of components by slice
+            fn __getitem__<'py>(
+                &self,
+                index: Bound<'py, PyAny>,
+            ) -> PyResult<Either<$var_type, Bound<'py, PyTuple>>> {
+                py_value::get_item(&[self.0.x, self.0.y, self.0.z, self.0.w], &index)
+            }
+            /// Set a component by index, or a slice of components from a sequence of the same length
+            fn __setitem__(&mut self, index: Bound<'_, PyAny>, value: Bound<'_, PyAny>) -> PyResult<()> {
+                let mut components = self.0.to_array();
+                py_value::set_item(&mut components, &index, &value)?;
+                self.0 = <$glam_class_name>::from_array(components);
+                Ok(())
+            }
+            fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+                PyTuple::new(py, [self.0.x, self.0.y, self.0.z, self.0.w])?.try_iter()
+            }
+            /// Whether any component equals `value`, False for values that aren't numbers
+            fn __contains__(&self, value: Bound<'_, PyAny>) -> bool {
+                value
+                    .extract::<$var_type>()
+                    .is_ok_and(|value| [self.0.x, self.0.y, self.0.z, self.0.w].contains(&value))
+            }
+
+            fn __add__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this + rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 + rhs));
+            }
+            fn __radd__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs + this
+                return self.__add__(lhs);
+            }
+            fn __sub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this - rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 - rhs));
+            }
+            fn __rsub__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs - this
+                let lhs = Self::scale_op(lhs)?;
+                return Ok($py_class_name::new(lhs - self.0));
+            }
+            fn __mul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this * rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 * rhs));
+            }
+            fn __rmul__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs * this
+                return self.__mul__(lhs);
+            }
+            fn __truediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // this / rhs
+                let rhs = Self::scale_op(rhs)?;
+                return Ok($py_class_name::new(self.0 / rhs));
+            }
+            fn __rtruediv__(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] lhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<$py_class_name> {
+                // lhs / this
+                let lhs = Self::scale_op(lhs)?;
+                return Ok($py_class_name::new(lhs / self.0));
+            }
+
+            fn __iadd__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this += rhs
+                self.0 += Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __isub__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this -= rhs
+                self.0 -= Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __imul__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this *= rhs
+                self.0 *= Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __itruediv__(
+                &mut self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4, builtins.float]", imports = ("typing", "builtins")))] rhs: Vec4ScaleOpsEnum<$vec_operand>,
+            ) -> PyResult<()> {
+                // this /= rhs
+                self.0 /= Self::scale_op(rhs)?;
+                return Ok(());
+            }
+            fn __neg__(&self) -> $py_class_name {
+                // -this
+                return $py_class_name::new(-self.0);
+            }
+
+            /// Normalize this vector to unit length
+            ///
+            /// # Returns
+            ///
+            /// - `PyResult<Self>` - Unit vector, raises NormalizationError if the length is zero, infinite or NaN
+            ///
+            fn normalize(&self) -> PyResult<$py_class_name> {
+                self.0
+                    .try_normalize()
+                    .map($py_class_name::new)
+                    .ok_or_else(|| NormalizationError::new_err("Can not normalize a vector with zero, infinite or NaN length"))
+            }
+            fn length(&self) -> $var_type {
+                return self.0.length();
+            }
+            fn dot(
+                &self,
+                #[gen_stub(override_type(type_repr = "typing.Union[DVec4, Vec4]", imports = ("typing")))] rhs: Vec4VecOpsEnum,
+            ) -> PyResult<$var_type> {
+                let rhs = Self::vec_op(rhs)?;
+                return Ok(self.0.dot(rhs));
+            }
+            /// Creates a vec3 from the `x`, `y` and `z` elements of this vector, discarding `w`
+            fn truncate(&self) -> $py_vec3_class_name {
+                return <$py_vec3_class_name>::new(self.0.truncate());
+            }
+            /// Projects this homogeneous coordinate to 3D space by dividing `x`, `y` and `z` by `w`
+            ///
+            /// # Returns
+            ///
+            /// - `vec3` - The projected point. Contains inf/nan when `w` is zero
+            ///
+            fn project_homogeneous(&self) -> $py_vec3_class_name {
+                return <$py_vec3_class_name>::new(self.0.project());
+            }
+
+            /// Pickle support, restored from a version tagged list of the components
+            fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+                py_value::reduce::<$py_class_name>(py, self.0.to_array())
+            }
+            /// Restore a state built by `__reduce__`
+            #[staticmethod]
+            #[gen_stub(skip)]
+            fn _from_state(state: Bound<'_, PyAny>) -> PyResult<Self> {
+                Ok(Self(<$glam_class_name>::from_array(py_value::unpack_state(&state)?)))
+            }
+            /// Copy this vector
+            fn __copy__(&self) -> Self {
+                *self
+            }
+            fn __deepcopy__(&self, _memo: Bound<'_, PyAny>) -> Self {
+                *self
+            }
+        }
+
+        macro_rules! from_glam {
+            ($a:ty, $b:ty) => {
+                impl From<$a> for $b {
+                    fn from(value: $a) -> Self {
+                        Self(value.clone())
+                    }
+                }
+            };
+        }
+        from_glam!($glam_class_name, $py_class_name);
+        from_glam!(&$glam_class_name, $py_class_name);
+        impl From<$py_class_name> for $glam_class_name {
+            fn from(value: $py_class_name) -> Self {
+                value.0
+            }
+        }
+        impl From<&$py_class_name> for $glam_class_name {
+            fn from(value: &$py_class_name) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $py_class_name {
+            type Target = $glam_class_name;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl DerefMut for $py_class_name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        macro_rules! ops_with_self {
+            ($a:ty, $b:ty) => {
+                impl Add<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn add(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 + rhs.0)
+                    }
+                }
+                impl Sub<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn sub(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 - rhs.0)
+                    }
+                }
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs.0)
+                    }
+                }
+                impl Div<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn div(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 / rhs.0)
+                    }
+                }
+            };
+        }
+        ops_with_self!($py_class_name, $py_class_name);
+        ops_with_self!($py_class_name, &$py_class_name);
+        ops_with_self!(&$py_class_name, $py_class_name);
+        ops_with_self!(&$py_class_name, &$py_class_name);
+
+        macro_rules! ops_with_glam {
+            ($a:ty, $b:ty) => {
+                impl Add<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn add(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 + rhs)
+                    }
+                }
+                impl Sub<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn sub(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 - rhs)
+                    }
+                }
+                impl Mul<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn mul(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 * rhs)
+                    }
+                }
+                impl Div<$a> for $b {
+                    type Output = $py_class_name;
+
+                    fn div(self, rhs: $a) -> Self::Output {
+                        $py_class_name(self.0 / rhs)
+                    }
+                }
+            };
+        }
+        ops_with_glam!($glam_class_name, $py_class_name);
+        ops_with_glam!(&$glam_class_name, $py_class_name);
+        ops_with_glam!($glam_class_name, &$py_class_name);
+        ops_with_glam!(&$glam_class_name, &$py_class_name);
+        ops_with_glam!($var_type, $py_class_name);
+        ops_with_glam!(&$var_type, $py_class_name);
+        ops_with_glam!($var_type, &$py_class_name);
+        ops_with_glam!(&$var_type, &$py_class_name);
+    };
+}
+vec4_glam_wrapper!(DVec4, vec3::DVec3, glam::DVec4, f64, Vec4VecOpsEnum);
+#[cfg(feature = "f32")]
+vec4_glam_wrapper!(Vec4, vec3::Vec3, glam::Vec4, f32, py_value::F32Operand<Vec4VecOpsEnum, DVec4>);
+
+/// Widens every component to f64
+#[cfg(feature = "f32")]
+impl From<Vec4> for DVec4 {
+    fn from(value: Vec4) -> Self {
+        Self(value.0.as_dvec4())
+    }
+}
+/// Rounds every component to the nearest f32
+#[cfg(feature = "f32")]
+impl From<DVec4> for Vec4 {
+    fn from(value: DVec4) -> Self {
+        Self(value.0.as_vec4())
+    }
+}
+
+/// Creates a 4-dimensional f64 vector
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn dvec4(x: f64, y: f64, z: f64, w: f64) -> DVec4 {
+    DVec4::new(glam::dvec4(x, y, z, w))
+}
+#[cfg(feature = "f32")]
+/// Creates a 4-dimensional f32 vector
+#[inline(always)]
+#[cfg_attr(feature = "pyo3", pyfunction)]
+pub fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
+    Vec4::new(glam::vec4(x, y, z, w))
+}
+
+#[cfg(test)]
+mod test_vec4 {
+    use super::*;
+
+    mod test_dvec4 {
+        use super::*;
+
+        #[test]
+        fn test_simple_dvec4_api() {
+            let actual = dvec4(10., 10., 10., 1.);
+            assert_eq!(actual.w, 1.);
+        }
+        #[cfg(feature = "f32")]
+        #[test]
+        fn test_simple_vec4_api() {
+            let actual = vec4(10., 10., 10., 1.);
+            assert_eq!(actual.w, 1.);
+        }
+
+        #[test]
+        fn test_add() {
+            let actual = dvec4(10., 10., 10., 10.) + glam::dvec4(10., 10., 10., 10.);
+            assert_eq!(actual.w, 20.);
+        }
+        #[test]
+        fn test_project() {
+            let actual = dvec4(2., 4., 6., 2.).0.project();
+            assert_eq!(actual, glam::dvec3(1., 2., 3.));
+        }
+    }
+}
+
@@ -0,0 +1,17 @@
+import math
+
+import numpy as np
+import pyglam
+
+class TestDAffine3:
+    def test_compose(self):
+        rotate = pyglam.DAffine3(pyglam.DQuat.from_axis_angle(pyglam.DVec3(0., 0., 1.), math.pi / 2), pyglam.DVec3(0.))
+        translate = pyglam.DAffine3.from_rotation_translation(pyglam.DQuat(0., 0., 0., 1.), pyglam.DVec3(1., 0., 0.))
+        actual = (translate * rotate).transform_point3(pyglam.DVec3(1., 0., 0.))
+        np.testing.assert_allclose(actual.to_tuple(), (1., 1., 0.), atol=1e-12)
+
+    def test_inverse(self):
+        rotation = pyglam.DQuat.from_axis_angle(pyglam.DVec3(0., 0., 1.), 0.5)
+        affine = pyglam.DAffine3.from_scale_rotation_translation(pyglam.DVec3(2.), rotation, pyglam.DVec3(1., 2., 3.))
+        actual = affine.inverse() @ affine @ pyglam.DVec3(1., 2., 3.)
+        np.testing.assert_allclose(actual.to_tuple(), (1., 2., 3.))
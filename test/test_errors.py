@@ -0,0 +1,118 @@
+import pytest
+import pyglam
+
+
+class Reflected:
+    """Operand that only supports reflected operators"""
+
+    def __radd__(self, other):
+        return "radd"
+
+    def __rsub__(self, other):
+        return "rsub"
+
+    def __rmul__(self, other):
+        return "rmul"
+
+    def __rtruediv__(self, other):
+        return "rtruediv"
+
+    def __rmatmul__(self, other):
+        return "rmatmul"
+
+
+class BrokenArray:
+    """Object whose `__array__` fails, as it does for array types when numpy isn't installed"""
+
+    def __array__(self, *args, **kwargs):
+        raise ImportError("numpy is not installed")
+
+
+class TestNotImplemented:
+    def test_reflected_operators(self):
+        values = [
+            pyglam.DVec2(1.),
+            pyglam.DVec3(1.),
+            pyglam.DVec4(1.),
+            pyglam.DVec3Array([(1., 2., 3.)]),
+        ]
+        for value in values:
+            assert value + Reflected() == "radd"
+            assert value - Reflected() == "rsub"
+            assert value * Reflected() == "rmul"
+            assert value / Reflected() == "rtruediv"
+        assert pyglam.DQuat.IDENTITY * Reflected() == "rmul"
+        assert pyglam.DQuatArray.identity(2) * Reflected() == "rmul"
+        mat = pyglam.DMat3.from_quat(pyglam.DQuat.IDENTITY)
+        assert mat * Reflected() == "rmul"
+        assert mat @ Reflected() == "rmatmul"
+
+    def test_unsupported_operand(self):
+        vec = pyglam.DVec3(1., 2., 3.)
+        with pytest.raises(TypeError):
+            vec + "x"
+        with pytest.raises(TypeError):
+            vec * (1., 2.)
+        with pytest.raises(TypeError):
+            pyglam.DQuat.IDENTITY * "x"
+        with pytest.raises(TypeError):
+            pyglam.DVec2(1.) - None
+
+    def test_failing_array_protocol(self):
+        with pytest.raises(TypeError):
+            pyglam.DVec3(1.) + BrokenArray()
+        with pytest.raises(TypeError):
+            pyglam.DQuat.IDENTITY * BrokenArray()
+        with pytest.raises(TypeError):
+            pyglam.DQuat.IDENTITY.rotate(BrokenArray())
+        with pytest.raises(TypeError):
+            pyglam.DVec2(1.).dot(BrokenArray())
+
+    def test_pyglam_arrays_are_not_vec_like(self):
+        with pytest.raises(TypeError):
+            pyglam.DVec3(1.).dot(pyglam.DVec3Array([(1., 2., 3.)] * 3))
+        with pytest.raises(TypeError):
+            pyglam.DQuat.IDENTITY * pyglam.DVec3Array([(1., 2., 3.)] * 4)
+
+    def test_unsupported_inplace(self):
+        vec = pyglam.DVec3(1., 2., 3.)
+        with pytest.raises(TypeError):
+            vec += "x"
+        assert vec == pyglam.DVec3(1., 2., 3.)
+
+    def test_eq_other_types(self):
+        vec = pyglam.DVec3(1., 2., 3.)
+        assert vec != "x"
+        assert not (vec == (1., 2., 3.))
+        assert pyglam.DQuat.IDENTITY != (0., 0., 0., 1.)
+
+
+class TestPyglamError:
+    def test_hierarchy(self):
+        assert issubclass(pyglam.PyglamError, ValueError)
+        assert issubclass(pyglam.NormalizationError, pyglam.PyglamError)
+        assert issubclass(pyglam.SingularMatrixError, pyglam.PyglamError)
+
+    def test_normalize_zero(self):
+        with pytest.raises(pyglam.NormalizationError):
+            pyglam.DVec2(0.).normalize()
+        with pytest.raises(pyglam.NormalizationError):
+            pyglam.DVec3(0.).normalize()
+        with pytest.raises(pyglam.NormalizationError):
+            pyglam.DVec3(float("nan")).normalize()
+        with pytest.raises(pyglam.NormalizationError):
+            pyglam.DVec4(0.).normalize()
+        with pytest.raises(pyglam.NormalizationError):
+            pyglam.DQuat(0., 0., 0., 0.).normalize()
+        with pytest.raises(pyglam.NormalizationError):
+            pyglam.DVec3.rand_in_cone((0., 0., 0.), 0.1)
+        assert pyglam.DVec3(0., 3., 4.).normalize().is_close((0., 0.6, 0.8))
+        assert pyglam.DQuat(0., 0., 0., 2.).normalize() == pyglam.DQuat.IDENTITY
+
+    def test_singular_matrix(self):
+        zero = pyglam.DMat3.from_diagonal(pyglam.DVec3(0.))
+        with pytest.raises(pyglam.SingularMatrixError):
+            zero.inverse()
+        # existing ValueError handlers keep working
+        with pytest.raises(ValueError):
+            zero.inverse()
@@ -0,0 +1,20 @@
+import numpy as np
+import pyglam
+
+class TestDMat3:
+    def test_mul_vec(self):
+        mat = pyglam.DMat3.from_diagonal(pyglam.DVec3(1., 2., 3.))
+        actual = mat @ pyglam.DVec3(1.)
+        np.testing.assert_allclose(actual.to_tuple(), (1., 2., 3.))
+
+    def test_quat_round_trip(self):
+        quat = pyglam.DQuat.from_axis_angle(pyglam.DVec3(0., 0., 1.), 0.5)
+        actual = pyglam.DQuat.from_mat3(pyglam.DMat3.from_quat(quat))
+        np.testing.assert_allclose(actual.to_tuple(), quat.to_tuple(), atol=1e-12)
+
+    def test_vec3_like(self):
+        actual = pyglam.DMat3((1., 0., 0.), [0., 1., 0.], pyglam.Vec3(0., 0., 1.))
+        assert actual.to_tuple() == pyglam.DMat3.from_diagonal((1., 1., 1.)).to_tuple()
+        assert pyglam.DMat3.from_rows((1., 2., 3.), (4., 5., 6.), (7., 8., 9.)).x_axis == pyglam.DVec3(1., 4., 7.)
+        rotation = pyglam.DMat3.from_quat((0., 0., 0., 1.))
+        assert rotation.to_tuple() == ((1., 0., 0.), (0., 1., 0.), (0., 0., 1.))
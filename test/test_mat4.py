@@ -0,0 +1,24 @@
+import numpy as np
+import pyglam
+
+class TestDMat4:
+    def test_scale_rotation_translation(self):
+        rotation = pyglam.DQuat.from_axis_angle(pyglam.DVec3(0., 0., 1.), 0.5)
+        mat = pyglam.DMat4.from_scale_rotation_translation(pyglam.DVec3(1., 2., 3.), rotation, pyglam.DVec3(4., 5., 6.))
+        scale, actual_rotation, translation = mat.to_scale_rotation_translation()
+        np.testing.assert_allclose(scale.to_tuple(), (1., 2., 3.))
+        np.testing.assert_allclose(actual_rotation.to_tuple(), rotation.to_tuple())
+        np.testing.assert_allclose(translation.to_tuple(), (4., 5., 6.))
+
+    def test_transform_point3(self):
+        mat = pyglam.DMat4.from_scale_rotation_translation(pyglam.DVec3(2.), pyglam.DQuat(0., 0., 0., 1.), pyglam.DVec3(1., 0., 0.))
+        np.testing.assert_allclose(mat.transform_point3(pyglam.DVec3(1.)).to_tuple(), (3., 2., 2.))
+        np.testing.assert_allclose(mat.transform_vector3(pyglam.DVec3(1.)).to_tuple(), (2., 2., 2.))
+
+    def test_vec3_like(self):
+        mat = pyglam.DMat4.from_scale_rotation_translation((2., 2., 2.), (0., 0., 0., 1.), [1., 0., 0.])
+        np.testing.assert_allclose(mat.transform_point3(pyglam.DVec3(1.)).to_tuple(), (3., 2., 2.))
+        view = pyglam.DMat4.look_at_rh((0., 0., 1.), (0., 0., 0.), pyglam.Vec3(0., 1., 0.))
+        np.testing.assert_allclose(view.transform_point3(pyglam.DVec3(0.)).to_tuple(), (0., 0., -1.))
+        view = pyglam.DMat4.look_at_lh((0., 0., -1.), (0., 0., 0.), (0., 1., 0.))
+        np.testing.assert_allclose(view.transform_point3(pyglam.DVec3(0.)).to_tuple(), (0., 0., 1.))
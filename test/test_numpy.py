@@ -0,0 +1,92 @@
+import ctypes
+import numpy as np
+import pytest
+import pyglam
+
+class TestNumpy:
+    def test_dvec3_view(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        array = np.asarray(actual)
+        assert array.dtype == np.float64
+        np.testing.assert_allclose(array, [1., 2., 3.])
+        assert not array.flags.writeable
+        with pytest.raises(ValueError):
+            array[0] = 5.
+        actual.x = 5.
+        assert array[0] == 5.
+
+    def test_dvec3_copy(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        array = np.array(actual, copy=True)
+        array[0] = 5.
+        assert actual.x == 1.
+        assert np.asarray(actual, dtype=np.float32).dtype == np.float32
+
+    def test_dquat_xyzw(self):
+        actual = pyglam.DQuat(0.1, 0.2, 0.3, 0.9)
+        np.testing.assert_allclose(np.asarray(actual), [0.1, 0.2, 0.3, 0.9])
+        assert pyglam.DQuat.from_numpy(np.asarray(actual)) == actual
+
+    def test_from_numpy(self):
+        actual = pyglam.DVec3.from_numpy(np.array([1., 2., 3.]))
+        assert actual == pyglam.DVec3(1., 2., 3.)
+        with pytest.raises(ValueError):
+            pyglam.DVec3.from_numpy(np.array([1., 2.]))
+
+    def test_from_numpy_casts(self):
+        actual = pyglam.DVec3.from_numpy(np.array([1., 2., 3.], dtype=np.float32))
+        assert actual == pyglam.DVec3(1., 2., 3.)
+        assert pyglam.DQuat.from_numpy([0., 0., 0., 1]) == pyglam.DQuat.IDENTITY
+
+    def test_memoryview(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        view = memoryview(actual)
+        assert view.format == "d"
+        assert view.shape == (3,)
+        assert view.readonly
+        with pytest.raises(TypeError):
+            (ctypes.c_double * 3).from_buffer(actual)
+
+    def test_dvec3_array_round_trip(self):
+        positions = np.arange(12, dtype=np.float64).reshape(4, 3)
+        actual = pyglam.DVec3Array.from_numpy(positions)
+        assert actual[1] == pyglam.DVec3(3., 4., 5.)
+        np.testing.assert_allclose(actual.to_numpy(), positions)
+        np.testing.assert_allclose(np.asarray(actual), positions)
+
+    def test_dvec3_array_casts(self):
+        positions = np.arange(12, dtype=np.float32).reshape(4, 3)
+        actual = pyglam.DVec3Array.from_numpy(positions)
+        np.testing.assert_allclose(actual.to_numpy(), positions)
+        assert pyglam.DVec3Array(positions).to_list() == actual.to_list()
+        assert pyglam.DVec3Array(np.arange(6).reshape(2, 3))[1] == pyglam.DVec3(3., 4., 5.)
+        with pytest.raises(ValueError):
+            pyglam.DVec3Array.from_numpy(np.zeros((2, 4)))
+
+    def test_dquat_array_round_trip(self):
+        rotations = np.array([[0., 0., 0., 1.], [0., 0., 1., 0.]])
+        actual = pyglam.DQuatArray.from_numpy(rotations)
+        assert actual[1] == pyglam.DQuat(0., 0., 1., 0.)
+        np.testing.assert_allclose(actual.to_numpy(), rotations)
+        np.testing.assert_allclose(np.asarray(actual), rotations)
+
+    def test_dquat_array_casts(self):
+        rotations = np.array([[0., 0., 0., 1.], [0., 0., 1., 0.]], dtype=np.float32)
+        actual = pyglam.DQuatArray.from_numpy(rotations)
+        np.testing.assert_allclose(actual.to_numpy(), rotations)
+        assert pyglam.DQuatArray(rotations).to_list() == actual.to_list()
+        with pytest.raises(ValueError):
+            pyglam.DQuatArray.from_numpy(np.zeros((2, 3)))
+
+    def test_array_dtype_arguments(self):
+        positions = np.arange(6, dtype=np.float64).reshape(2, 3)
+        actual = pyglam.DVec3Array.from_numpy(positions, dtype="float32")
+        assert isinstance(actual, pyglam.Vec3Array)
+        assert actual.to_numpy().dtype == np.float32
+        assert actual.to_numpy(dtype=np.float64).dtype == np.float64
+        assert pyglam.vec3_array(positions.astype(np.float32)).dtype == "float32"
+        rotations = pyglam.QuatArray.from_numpy([[0., 0., 0., 1.]], dtype=float)
+        assert isinstance(rotations, pyglam.DQuatArray)
+        assert rotations.to_numpy(dtype="float32").dtype == np.float32
+        with pytest.raises(ValueError):
+            actual.to_numpy(dtype="int32")
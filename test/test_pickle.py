@@ -0,0 +1,95 @@
+import copy
+import pickle
+import pytest
+import pyglam
+
+
+VALUES = [
+    pyglam.DVec2(1., 2.),
+    pyglam.DVec3(1., -2., 3.5),
+    pyglam.DVec4(1., 2., 3., 4.),
+    pyglam.DQuat.from_axis_angle((0., 0., 1.), 0.5),
+    pyglam.DMat3.from_quat(pyglam.DQuat.from_axis_angle((1., 0., 0.), 0.25)),
+    pyglam.DMat4.from_scale_rotation_translation(
+        pyglam.DVec3(1., 2., 3.), pyglam.DQuat.from_axis_angle((0., 1., 0.), 0.25), pyglam.DVec3(4., 5., 6.)
+    ),
+    pyglam.DAffine3.from_rotation_translation(
+        pyglam.DQuat.from_axis_angle((0., 1., 0.), 0.25), pyglam.DVec3(1., 2., 3.)
+    ),
+]
+
+
+def to_tuple(value):
+    if isinstance(value, pyglam.DAffine3):
+        return to_tuple(value.to_mat4())
+    return value.to_tuple()
+
+
+class TestPickle:
+    def test_round_trip(self):
+        for value in VALUES:
+            for protocol in range(pickle.HIGHEST_PROTOCOL + 1):
+                actual = pickle.loads(pickle.dumps(value, protocol))
+                assert type(actual) is type(value)
+                assert to_tuple(actual) == to_tuple(value)
+
+    def test_exact_floats(self):
+        vec = pyglam.DVec3(0.1, float("inf"), -0.0)
+        actual = pickle.loads(pickle.dumps(vec))
+        assert actual == vec
+        assert str(actual.z) == "-0.0"
+
+    def test_arrays(self):
+        vecs = pyglam.DVec3Array([(1., 2., 3.), (0.1, -0.2, 0.3)])
+        actual = pickle.loads(pickle.dumps(vecs))
+        assert len(actual) == 2
+        assert actual[1] == pyglam.DVec3(0.1, -0.2, 0.3)
+        quats = pyglam.DQuatArray([pyglam.DQuat.from_axis_angle((0., 0., 1.), 0.5)])
+        assert pickle.loads(pickle.dumps(quats))[0] == quats[0]
+        assert len(pickle.loads(pickle.dumps(pyglam.DVec3Array()))) == 0
+
+    def test_euler_rot(self):
+        assert pickle.loads(pickle.dumps(pyglam.EulerRot.ZYXEx)) == pyglam.EulerRot.ZYXEx
+        assert copy.copy(pyglam.EulerRot.XYZ) == pyglam.EulerRot.XYZ
+
+    def test_rng(self):
+        rng = pyglam.Rng(3)
+        rng.random()
+        restored = pickle.loads(pickle.dumps(rng))
+        assert restored.random() == rng.random()
+
+    def test_unsupported_version(self):
+        restore, (state,) = pyglam.DVec3(1., 2., 3.).__reduce__()
+        with pytest.raises(ValueError):
+            restore((99, state[1]))
+
+
+class TestCopy:
+    def test_copy_is_independent(self):
+        vec = pyglam.DVec3(1., 2., 3.)
+        for copied in [copy.copy(vec), copy.deepcopy(vec)]:
+            copied.x = 5.
+            assert vec.x == 1.
+            assert copied == pyglam.DVec3(5., 2., 3.)
+
+    def test_copy_constant(self):
+        zero = copy.copy(pyglam.DVec3.ZERO)
+        zero.x = 1.
+        assert pyglam.DVec3.ZERO == pyglam.DVec3(0., 0., 0.)
+
+    def test_copy_values(self):
+        for value in VALUES:
+            assert to_tuple(copy.copy(value)) == to_tuple(value)
+            assert to_tuple(copy.deepcopy(value)) == to_tuple(value)
+
+    def test_deepcopy_containers(self):
+        vecs = pyglam.DVec3Array([(1., 2., 3.)])
+        actual = copy.deepcopy({"vecs": vecs, "rot": pyglam.DQuat.IDENTITY})
+        actual["vecs"][0] = (4., 5., 6.)
+        assert vecs[0] == pyglam.DVec3(1., 2., 3.)
+        assert actual["rot"] == pyglam.DQuat.IDENTITY
+
+    def test_copy_rng(self):
+        rng = pyglam.Rng(7)
+        copied = copy.copy(rng)
+        assert copied.random() == rng.random()
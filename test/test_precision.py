@@ -0,0 +1,185 @@
+import pytest
+import pyglam
+
+
+requires_f32_feature = pytest.mark.skipif(
+    not hasattr(pyglam, "Vec3"), reason="pyglam built without the f32 feature"
+)
+
+
+@requires_f32_feature
+class TestPromotion:
+    def test_vec3(self):
+        a = pyglam.Vec3(1., 2., 3.)
+        b = pyglam.DVec3(0.1, 0.2, 0.3)
+        for actual in [a + b, b + a, a - b, b - a, a * b, b * a, a / b, b / a]:
+            assert isinstance(actual, pyglam.DVec3)
+        assert (a + b).is_close((1.1, 2.2, 3.3))
+        assert (a - b).is_close((0.9, 1.8, 2.7))
+        assert (b - a).is_close((-0.9, -1.8, -2.7))
+        assert (a / b).is_close((10., 10., 10.))
+
+    def test_vec3_keeps_precision(self):
+        a = pyglam.Vec3(1., 2., 3.)
+        assert isinstance(a + a, pyglam.Vec3)
+        assert isinstance(a * 2., pyglam.Vec3)
+        assert isinstance(a + (0.1, 0.2, 0.3), pyglam.Vec3)
+        assert isinstance(pyglam.DVec3(1.) + a, pyglam.DVec3)
+
+    def test_inplace_rebinds(self):
+        a = pyglam.Vec3(1., 2., 3.)
+        alias = a
+        a += pyglam.DVec3(0.1, 0.2, 0.3)
+        assert isinstance(a, pyglam.DVec3)
+        assert alias == pyglam.Vec3(1., 2., 3.)
+
+    def test_vec2_vec4(self):
+        assert isinstance(pyglam.Vec2(1., 2.) + pyglam.DVec2(1., 2.), pyglam.DVec2)
+        assert isinstance(pyglam.Vec2(1., 2.) * 2, pyglam.Vec2)
+        assert isinstance(pyglam.Vec2(1., 2.) - (0.5, 0.5), pyglam.Vec2)
+        assert pyglam.Vec2(1., 2.).dot(pyglam.DVec2(3., 4.)) == 11.
+        assert isinstance(pyglam.Vec4(1.) * pyglam.DVec4(0.1), pyglam.DVec4)
+        assert isinstance(pyglam.DVec4(1.) - pyglam.Vec4(1.), pyglam.DVec4)
+
+    def test_quat(self):
+        q = pyglam.Quat.from_axis_angle((0., 0., 1.), 0.5)
+        dq = pyglam.DQuat.from_axis_angle((1., 0., 0.), 0.5)
+        assert isinstance(q * dq, pyglam.DQuat)
+        assert (q * dq).is_close(q.as_dquat() * dq)
+        assert isinstance(dq * q, pyglam.DQuat)
+        assert isinstance(q * pyglam.DVec3(1., 0., 0.), pyglam.DVec3)
+        assert isinstance(q * pyglam.Vec3(1., 0., 0.), pyglam.Vec3)
+        assert isinstance(q * (1., 0., 0.), pyglam.Vec3)
+        assert isinstance(q * q, pyglam.Quat)
+
+    def test_mat3(self):
+        m = pyglam.Mat3.from_diagonal(pyglam.Vec3(2.))
+        dm = pyglam.DMat3.from_diagonal(pyglam.DVec3(0.1))
+        for actual in [m * dm, dm * m, m @ dm, dm @ m]:
+            assert isinstance(actual, pyglam.DMat3)
+        assert (m * dm).x_axis == pyglam.DVec3(0.2, 0., 0.)
+        assert isinstance(m @ pyglam.DVec3(0.1), pyglam.DVec3)
+        assert (m * pyglam.DVec3(0.1)) == pyglam.DVec3(0.2)
+        assert isinstance(m * pyglam.Vec3(1.), pyglam.Vec3)
+        assert isinstance(m * m, pyglam.Mat3)
+        assert isinstance(2. * m, pyglam.Mat3)
+
+    def test_mat4(self):
+        m = pyglam.Mat4.from_scale_rotation_translation(pyglam.Vec3(2.), pyglam.Quat.IDENTITY, pyglam.Vec3(0.))
+        dm = pyglam.DMat4.from_scale_rotation_translation(pyglam.DVec3(0.1), pyglam.DQuat.IDENTITY, pyglam.DVec3(0.))
+        for actual in [m * dm, dm * m, m @ dm, dm @ m]:
+            assert isinstance(actual, pyglam.DMat4)
+        assert isinstance(m * pyglam.DVec4(0.1), pyglam.DVec4)
+        assert isinstance(m @ pyglam.Vec4(1.), pyglam.Vec4)
+        assert isinstance(m * m, pyglam.Mat4)
+
+    def test_affine3(self):
+        a = pyglam.Affine3A(pyglam.Quat.IDENTITY, pyglam.Vec3(1.))
+        da = pyglam.DAffine3(pyglam.DQuat.IDENTITY, pyglam.DVec3(0.1))
+        for actual in [a * da, da * a, a @ da, da @ a]:
+            assert isinstance(actual, pyglam.DAffine3)
+        assert isinstance(a * pyglam.DVec3(0.1), pyglam.DVec3)
+        assert (a * pyglam.DVec3(0.1)) == pyglam.DVec3(1.1)
+        assert isinstance(a * pyglam.Vec3(0.), pyglam.Vec3)
+        assert isinstance(a * a, pyglam.Affine3A)
+
+    def test_vec3_array_rejects_mixed_precision(self):
+        a = pyglam.vec3_array([(1., 2., 3.)], dtype="float32")
+        d = pyglam.vec3_array([(1., 2., 3.)], dtype="float64")
+        for mixed in [lambda: a + pyglam.DVec3(1.), lambda: a * d, lambda: d - a, lambda: a.dot(pyglam.DVec3(1.))]:
+            with pytest.raises(TypeError):
+                mixed()
+        assert isinstance(d + pyglam.Vec3(1.), pyglam.DVec3Array)
+        assert isinstance(a + pyglam.Vec3(1.), pyglam.Vec3Array)
+        assert isinstance(pyglam.vec3_array(a, dtype="float64") + d, pyglam.DVec3Array)
+
+    def test_quat_array_rejects_mixed_precision(self):
+        qa = pyglam.quat_array([(0., 0., 0., 1.)], dtype="float32")
+        d = pyglam.vec3_array([(1., 2., 3.)], dtype="float64")
+        for mixed in [lambda: qa * pyglam.DQuat.IDENTITY, lambda: pyglam.DQuat.IDENTITY * qa,
+                      lambda: qa * pyglam.DVec3(1.), lambda: qa * d]:
+            with pytest.raises(TypeError):
+                mixed()
+        assert isinstance(qa * pyglam.Quat.IDENTITY, pyglam.QuatArray)
+        assert isinstance(pyglam.quat_array(qa, dtype="float64") * d, pyglam.DVec3Array)
+
+    def test_vector_on_the_left(self):
+        with pytest.raises(TypeError):
+            pyglam.DVec3(1.) * pyglam.DMat3.from_diagonal(pyglam.DVec3(1.))
+        with pytest.raises(TypeError):
+            pyglam.Vec3(1.) @ pyglam.Mat3.from_diagonal(pyglam.Vec3(1.))
+
+
+@requires_f32_feature
+class TestConversion:
+    def test_vec3(self):
+        actual = pyglam.DVec3(0.1, 0.2, 0.3).as_vec3()
+        assert isinstance(actual, pyglam.Vec3)
+        assert actual.is_close((0.1, 0.2, 0.3), rel_tol=1e-6)
+        widened = actual.as_dvec3()
+        assert isinstance(widened, pyglam.DVec3)
+        assert widened == actual
+        assert isinstance(widened.as_dvec3(), pyglam.DVec3)
+        assert isinstance(actual.as_vec3(), pyglam.Vec3)
+
+    def test_vec2_vec4(self):
+        assert isinstance(pyglam.DVec2(1., 2.).as_vec2(), pyglam.Vec2)
+        assert isinstance(pyglam.Vec2(1., 2.).as_dvec2(), pyglam.DVec2)
+        assert pyglam.Vec2(1., 2.).as_dvec2() == pyglam.DVec2(1., 2.)
+        assert isinstance(pyglam.DVec4(1.).as_vec4(), pyglam.Vec4)
+        assert isinstance(pyglam.Vec4(1.).as_dvec4(), pyglam.DVec4)
+        assert pyglam.Vec4(1.).as_dvec4() == pyglam.DVec4(1.)
+
+    def test_quat(self):
+        dq = pyglam.DQuat.from_axis_angle((1., 0., 0.), 0.5)
+        q = dq.as_quat()
+        assert isinstance(q, pyglam.Quat)
+        assert q.is_close(dq, rel_tol=1e-6)
+        assert isinstance(q.as_dquat(), pyglam.DQuat)
+        assert q.as_dquat() == q
+
+
+class TestDtype:
+    def test_f64(self):
+        affine = pyglam.DAffine3(pyglam.DQuat.IDENTITY, pyglam.DVec3(0.))
+        assert affine.dtype == affine.to_mat4().dtype == "float64"
+        for value in [pyglam.DVec2(1.), pyglam.DVec3(1.), pyglam.DVec4(1.), pyglam.DQuat.IDENTITY,
+                      pyglam.DMat3.from_diagonal(pyglam.DVec3(1.)), pyglam.DVec3Array(), pyglam.DQuatArray()]:
+            assert value.dtype == "float64"
+
+    @requires_f32_feature
+    def test_f32(self):
+        affine = pyglam.Affine3A(pyglam.Quat.IDENTITY, pyglam.Vec3(0.))
+        assert affine.dtype == affine.to_mat4().dtype == "float32"
+        for value in [pyglam.Vec2(1.), pyglam.Vec3(1.), pyglam.Vec4(1.), pyglam.Quat.IDENTITY,
+                      pyglam.Mat3.from_diagonal(pyglam.Vec3(1.)), pyglam.Vec3Array(), pyglam.QuatArray()]:
+            assert value.dtype == "float32"
+
+    def test_vec3_array(self):
+        assert isinstance(pyglam.vec3_array(), pyglam.DVec3Array)
+        actual = pyglam.vec3_array([(1., 2., 3.)], dtype="float64")
+        assert isinstance(actual, pyglam.DVec3Array)
+        assert len(actual) == 1
+        assert isinstance(pyglam.vec3_array([(1., 2., 3.)], dtype=float), pyglam.DVec3Array)
+
+    @requires_f32_feature
+    def test_vec3_array_f32(self):
+        actual = pyglam.vec3_array([(1., 2., 3.)], dtype="float32")
+        assert isinstance(actual, pyglam.Vec3Array)
+        assert actual[0] == pyglam.Vec3(1., 2., 3.)
+        assert isinstance(pyglam.vec3_array(dtype="f4"), pyglam.Vec3Array)
+        assert isinstance(pyglam.vec3_array(actual), pyglam.Vec3Array)
+
+    @requires_f32_feature
+    def test_quat_array(self):
+        assert isinstance(pyglam.quat_array(), pyglam.DQuatArray)
+        actual = pyglam.quat_array([(0., 0., 0., 1.)], dtype="float32")
+        assert isinstance(actual, pyglam.QuatArray)
+        assert isinstance(pyglam.quat_array(actual), pyglam.QuatArray)
+        assert isinstance(pyglam.quat_array(actual, dtype="float64"), pyglam.DQuatArray)
+
+    def test_invalid_dtype(self):
+        with pytest.raises(ValueError):
+            pyglam.vec3_array(dtype="int32")
+        with pytest.raises(ValueError):
+            pyglam.quat_array(dtype="float16")
@@ -0,0 +1,158 @@
+import numpy as np
+import pytest
+import pyglam
+
+class TestDQuat:
+    def test_eq(self):
+        actual = pyglam.DQuat(0., 0., 0., 1.)
+        assert actual == pyglam.DQuat(0., 0., 0., 1.)
+        assert actual != pyglam.DQuat(0., 0., 0., -1.)
+        assert len({actual, pyglam.DQuat(0., 0., 0., 1.)}) == 1
+
+    def test_is_close_sign_flip(self):
+        actual = pyglam.DQuat.from_axis_angle(pyglam.DVec3(0., 0., 1.), 0.5)
+        flipped = pyglam.DQuat(*(-c for c in actual.to_tuple()))
+        assert actual.is_close(flipped)
+        assert not actual.is_close(pyglam.DQuat(0., 0., 0., 1.))
+
+    def test_vec_like_operands(self):
+        actual = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        rotated = actual * [1., 0., 0.]
+        assert rotated.is_close((0., 1., 0.), abs_tol=1e-12)
+        assert actual.is_close(actual * (0., 0., 0., 1.))
+        arc = pyglam.DQuat.from_rotation_arc(np.array([1., 0., 0.]), (0., 1., 0.))
+        assert arc.is_close(actual)
+
+    def test_slerp(self):
+        start = pyglam.DQuat(0., 0., 0., 1.)
+        end = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        expected = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 4.)
+        assert start.slerp(end, 0.5).is_close(expected)
+        assert start.nlerp(end, 0.5).is_close(expected)
+        assert start.lerp(end, 1.).is_close(end)
+        # the negated end quat is the same rotation, interpolation must take the short way
+        flipped = pyglam.DQuat(*(-c for c in end.to_tuple()))
+        assert start.slerp(flipped, 0.5).is_close(expected)
+        assert start.nlerp(flipped, 0.5).is_close(expected)
+
+    def test_squad(self):
+        start = pyglam.DQuat(0., 0., 0., 1.)
+        end = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        assert pyglam.DQuat.squad(start, start, end, end, 0.).is_close(start)
+        assert pyglam.DQuat.squad(start, start, end, end, 1.).is_close(end)
+
+    def test_interpolate_many(self):
+        start = pyglam.DQuat(0., 0., 0., 1.)
+        end = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        ts = np.linspace(0., 1., 5)
+        actual = start.slerp_many(end, ts)
+        assert len(actual) == 5
+        for t, quat in zip(ts, actual.to_list()):
+            assert quat.is_close(start.slerp(end, t))
+        assert start.nlerp_many(end, [0.5])[0].is_close(start.nlerp(end, 0.5))
+        assert pyglam.DQuat.squad_many(start, start, end, end, [1.])[0].is_close(end)
+
+    def test_euler_round_trip(self):
+        orders = [name for name in dir(pyglam.EulerRot) if name[0].isupper()]
+        assert len(orders) == 24
+        for name in orders:
+            actual = pyglam.DQuat.from_euler(name, 0.3, 0.5, 0.7)
+            assert actual == pyglam.DQuat.from_euler(getattr(pyglam.EulerRot, name), 0.3, 0.5, 0.7)
+            # two-axis extrinsic orders may return the equivalent solution with a negated middle angle
+            assert pyglam.DQuat.from_euler(name, *actual.to_euler(name)).is_close(actual)
+
+    def test_euler_degrees(self):
+        actual = pyglam.DQuat.from_euler("zyx", 30., 20., 10., degrees=True)
+        np.testing.assert_allclose(actual.to_euler(pyglam.EulerRot.ZYX, degrees=True), (30., 20., 10.))
+        assert actual.is_close(pyglam.DQuat.from_euler("ZYX", np.pi / 6., np.pi / 9., np.pi / 18.))
+        np.testing.assert_allclose(
+            pyglam.DQuat.from_euler("XYZ", 0.3, 0.5, 0.7).to_euler("XYZ"), (0.3, 0.5, 0.7)
+        )
+
+    def test_euler_gimbal_lock(self):
+        actual = pyglam.DQuat.from_euler("XYZ", 0.3, np.pi / 2., 0.7)
+        angles = actual.to_euler("XYZ")
+        assert angles[2] == 0.
+        assert pyglam.DQuat.from_euler("XYZ", *angles).is_close(actual)
+
+    def test_euler_invalid_order(self):
+        with pytest.raises(ValueError):
+            pyglam.DQuat.from_euler("XYW", 0., 0., 0.)
+
+    def test_axis_angle(self):
+        actual = pyglam.DQuat.from_axis_angle((0., 1., 0.), 0.5)
+        axis, angle = actual.to_axis_angle()
+        assert axis.is_close((0., 1., 0.))
+        assert np.isclose(angle, 0.5)
+        assert actual.to_scaled_axis().is_close((0., 0.5, 0.))
+        assert pyglam.DQuat.from_scaled_axis((0., 0.5, 0.)).is_close(actual)
+        assert pyglam.DQuat.from_rotation_y(0.5).is_close(actual)
+        assert pyglam.DQuat.from_scaled_axis((0., 0., 0.)).is_near_identity()
+
+    def test_rotation_axes(self):
+        vec = pyglam.DVec3(1., 1., 1.)
+        assert (pyglam.DQuat.from_rotation_x(np.pi / 2.) * vec).is_close((1., -1., 1.))
+        assert (pyglam.DQuat.from_rotation_y(np.pi / 2.) * vec).is_close((1., 1., -1.))
+        assert (pyglam.DQuat.from_rotation_z(np.pi / 2.) * vec).is_close((-1., 1., 1.))
+
+    def test_rotation_metrics(self):
+        start = pyglam.DQuat.from_rotation_z(0.25)
+        end = pyglam.DQuat.from_rotation_z(1.)
+        assert np.isclose(start.angle_between(end), 0.75)
+        assert np.isclose(start.dot(start), 1.)
+        assert np.isclose(start.length(), 1.)
+        assert start.is_normalized()
+        assert not pyglam.DQuat(0., 0., 0., 2.).is_normalized()
+        assert not start.is_near_identity()
+        assert (start * start.inverse()).is_near_identity()
+        assert pyglam.DQuat.from_mat3(start.to_mat3()).is_close(start)
+
+    def test_rand_uniform(self):
+        samples = [pyglam.DQuat.rand_uniform() for _ in range(2000)]
+        assert all(q.is_normalized() for q in samples)
+        rotated = [q * (1., 0., 0.) for q in samples]
+        assert sum(rotated, pyglam.DVec3(0.)).length() / len(rotated) < 0.1
+
+    def test_identity(self):
+        assert pyglam.DQuat.IDENTITY == pyglam.DQuat(0., 0., 0., 1.)
+        assert pyglam.DQuat.IDENTITY * pyglam.DVec3.X == pyglam.DVec3.X
+        q = pyglam.DQuat.IDENTITY
+        q *= pyglam.DQuat.from_rotation_x(1.)
+        assert pyglam.DQuat.IDENTITY == pyglam.DQuat(0., 0., 0., 1.)
+
+    def test_sequence(self):
+        actual = pyglam.DQuat(0., 0., 0., 1.)
+        actual[1:3] = (0.5, 0.5)
+        assert actual.to_tuple() == (0., 0.5, 0.5, 1.)
+        assert 0.5 in actual
+        assert "a" not in actual
+        with pytest.raises(ValueError):
+            actual[:2] = (1.,)
+
+    def test_rmul_order(self):
+        a = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        b = pyglam.DQuat.from_axis_angle((1., 0., 0.), np.pi / 2.)
+        assert b.__rmul__(a).is_close(a * b)
+        assert not (a * b).is_close(b * a)
+        assert (a.to_tuple() * b).is_close(a * b)
+
+    def test_vec_on_left(self):
+        quat = pyglam.DQuat.from_axis_angle((0., 0., 1.), 0.5)
+        for vec in [pyglam.DVec3(1., 0., 0.), (1., 0., 0.)]:
+            with pytest.raises(TypeError):
+                vec * quat
+
+    def test_rotate(self):
+        quat = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        assert quat.rotate((1., 0., 0.)).is_close((0., 1., 0.), abs_tol=1e-12)
+        assert quat.rotate(pyglam.DVec3(1., 2., 3.)) == quat * pyglam.DVec3(1., 2., 3.)
+        assert quat.inverse_rotate((0., 1., 0.)).is_close((1., 0., 0.), abs_tol=1e-12)
+        assert quat.inverse_rotate(quat.rotate((1., 2., 3.))).is_close((1., 2., 3.))
+
+    def test_then(self):
+        a = pyglam.DQuat.from_axis_angle((0., 0., 1.), np.pi / 2.)
+        b = pyglam.DQuat.from_axis_angle((1., 0., 0.), np.pi / 2.)
+        assert a.then(b).is_close(b * a)
+        # x -> y by a, then y -> z by b
+        assert a.then(b).rotate((1., 0., 0.)).is_close((0., 0., 1.), abs_tol=1e-12)
+        assert a.then(b.to_tuple()) == a.then(b)
@@ -0,0 +1,45 @@
+import math
+import numpy as np
+import pytest
+import pyglam
+
+
+class TestDQuatArray:
+    def test_construct(self):
+        quat = pyglam.DQuat.from_axis_angle((0., 0., 1.), 0.5)
+        actual = pyglam.DQuatArray([quat, (0., 0., 0., 1.)])
+        assert len(actual) == 2
+        assert actual[0] == quat
+        assert actual[-1] == pyglam.DQuat(0., 0., 0., 1.)
+        assert len(actual[1:]) == 1
+        assert len(pyglam.DQuatArray.identity(3)) == 3
+
+    def test_rotate_vectors(self):
+        quats = pyglam.DQuatArray([
+            pyglam.DQuat.from_axis_angle((0., 0., 1.), math.pi / 2.),
+            pyglam.DQuat.from_axis_angle((0., 1., 0.), math.pi / 2.),
+        ])
+        vecs = pyglam.DVec3Array([(1., 0., 0.), (1., 0., 0.)])
+        actual = quats * vecs
+        assert actual[0].is_close((0., 1., 0.), abs_tol=1e-12)
+        assert actual[1].is_close((0., 0., -1.), abs_tol=1e-12)
+        assert (quats * (1., 0., 0.))[0].is_close((0., 1., 0.), abs_tol=1e-12)
+
+    def test_compose(self):
+        quat = pyglam.DQuat.from_axis_angle((0., 0., 1.), 0.25)
+        quats = pyglam.DQuatArray([quat, quat])
+        expected = quat * quat
+        assert (quats * quats)[0].is_close(expected)
+        assert (quats * quat)[1].is_close(expected)
+        assert (quat * quats)[1].is_close(expected)
+
+    def test_unary_ops(self):
+        quat = pyglam.DQuat.from_axis_angle((1., 0., 0.), 0.5)
+        quats = pyglam.DQuatArray([quat])
+        assert quats.conjugate()[0].is_close(quat.conjugate())
+        assert (quats * quats.inverse())[0].is_close((0., 0., 0., 1.))
+        assert quats.normalize()[0].is_close(quat)
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            pyglam.DQuatArray.identity(2) * pyglam.DVec3Array.zeros(3)
@@ -0,0 +1,49 @@
+import pytest
+import pyglam
+
+
+class TestRng:
+    def test_seed_is_reproducible(self):
+        actual = pyglam.Rng(42)
+        expected = pyglam.Rng(42)
+        assert actual.rand_uv() == expected.rand_uv()
+        assert actual.rand_uniform() == expected.rand_uniform()
+        assert actual.rand_in_cone((0., 0., 1.), 0.5) == expected.rand_in_cone((0., 0., 1.), 0.5)
+        assert actual.random() == expected.random()
+        assert pyglam.Rng(1).rand_uv() != pyglam.Rng(2).rand_uv()
+
+    def test_dtype(self):
+        actual = pyglam.Rng(5)
+        assert isinstance(actual.rand_uv(), pyglam.DVec3)
+        assert isinstance(actual.rand_uv(dtype="float32"), pyglam.Vec3)
+        assert isinstance(actual.rand_in_cone((0., 0., 1.), 0.5, dtype="float32"), pyglam.Vec3)
+        assert isinstance(actual.rand_gaussian(2., dtype="float32"), pyglam.Vec3)
+        assert isinstance(actual.rand_uniform(dtype="float32"), pyglam.Quat)
+        expected = pyglam.Rng(5).rand_in_ball()
+        assert pyglam.Rng(5).rand_in_ball(dtype="float32") == expected.as_vec3()
+
+    def test_reseed(self):
+        actual = pyglam.Rng(7)
+        first = actual.rand_gaussian()
+        actual.seed(7)
+        assert actual.rand_gaussian() == first
+
+    def test_state_round_trip(self):
+        actual = pyglam.Rng(3)
+        actual.rand_in_ball()
+        state = actual.getstate()
+        expected = [actual.rand_in_cube(), actual.rand_uv()]
+        actual.setstate(state)
+        assert [actual.rand_in_cube(), actual.rand_uv()] == expected
+        with pytest.raises(ValueError):
+            actual.setstate((b"short", 0, 0))
+
+    def test_module_seed(self):
+        try:
+            pyglam.seed(11)
+            expected = [pyglam.DVec3.rand_uv(), pyglam.DQuat.rand_uniform()]
+            pyglam.seed(11)
+            assert [pyglam.DVec3.rand_uv(), pyglam.DQuat.rand_uniform()] == expected
+        finally:
+            pyglam.seed(None)
+        assert pyglam.DVec3.rand_uv() != pyglam.DVec3.rand_uv()
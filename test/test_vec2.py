@@ -0,0 +1,42 @@
+import numpy as np
+import pytest
+import pyglam
+
+class TestDVec2:
+    def test_construct(self):
+        actual = pyglam.DVec2(100.)
+        np.testing.assert_allclose(actual.x, 100.)
+        np.testing.assert_allclose(actual.y, 100.)
+
+    def test_perp_dot(self):
+        actual = pyglam.DVec2(1., 0.).perp_dot(pyglam.DVec2(0., 1.))
+        np.testing.assert_allclose(actual, 1.)
+
+    def test_vec2_like(self):
+        actual = pyglam.DVec2(1., 2.)
+        assert actual + (1., 1.) == pyglam.DVec2(2., 3.)
+        assert (4., 4.) / actual == pyglam.DVec2(4., 2.)
+        assert actual.dot((3., 4.)) == 11.
+        assert actual.perp_dot([0., 1.]) == 1.
+        with pytest.raises(TypeError):
+            actual.dot((1., 2., 3.))
+
+    def test_value_semantics(self):
+        actual = pyglam.DVec2(1., 0.5)
+        assert eval(repr(actual), {"DVec2": pyglam.DVec2}) == actual
+        assert actual != pyglam.DVec2(1., 0.)
+        assert actual != (1., 0.5)
+        assert hash(pyglam.DVec2(0., 1.)) == hash(pyglam.DVec2(-0., 1.))
+
+    def test_sequence(self):
+        actual = pyglam.DVec2(1., 2.)
+        x, y = actual
+        assert (x, y) == (1., 2.)
+        assert len(actual) == 2
+        assert actual[::-1] == (2., 1.)
+        actual[-1] = 5.
+        assert actual == pyglam.DVec2(1., 5.)
+        assert 5. in actual
+        assert "a" not in actual
+        with pytest.raises(IndexError):
+            actual[2]
@@ -1,4 +1,5 @@
 import numpy as np
+import pytest
 import pyglam
 
 class TestDVec3:
@@ -6,4 +7,172 @@ class TestDVec3:
         actual = pyglam.DVec3(100.)
         np.testing.assert_allclose(actual.x, 100.)
         np.testing.assert_allclose(actual.y, 100.)
-        np.testing.assert_allclose(actual.z, 100.)
\ No newline at end of file
+        np.testing.assert_allclose(actual.z, 100.)
+    def test_repr_round_trip(self):
+        from pyglam import DVec3
+        actual = DVec3(1., 0.1, -2.5)
+        assert eval(repr(actual)) == actual
+
+    def test_hash_signed_zero(self):
+        assert pyglam.DVec3(0., 1., 2.) == pyglam.DVec3(-0., 1., 2.)
+        assert hash(pyglam.DVec3(0., 1., 2.)) == hash(pyglam.DVec3(-0., 1., 2.))
+
+    def test_is_close(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        assert actual.is_close(actual + 1e-12)
+        assert not actual.is_close(actual + 1e-6)
+        assert pyglam.DVec3(0.).is_close(pyglam.DVec3(1e-12), abs_tol=1e-9)
+
+    def test_sequence(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        x, y, z = actual
+        assert (x, y, z) == (1., 2., 3.)
+        assert actual[-1] == 3.
+        assert actual[1:] == (2., 3.)
+        actual[0] = 5.
+        assert actual.x == 5.
+        with pytest.raises(IndexError):
+            actual[3]
+
+    def test_slice_assignment(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        actual[0:2] = (9, 9)
+        assert actual == pyglam.DVec3(9., 9., 3.)
+        actual[::-1] = [1., 2., 3.]
+        assert actual == pyglam.DVec3(3., 2., 1.)
+        with pytest.raises(ValueError):
+            actual[1:] = (1., 2., 3.)
+
+    def test_contains(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        assert 2. in actual
+        assert 2 in actual
+        assert 4. not in actual
+        assert "a" not in actual
+        assert None not in actual
+
+    def test_vec_like_operands(self):
+        actual = pyglam.DVec3(1., 2., 3.)
+        assert actual + (1., 1., 1.) == pyglam.DVec3(2., 3., 4.)
+        assert [1, 1, 1] - actual == pyglam.DVec3(0., -1., -2.)
+        assert actual.dot([1., 0., 0.]) == 1.
+        assert actual.cross((0., 0., 1.)) == pyglam.DVec3(2., -1., 0.)
+        assert actual.is_close(np.array([1., 2., 3.]))
+        assert actual != (1., 2., 3.)
+        with pytest.raises(TypeError):
+            actual + (1., 2.)
+
+    def test_rand_uv(self):
+        samples = [pyglam.DVec3.rand_uv() for _ in range(2000)]
+        assert all(np.isclose(v.length(), 1.) for v in samples)
+        # every octant is reached and the mean is close to the origin
+        assert len({tuple(c > 0. for c in v.to_tuple()) for v in samples}) == 8
+        assert sum(samples, pyglam.DVec3(0.)).length() / len(samples) < 0.1
+
+    def test_rand_regions(self):
+        for _ in range(500):
+            assert pyglam.DVec3.rand_in_ball().length() <= 1.
+            assert all(-1. <= c <= 1. for c in pyglam.DVec3.rand_in_cube().to_tuple())
+            cone = pyglam.DVec3.rand_in_cone((0., 0., 2.), 0.2)
+            assert np.isclose(cone.length(), 1.)
+            assert cone.z >= np.cos(0.2) - 1e-12
+
+    def test_rand_gaussian(self):
+        samples = [pyglam.DVec3.rand_gaussian(2.) for _ in range(4000)]
+        xs = [v.x for v in samples]
+        mean = sum(xs) / len(xs)
+        variance = sum((x - mean) ** 2 for x in xs) / len(xs)
+        assert abs(mean) < 0.2
+        assert 3.4 < variance < 4.6
+
+    def test_distances(self):
+        actual = pyglam.DVec3(1., 2., 2.)
+        assert actual.length_squared() == 9.
+        assert actual.distance((1., 2., 0.)) == 2.
+        assert actual.distance_squared(np.array([1., 0., 2.])) == 4.
+        assert actual.lerp((3., 2., 2.), 0.5) == pyglam.DVec3(2., 2., 2.)
+        assert actual.midpoint([3., 4., 4.]) == pyglam.DVec3(2., 3., 3.)
+
+    def test_projection(self):
+        actual = pyglam.DVec3(2., 3., 0.)
+        assert actual.project_onto((1., 0., 0.)) == pyglam.DVec3(2., 0., 0.)
+        assert actual.reject_from((1., 0., 0.)) == pyglam.DVec3(0., 3., 0.)
+        assert pyglam.DVec3(1., -1., 0.).reflect((0., 1., 0.)) == pyglam.DVec3(1., 1., 0.)
+        incident = pyglam.DVec3(1., -1., 0.).normalize()
+        assert incident.refract((0., 1., 0.), 1.).is_close(incident)
+        assert np.isclose(actual.angle_between((0., 0., 5.)), np.pi / 2.)
+
+    def test_normalization(self):
+        assert pyglam.DVec3(0., 3., 4.).clamp_length(0., 1.).is_close((0., 0.6, 0.8))
+        assert pyglam.DVec3(0.).normalize_or_zero() == pyglam.DVec3(0.)
+        assert pyglam.DVec3(0.).try_normalize() is None
+        assert pyglam.DVec3(0., 0., 2.).try_normalize() == pyglam.DVec3(0., 0., 1.)
+        first, second = pyglam.DVec3(0., 0., 1.).any_orthonormal_pair()
+        assert np.isclose(first.dot(second), 0.)
+        assert np.isclose(first.dot((0., 0., 1.)), 0.)
+        assert first.cross(second).is_close((0., 0., 1.))
+
+    def test_componentwise(self):
+        actual = pyglam.DVec3(-1., 2., -3.)
+        assert actual.abs() == pyglam.DVec3(1., 2., 3.)
+        assert actual.signum() == pyglam.DVec3(-1., 1., -1.)
+        assert actual.min((0., 0., 0.)) == pyglam.DVec3(-1., 0., -3.)
+        assert actual.max((0., 0., 0.)) == pyglam.DVec3(0., 2., 0.)
+        assert actual.clamp((-2., -2., -2.), (1., 1., 1.)) == pyglam.DVec3(-1., 1., -2.)
+        assert actual.element_sum() == -2.
+        assert actual.element_product() == 6.
+        assert actual.max_element() == 2.
+        assert actual.min_element() == -3.
+        assert actual.is_finite()
+        assert not pyglam.DVec3(float("nan"), 0., 0.).is_finite()
+        assert pyglam.DVec3(float("nan"), 0., 0.).is_nan()
+
+    def test_invalid_bounds(self):
+        for call in (
+            lambda: pyglam.DVec3(1.).clamp((1., 1., 1.), (0., 0., 0.)),
+            lambda: pyglam.DVec3(1.).clamp_length(2., 1.),
+        ):
+            with pytest.raises(ValueError):
+                call()
+
+    def test_constants(self):
+        assert pyglam.DVec3.ZERO == pyglam.DVec3(0.)
+        assert pyglam.DVec3.ONE == pyglam.DVec3(1.)
+        assert pyglam.DVec3.NEG_ONE == pyglam.DVec3(-1.)
+        assert pyglam.DVec3.X == pyglam.DVec3(1., 0., 0.)
+        assert pyglam.DVec3.Y == pyglam.DVec3(0., 1., 0.)
+        assert pyglam.DVec3.Z == pyglam.DVec3(0., 0., 1.)
+        assert pyglam.DVec3.NEG_X == -pyglam.DVec3.X
+        assert pyglam.DVec3.NEG_Y == -pyglam.DVec3.Y
+        assert pyglam.DVec3.NEG_Z == -pyglam.DVec3.Z
+        assert pyglam.DVec3.NAN.is_nan()
+        assert pyglam.DVec3.INFINITY.min_element() == float("inf")
+        assert pyglam.DVec3.NEG_INFINITY.max_element() == float("-inf")
+
+    def test_constants_are_copies(self):
+        v = pyglam.DVec3.ZERO
+        v += (1., 2., 3.)
+        assert pyglam.DVec3.ZERO == pyglam.DVec3(0.)
+        pyglam.DVec3.X.x = 7.
+        assert pyglam.DVec3.X == pyglam.DVec3(1., 0., 0.)
+        assert pyglam.DVec3(1.).ONE == pyglam.DVec3(1.)
+
+    def test_swizzle_read(self):
+        v = pyglam.DVec3(1., 2., 3.)
+        assert isinstance(v.zy, pyglam.DVec2)
+        assert v.zy == pyglam.DVec2(3., 2.)
+        assert v.zyx == pyglam.DVec3(3., 2., 1.)
+        assert v.xxx == pyglam.DVec3(1.)
+        assert isinstance(v.xyzx, pyglam.DVec4)
+        assert v.xyzx == pyglam.DVec4(1., 2., 3., 1.)
+
+    def test_swizzle_write(self):
+        v = pyglam.DVec3(1., 2., 3.)
+        v.zx = (4., 5.)
+        assert v == pyglam.DVec3(5., 2., 4.)
+        v.zyx = pyglam.DVec3(7., 8., 9.)
+        assert v == pyglam.DVec3(9., 8., 7.)
+        v.xy = v.yx
+        assert v == pyglam.DVec3(8., 9., 7.)
+        with pytest.raises(AttributeError):
+            v.xx = (1., 2.)
@@ -0,0 +1,39 @@
+import numpy as np
+import pytest
+import pyglam
+
+
+class TestDVec3Array:
+    def test_construct(self):
+        actual = pyglam.DVec3Array([(1., 2., 3.), pyglam.DVec3(4., 5., 6.)])
+        assert len(actual) == 2
+        assert actual[1] == pyglam.DVec3(4., 5., 6.)
+        assert actual[-1] == pyglam.DVec3(4., 5., 6.)
+        assert len(actual[:1]) == 1
+        assert len(pyglam.DVec3Array.zeros(4)) == 4
+
+    def test_elementwise_ops(self):
+        actual = pyglam.DVec3Array([(1., 2., 3.), (4., 5., 6.)])
+        np.testing.assert_allclose((actual + 1.)[0].to_tuple(), (2., 3., 4.))
+        np.testing.assert_allclose((actual - pyglam.DVec3(1., 1., 1.))[1].to_tuple(), (3., 4., 5.))
+        np.testing.assert_allclose((actual * actual)[1].to_tuple(), (16., 25., 36.))
+        np.testing.assert_allclose((1. / actual)[0].to_tuple(), (1., 0.5, 1. / 3.))
+        np.testing.assert_allclose(((10., 10., 10.) - actual)[0].to_tuple(), (9., 8., 7.))
+
+    def test_vector_ops(self):
+        actual = pyglam.DVec3Array([(1., 0., 0.), (0., 2., 0.)])
+        np.testing.assert_allclose(actual.dot((1., 1., 0.)), [1., 2.])
+        np.testing.assert_allclose(actual.length(), [1., 2.])
+        assert actual.cross((0., 0., 1.))[0] == pyglam.DVec3(0., -1., 0.)
+        assert actual.normalize()[1] == pyglam.DVec3(0., 1., 0.)
+
+    def test_reductions(self):
+        actual = pyglam.DVec3Array([(1., 5., 3.), (3., 1., 5.)])
+        assert actual.sum() == pyglam.DVec3(4., 6., 8.)
+        assert actual.mean() == pyglam.DVec3(2., 3., 4.)
+        assert actual.min() == pyglam.DVec3(1., 1., 3.)
+        assert actual.max() == pyglam.DVec3(3., 5., 5.)
+
+    def test_length_mismatch(self):
+        with pytest.raises(ValueError):
+            pyglam.DVec3Array.zeros(2) + pyglam.DVec3Array.zeros(3)
@@ -0,0 +1,29 @@
+import numpy as np
+import pytest
+import pyglam
+
+class TestDVec4:
+    def test_construct(self):
+        actual = pyglam.DVec4(100.)
+        np.testing.assert_allclose(actual.to_tuple(), (100., 100., 100., 100.))
+
+    def test_project_homogeneous(self):
+        actual = pyglam.DVec3(2., 4., 6.).extend(2.)
+        np.testing.assert_allclose(actual.project_homogeneous().to_tuple(), (1., 2., 3.))
+
+    def test_value_semantics(self):
+        actual = pyglam.DVec4(1., 0.5, -2., 0.1)
+        assert eval(repr(actual), {"DVec4": pyglam.DVec4}) == actual
+        assert actual != pyglam.DVec4(1.)
+        assert actual != (1., 0.5, -2., 0.1)
+        assert hash(pyglam.DVec4(0., 1., 2., 3.)) == hash(pyglam.DVec4(-0., 1., 2., 3.))
+
+    def test_sequence(self):
+        actual = pyglam.DVec4(1., 2., 3., 4.)
+        assert tuple(actual) == (1., 2., 3., 4.)
+        assert actual[1:3] == (2., 3.)
+        actual[1:3] = (7., 8.)
+        assert actual == pyglam.DVec4(1., 7., 8., 4.)
+        assert 8 in actual
+        with pytest.raises(ValueError):
+            actual[1:] = (1., 2.)